@@ -9,6 +9,8 @@ export const WH_BASE_CHAIN_ID = 30
 
 export const WH_SOLANA_CHAIN_ID = 1
 
+export const WH_ETHEREUM_CHAIN_ID = 2
+
 // EVM addresses converted to 32 bytes. 0x is trimmed intentionally as the input
 // param requires it without leading 0x.
 
@@ -38,6 +40,11 @@ export const SOLANA_GATEWAY_ADDRESS_TESTNET =
 export const SOLANA_GATEWAY_ADDRESS_MAINNET =
   "87MEvHZCXE3ML5rrmh5uX1FbShHmRXXS32xJDGbQ7h5t"
 
+export const TBTC_ETHEREUM_TOKEN_ADDRESS_TESTNET =
+  "000000000000000000000000679874fbe6d4e7cc54a59e315ff1eb266686a937"
+export const TBTC_ETHEREUM_TOKEN_ADDRESS_MAINNET =
+  "00000000000000000000000018084fba666a33d37592fa2633fd49a74dd93a88"
+
 // deriveWrappedMintKey("DZnkkTmCiFWfYTfT41X3Rd1kDgozqzxWaHqsw6W4x2oe", 2, "0x679874fbe6d4e7cc54a59e315ff1eb266686a937")
 export const WRAPPED_TBTC_MINT_TESTNET =
   "FMYvcyMJJ22whB9m3T5g1oPKwM6jpLnFBXnrY6eXmCrp"
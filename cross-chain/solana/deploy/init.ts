@@ -5,6 +5,8 @@ import dotenv from "dotenv"
 import { Program } from "@coral-xyz/anchor"
 import { Tbtc } from "../target/types/tbtc"
 import { WormholeGateway } from "../target/types/wormhole_gateway"
+import { BtcRedeemer } from "../target/types/btc_redeemer"
+import { BtcDepositor } from "../target/types/btc_depositor"
 import { PROGRAM_ID as METADATA_PROGRAM_ID } from "@metaplex-foundation/mpl-token-metadata"
 import * as consts from "./helpers/consts"
 
@@ -16,6 +18,10 @@ async function run(): Promise<void> {
   const tbtcProgram = anchor.workspace.Tbtc as Program<Tbtc>
   const wormholeGatewayProgram = anchor.workspace
     .WormholeGateway as Program<WormholeGateway>
+  const btcRedeemerProgram = anchor.workspace
+    .BtcRedeemer as Program<BtcRedeemer>
+  const btcDepositorProgram = anchor.workspace
+    .BtcDepositor as Program<BtcDepositor>
 
   // This wallet deployed the program and is also an authority
   const authority = loadKey(process.env.AUTHORITY).publicKey
@@ -40,6 +46,11 @@ async function run(): Promise<void> {
     tbtcProgram.programId
   )[0]
 
+  const mintRateLimit = PublicKey.findProgramAddressSync(
+    [Buffer.from("mint-rate-limit")],
+    tbtcProgram.programId
+  )[0]
+
   const tbtcMetadata = PublicKey.findProgramAddressSync(
     [Buffer.from("metadata"), METADATA_PROGRAM_ID.toBuffer(), mint.toBuffer()],
     METADATA_PROGRAM_ID
@@ -55,6 +66,7 @@ async function run(): Promise<void> {
       config,
       guardians,
       minters,
+      mintRateLimit,
       authority,
       tbtcMetadata,
       mplTokenMetadataProgram,
@@ -68,8 +80,10 @@ async function run(): Promise<void> {
 
   const mintingLimit = "18446744073709551615" // Max u64
   let WRAPPED_TBTC = consts.WRAPPED_TBTC_MINT_TESTNET
+  let TBTC_ETHEREUM_TOKEN = consts.TBTC_ETHEREUM_TOKEN_ADDRESS_TESTNET
   if (process.env.CLUSTER === "mainnet") {
     WRAPPED_TBTC = consts.WRAPPED_TBTC_MINT_MAINNET
+    TBTC_ETHEREUM_TOKEN = consts.TBTC_ETHEREUM_TOKEN_ADDRESS_MAINNET
   }
   const WRAPPED_TBTC_MINT = new PublicKey(WRAPPED_TBTC)
 
@@ -90,7 +104,13 @@ async function run(): Promise<void> {
 
   // Initialize wormhole gateway
   await wormholeGatewayProgram.methods
-    .initialize(new anchor.BN(mintingLimit))
+    .initialize({
+      mintingLimit: new anchor.BN(mintingLimit),
+      canonicalToken: {
+        chain: consts.WH_ETHEREUM_CHAIN_ID,
+        address: Array.from(Buffer.from(TBTC_ETHEREUM_TOKEN, "hex")),
+      },
+    })
     .accounts({
       authority,
       custodian: minter,
@@ -108,12 +128,34 @@ async function run(): Promise<void> {
     tbtcProgram.programId
   )[0]
 
-  // Adding a minter (wormholeGateway)
+  // The authority manages minters itself during deployment. This role can be
+  // revoked once ops holds it on a separate key.
+  const minterAdminRole = PublicKey.findProgramAddressSync(
+    [Buffer.from("role-member"), Buffer.from([0]), authority.toBuffer()],
+    tbtcProgram.programId
+  )[0]
+
   await tbtcProgram.methods
-    .addMinter()
+    .grantRole({ minterAdmin: {} })
     .accounts({
       config,
       authority,
+      roleMember: minterAdminRole,
+      member: authority,
+    })
+    .rpc()
+
+  console.log("Granted minter admin role..")
+
+  // Adding a minter (wormholeGateway). The gateway enforces its own minting
+  // limit, so its allowance in the tbtc program is unbounded.
+  const minterAllowance = "18446744073709551615" // Max u64
+  await tbtcProgram.methods
+    .addMinter(new anchor.BN(minterAllowance))
+    .accounts({
+      config,
+      roleMember: minterAdminRole,
+      minterAdmin: authority,
       minters,
       minterInfo,
       minter,
@@ -122,6 +164,24 @@ async function run(): Promise<void> {
 
   console.log("Added a minter..")
 
+  // Same for gateway addresses.
+  const gatewayManagerRole = PublicKey.findProgramAddressSync(
+    [Buffer.from("role-member"), Buffer.from([1]), authority.toBuffer()],
+    wormholeGatewayProgram.programId
+  )[0]
+
+  await wormholeGatewayProgram.methods
+    .grantRole({ gatewayManager: {} })
+    .accounts({
+      custodian: minter,
+      authority,
+      roleMember: gatewayManagerRole,
+      member: authority,
+    })
+    .rpc()
+
+  console.log("Granted gateway manager role..")
+
   // Point to devnet addresses by default
   let ARBITRUM_GATEWAY = consts.ARBITRUM_GATEWAY_ADDRESS_TESTNET
   let OPTIMISM_GATEWAY = consts.OPTIMISM_GATEWAY_ADDRESS_TESTNET
@@ -152,9 +212,9 @@ async function run(): Promise<void> {
   await wormholeGatewayProgram.methods
     .updateGatewayAddress(arbiArgs)
     .accounts({
-      custodian: minter,
       gatewayInfo: gatewayArbiInfo,
-      authority,
+      roleMember: gatewayManagerRole,
+      gatewayManager: authority,
     })
     .rpc()
 
@@ -179,9 +239,9 @@ async function run(): Promise<void> {
   await wormholeGatewayProgram.methods
     .updateGatewayAddress(optiArgs)
     .accounts({
-      custodian: minter,
       gatewayInfo: gatewayOptiInfo,
-      authority,
+      roleMember: gatewayManagerRole,
+      gatewayManager: authority,
     })
     .rpc()
 
@@ -206,9 +266,9 @@ async function run(): Promise<void> {
   await wormholeGatewayProgram.methods
     .updateGatewayAddress(polyArgs)
     .accounts({
-      custodian: minter,
       gatewayInfo: gatewayPolyInfo,
-      authority,
+      roleMember: gatewayManagerRole,
+      gatewayManager: authority,
     })
     .rpc()
 
@@ -233,9 +293,9 @@ async function run(): Promise<void> {
   await wormholeGatewayProgram.methods
     .updateGatewayAddress(baseArgs)
     .accounts({
-      custodian: minter,
       gatewayInfo: gatewayBaseInfo,
-      authority,
+      roleMember: gatewayManagerRole,
+      gatewayManager: authority,
     })
     .rpc()
 
@@ -260,9 +320,9 @@ async function run(): Promise<void> {
   await wormholeGatewayProgram.methods
     .updateGatewayAddress(solanaArgs)
     .accounts({
-      custodian: minter,
       gatewayInfo: gatewaySolanaInfo,
-      authority,
+      roleMember: gatewayManagerRole,
+      gatewayManager: authority,
     })
     .rpc()
 
@@ -271,6 +331,108 @@ async function run(): Promise<void> {
     Array.from(new PublicKey(SOLANA_GATEWAY).toBuffer())
   )
 
+  // Allow sending tBTC to EVM chains.
+  const allowedChains = [
+    consts.WH_ETHEREUM_CHAIN_ID,
+    consts.WH_ARBITRUM_CHAIN_ID,
+    consts.WH_OPTIMISM_CHAIN_ID,
+    consts.WH_POLYGON_CHAIN_ID,
+    consts.WH_BASE_CHAIN_ID,
+  ]
+  for (const chain of allowedChains) {
+    const encodedChain = Buffer.alloc(2)
+    encodedChain.writeUInt16LE(chain)
+    const allowedChain = PublicKey.findProgramAddressSync(
+      [Buffer.from("allowed-chain"), encodedChain],
+      wormholeGatewayProgram.programId
+    )[0]
+
+    await wormholeGatewayProgram.methods
+      .updateAllowedChain({ chain, recipientFormat: { evm: {} } })
+      .accounts({
+        allowedChain,
+        roleMember: gatewayManagerRole,
+        gatewayManager: authority,
+      })
+      .rpc()
+
+    console.log("Allowed sending tBTC to chain..", chain)
+  }
+
+  // Gateways cannot mint until their minting limits are raised.
+  const limitManagerRole = PublicKey.findProgramAddressSync(
+    [Buffer.from("role-member"), Buffer.from([0]), authority.toBuffer()],
+    wormholeGatewayProgram.programId
+  )[0]
+
+  await wormholeGatewayProgram.methods
+    .grantRole({ limitManager: {} })
+    .accounts({
+      custodian: minter,
+      authority,
+      roleMember: limitManagerRole,
+      member: authority,
+    })
+    .rpc()
+
+  console.log("Granted limit manager role..")
+
+  const gatewayInfos = [
+    [consts.WH_ARBITRUM_CHAIN_ID, gatewayArbiInfo],
+    [consts.WH_OPTIMISM_CHAIN_ID, gatewayOptiInfo],
+    [consts.WH_POLYGON_CHAIN_ID, gatewayPolyInfo],
+    [consts.WH_BASE_CHAIN_ID, gatewayBaseInfo],
+  ] as const
+  for (const [chain, gatewayInfo] of gatewayInfos) {
+    await wormholeGatewayProgram.methods
+      .updateGatewayMintingLimit({
+        chain,
+        mintingLimit: new anchor.BN(mintingLimit),
+      })
+      .accounts({
+        gatewayInfo,
+        roleMember: limitManagerRole,
+        limitManager: authority,
+      })
+      .rpc()
+
+    console.log("Updated gateway minting limit for chain..", chain)
+  }
+
+  // Initialize btc redeemer
+  const btcRedeemerConfig = PublicKey.findProgramAddressSync(
+    [Buffer.from("config")],
+    btcRedeemerProgram.programId
+  )[0]
+
+  const minimumRedemptionAmount = "1000000" // 0.01 tBTC
+  await btcRedeemerProgram.methods
+    .initialize(new anchor.BN(minimumRedemptionAmount))
+    .accounts({
+      authority,
+      config: btcRedeemerConfig,
+    })
+    .rpc()
+
+  console.log("Initialized btc redeemer program..")
+
+  // Initialize btc depositor. The gateway custodian finalizes deposits when
+  // it receives their tBTC.
+  const btcDepositorConfig = PublicKey.findProgramAddressSync(
+    [Buffer.from("config")],
+    btcDepositorProgram.programId
+  )[0]
+
+  await btcDepositorProgram.methods
+    .initialize(minter)
+    .accounts({
+      authority,
+      config: btcDepositorConfig,
+    })
+    .rpc()
+
+  console.log("Initialized btc depositor program..")
+
   console.log("Done initializing programs!")
 }
 
@@ -0,0 +1,2 @@
+/// A.K.A. b"msg".
+pub const MSG_SEED_PREFIX: &[u8] = b"msg";
@@ -0,0 +1,22 @@
+use anchor_lang::prelude::error_code;
+
+#[error_code]
+pub enum BtcDepositorError {
+    #[msg("Only the Wormhole Gateway custodian is permitted for this action")]
+    IsNotGatewayCustodian = 0x10,
+
+    #[msg("Only config authority is permitted for this action")]
+    IsNotAuthority = 0x12,
+
+    #[msg("0x0 recipient not allowed")]
+    ZeroRecipient = 0x20,
+
+    #[msg("Deposit key does not match the funding transaction and output index")]
+    InvalidDepositKey = 0x30,
+
+    #[msg("Deposit is not initialized")]
+    DepositNotInitialized = 0x40,
+
+    #[msg("Recipient does not match the deposit record")]
+    RecipientMismatch = 0x50,
+}
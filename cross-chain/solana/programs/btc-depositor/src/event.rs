@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+
+/// Emits an event through a self-CPI when the `event-cpi` feature is enabled, so indexers can read
+/// it from instruction data even when program logs are truncated. Otherwise the event is written
+/// to program logs with `emit!`.
+///
+/// NOTE: The instruction's accounts must be annotated with `event_cpi` when the feature is enabled.
+macro_rules! emit_event {
+    ($ctx:ident, $event:expr) => {{
+        let event = $event;
+
+        #[cfg(feature = "event-cpi")]
+        {
+            let ctx = &$ctx;
+            anchor_lang::prelude::emit_cpi!(event);
+        }
+
+        #[cfg(not(feature = "event-cpi"))]
+        anchor_lang::prelude::emit!(event);
+    }};
+}
+
+#[event]
+pub struct L1BtcDepositorUpdated {
+    pub l1_btc_depositor: [u8; 32],
+}
+
+#[event]
+pub struct DepositInitialized {
+    pub deposit_key: [u8; 32],
+    pub recipient: Pubkey,
+    pub depositor: Pubkey,
+}
+
+#[event]
+pub struct DepositFinalized {
+    pub deposit_key: [u8; 32],
+    pub recipient: Pubkey,
+    pub amount: u64,
+}
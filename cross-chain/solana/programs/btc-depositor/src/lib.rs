@@ -0,0 +1,56 @@
+#![allow(clippy::result_large_err)]
+
+pub mod constants;
+
+pub mod error;
+
+#[macro_use]
+pub(crate) mod event;
+
+mod processor;
+pub(crate) use processor::*;
+
+mod state;
+pub use state::*;
+
+use anchor_lang::prelude::*;
+
+declare_id!("tuB29m4h5MVyWSY7VBwaSd7C7kvc9NVNgnGUeswxbGB");
+
+#[derive(Clone)]
+pub struct BtcDepositor;
+
+impl Id for BtcDepositor {
+    fn id() -> Pubkey {
+        ID
+    }
+}
+
+#[program]
+pub mod btc_depositor {
+
+    use super::*;
+
+    pub fn initialize(ctx: Context<Initialize>, gateway_custodian: Pubkey) -> Result<()> {
+        processor::initialize(ctx, gateway_custodian)
+    }
+
+    pub fn update_l1_btc_depositor(
+        ctx: Context<UpdateL1BtcDepositor>,
+        l1_btc_depositor: [u8; 32],
+    ) -> Result<()> {
+        processor::update_l1_btc_depositor(ctx, l1_btc_depositor)
+    }
+
+    pub fn initialize_deposit(
+        ctx: Context<InitializeDeposit>,
+        deposit_key: [u8; 32],
+        args: InitializeDepositArgs,
+    ) -> Result<()> {
+        processor::initialize_deposit(ctx, deposit_key, args)
+    }
+
+    pub fn finalize_deposit(ctx: Context<FinalizeDeposit>, amount: u64) -> Result<()> {
+        processor::finalize_deposit(ctx, amount)
+    }
+}
@@ -0,0 +1,30 @@
+use crate::state::Config;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct Initialize<'info> {
+    #[account(mut)]
+    authority: Signer<'info>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + Config::INIT_SPACE,
+        seeds = [Config::SEED_PREFIX],
+        bump,
+    )]
+    config: Account<'info, Config>,
+
+    system_program: Program<'info, System>,
+}
+
+pub fn initialize(ctx: Context<Initialize>, gateway_custodian: Pubkey) -> Result<()> {
+    ctx.accounts.config.set_inner(Config {
+        bump: ctx.bumps["config"],
+        authority: ctx.accounts.authority.key(),
+        gateway_custodian,
+        l1_btc_depositor: [0; 32],
+    });
+
+    Ok(())
+}
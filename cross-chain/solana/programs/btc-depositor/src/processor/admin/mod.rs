@@ -0,0 +1,5 @@
+mod initialize;
+pub use initialize::*;
+
+mod update_l1_btc_depositor;
+pub use update_l1_btc_depositor::*;
@@ -0,0 +1,30 @@
+use crate::{error::BtcDepositorError, state::Config};
+use anchor_lang::prelude::*;
+
+#[cfg_attr(feature = "event-cpi", event_cpi)]
+#[derive(Accounts)]
+pub struct UpdateL1BtcDepositor<'info> {
+    #[account(
+        mut,
+        seeds = [Config::SEED_PREFIX],
+        bump = config.bump,
+        has_one = authority @ BtcDepositorError::IsNotAuthority
+    )]
+    config: Account<'info, Config>,
+
+    authority: Signer<'info>,
+}
+
+pub fn update_l1_btc_depositor(
+    ctx: Context<UpdateL1BtcDepositor>,
+    l1_btc_depositor: [u8; 32],
+) -> Result<()> {
+    ctx.accounts.config.l1_btc_depositor = l1_btc_depositor;
+
+    emit_event!(
+        ctx,
+        crate::event::L1BtcDepositorUpdated { l1_btc_depositor }
+    );
+
+    Ok(())
+}
@@ -0,0 +1,63 @@
+use crate::{
+    error::BtcDepositorError,
+    state::{Config, DepositRecord, DepositState},
+};
+use anchor_lang::prelude::*;
+
+#[cfg_attr(feature = "event-cpi", event_cpi)]
+#[derive(Accounts)]
+pub struct FinalizeDeposit<'info> {
+    #[account(
+        seeds = [Config::SEED_PREFIX],
+        bump = config.bump,
+        has_one = gateway_custodian @ BtcDepositorError::IsNotGatewayCustodian,
+    )]
+    config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [DepositRecord::SEED_PREFIX, &deposit_record.deposit_key],
+        bump = deposit_record.bump,
+        has_one = recipient @ BtcDepositorError::RecipientMismatch,
+    )]
+    deposit_record: Account<'info, DepositRecord>,
+
+    /// CHECK: This account is the recipient of the received tBTC, which must match the deposit
+    /// record.
+    recipient: UncheckedAccount<'info>,
+
+    /// The Wormhole Gateway signs for this instruction when it receives the deposit's tBTC.
+    gateway_custodian: Signer<'info>,
+}
+
+impl<'info> FinalizeDeposit<'info> {
+    fn constraints(ctx: &Context<Self>) -> Result<()> {
+        require!(
+            ctx.accounts.deposit_record.state == DepositState::Initialized,
+            BtcDepositorError::DepositNotInitialized
+        );
+
+        Ok(())
+    }
+}
+
+#[access_control(FinalizeDeposit::constraints(&ctx))]
+pub fn finalize_deposit(ctx: Context<FinalizeDeposit>, amount: u64) -> Result<()> {
+    let deposit_record = &mut ctx.accounts.deposit_record;
+    deposit_record.state = DepositState::Finalized;
+    deposit_record.amount = amount;
+
+    let deposit_key = deposit_record.deposit_key;
+    let recipient = deposit_record.recipient;
+
+    emit_event!(
+        ctx,
+        crate::event::DepositFinalized {
+            deposit_key,
+            recipient,
+            amount,
+        }
+    );
+
+    Ok(())
+}
@@ -0,0 +1,269 @@
+use crate::{
+    constants::MSG_SEED_PREFIX,
+    error::BtcDepositorError,
+    state::{DepositRecord, DepositState},
+};
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{hash, keccak};
+use anchor_lang::system_program;
+use wormhole_anchor_sdk::wormhole::{self as core_bridge, program::Wormhole as CoreBridge};
+
+#[cfg_attr(feature = "event-cpi", event_cpi)]
+#[derive(Accounts)]
+#[instruction(deposit_key: [u8; 32])]
+pub struct InitializeDeposit<'info> {
+    #[account(mut)]
+    payer: Signer<'info>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + DepositRecord::INIT_SPACE,
+        seeds = [DepositRecord::SEED_PREFIX, &deposit_key],
+        bump,
+    )]
+    deposit_record: Account<'info, DepositRecord>,
+
+    /// CHECK: This PDA signs for the deposit messages posted to the Core Bridge program.
+    #[account(
+        seeds = [core_bridge::SEED_PREFIX_EMITTER],
+        bump,
+    )]
+    core_emitter: UncheckedAccount<'info>,
+
+    /// This account is needed for the Core Bridge program, which also charges its message fee.
+    #[account(mut)]
+    core_bridge_data: Account<'info, core_bridge::BridgeData>,
+
+    /// CHECK: This account is needed for the Core Bridge program. There is one message per deposit.
+    #[account(
+        mut,
+        seeds = [MSG_SEED_PREFIX, &deposit_key],
+        bump,
+    )]
+    core_message: UncheckedAccount<'info>,
+
+    /// CHECK: This account is needed for the Core Bridge program.
+    #[account(mut)]
+    core_emitter_sequence: UncheckedAccount<'info>,
+
+    /// CHECK: This account is needed for the Core Bridge program.
+    #[account(mut)]
+    core_fee_collector: UncheckedAccount<'info>,
+
+    /// CHECK: This account is needed for the Core Bridge program.
+    clock: UncheckedAccount<'info>,
+
+    /// CHECK: This account is needed for the Core Bridge program.
+    rent: UncheckedAccount<'info>,
+
+    core_bridge_program: Program<'info, CoreBridge>,
+    system_program: Program<'info, System>,
+}
+
+impl<'info> InitializeDeposit<'info> {
+    fn constraints(deposit_key: &[u8; 32], args: &InitializeDepositArgs) -> Result<()> {
+        require_keys_neq!(
+            args.recipient,
+            Pubkey::default(),
+            BtcDepositorError::ZeroRecipient
+        );
+
+        // The deposit record is keyed by the deposit key, so it must be the one the tBTC Bridge
+        // derives from the funding transaction.
+        require!(
+            *deposit_key == args.deposit_key(),
+            BtcDepositorError::InvalidDepositKey
+        );
+
+        Ok(())
+    }
+}
+
+/// See `IBridgeTypes.BitcoinTxInfo`.
+#[derive(Debug, Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct BitcoinTxInfo {
+    pub version: [u8; 4],
+    pub input_vector: Vec<u8>,
+    pub output_vector: Vec<u8>,
+    pub locktime: [u8; 4],
+}
+
+/// See `IBridgeTypes.DepositRevealInfo`.
+#[derive(Debug, Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct DepositRevealInfo {
+    pub funding_output_index: u32,
+    pub blinding_factor: [u8; 8],
+    pub wallet_pubkey_hash: [u8; 20],
+    pub refund_pubkey_hash: [u8; 20],
+    pub refund_locktime: [u8; 4],
+    pub vault: [u8; 20],
+}
+
+#[derive(Debug, Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct InitializeDepositArgs {
+    funding_tx: BitcoinTxInfo,
+    reveal: DepositRevealInfo,
+    recipient: Pubkey,
+}
+
+impl InitializeDepositArgs {
+    /// Returns the deposit key the tBTC Bridge uses for this deposit, which is
+    /// keccak256(hash256(funding tx) | funding output index).
+    fn deposit_key(&self) -> [u8; 32] {
+        let BitcoinTxInfo {
+            version,
+            input_vector,
+            output_vector,
+            locktime,
+        } = &self.funding_tx;
+        let funding_tx_hash =
+            hash::hash(hash::hashv(&[version, input_vector, output_vector, locktime]).as_ref());
+
+        keccak::hashv(&[
+            funding_tx_hash.as_ref(),
+            &self.reveal.funding_output_index.to_be_bytes(),
+        ])
+        .to_bytes()
+    }
+
+    /// Returns the arguments of `L1BTCDepositorWormhole.initializeDeposit` ABI-encoded, with the
+    /// recipient as the destination chain deposit owner.
+    fn to_message_payload(&self) -> Vec<u8> {
+        let BitcoinTxInfo {
+            version,
+            input_vector,
+            output_vector,
+            locktime,
+        } = &self.funding_tx;
+        let reveal = &self.reveal;
+
+        let mut payload = Vec::with_capacity(
+            12 * 32 + padded_len(input_vector.len()) + padded_len(output_vector.len()),
+        );
+
+        // Head: offset of the funding tx tuple, the static reveal tuple and the recipient.
+        payload.extend_from_slice(&uint_word(8 * 32));
+        payload.extend_from_slice(&uint_word(reveal.funding_output_index.into()));
+        payload.extend_from_slice(&bytes_word(&reveal.blinding_factor));
+        payload.extend_from_slice(&bytes_word(&reveal.wallet_pubkey_hash));
+        payload.extend_from_slice(&bytes_word(&reveal.refund_pubkey_hash));
+        payload.extend_from_slice(&bytes_word(&reveal.refund_locktime));
+        payload.extend_from_slice(&address_word(&reveal.vault));
+        payload.extend_from_slice(self.recipient.as_ref());
+
+        // Tail: the funding tx tuple, whose input and output vectors are encoded after its head.
+        let input_vector_offset = 4 * 32;
+        let output_vector_offset = input_vector_offset + 32 + padded_len(input_vector.len());
+        payload.extend_from_slice(&bytes_word(version));
+        payload.extend_from_slice(&uint_word(input_vector_offset as u64));
+        payload.extend_from_slice(&uint_word(output_vector_offset as u64));
+        payload.extend_from_slice(&bytes_word(locktime));
+        extend_with_bytes(&mut payload, input_vector);
+        extend_with_bytes(&mut payload, output_vector);
+
+        payload
+    }
+}
+
+#[access_control(InitializeDeposit::constraints(&deposit_key, &args))]
+pub fn initialize_deposit(
+    ctx: Context<InitializeDeposit>,
+    deposit_key: [u8; 32],
+    args: InitializeDepositArgs,
+) -> Result<()> {
+    let depositor = ctx.accounts.payer.key();
+
+    ctx.accounts.deposit_record.set_inner(DepositRecord {
+        bump: ctx.bumps["deposit_record"],
+        deposit_key,
+        recipient: args.recipient,
+        depositor,
+        state: DepositState::Initialized,
+        amount: 0,
+    });
+
+    emit_event!(
+        ctx,
+        crate::event::DepositInitialized {
+            deposit_key,
+            recipient: args.recipient,
+            depositor,
+        }
+    );
+
+    // Pay the Core Bridge message fee, if any.
+    let fee = ctx.accounts.core_bridge_data.fee();
+    if fee > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.payer.to_account_info(),
+                    to: ctx.accounts.core_fee_collector.to_account_info(),
+                },
+            ),
+            fee,
+        )?;
+    }
+
+    // Finally post the deposit for relaying to `L1BTCDepositorWormhole`, which initializes it in the
+    // tBTC Bridge and eventually sends the minted tBTC to the recipient through the Wormhole Gateway.
+    core_bridge::post_message(
+        CpiContext::new_with_signer(
+            ctx.accounts.core_bridge_program.to_account_info(),
+            core_bridge::PostMessage {
+                config: ctx.accounts.core_bridge_data.to_account_info(),
+                message: ctx.accounts.core_message.to_account_info(),
+                emitter: ctx.accounts.core_emitter.to_account_info(),
+                sequence: ctx.accounts.core_emitter_sequence.to_account_info(),
+                payer: ctx.accounts.payer.to_account_info(),
+                fee_collector: ctx.accounts.core_fee_collector.to_account_info(),
+                clock: ctx.accounts.clock.to_account_info(),
+                rent: ctx.accounts.rent.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+            },
+            &[
+                &[
+                    core_bridge::SEED_PREFIX_EMITTER,
+                    &[ctx.bumps["core_emitter"]],
+                ],
+                &[MSG_SEED_PREFIX, &deposit_key, &[ctx.bumps["core_message"]]],
+            ],
+        ),
+        0, // Nonce is a free field that is not relevant in this context.
+        args.to_message_payload(),
+        core_bridge::Finality::Finalized,
+    )
+}
+
+/// Returns the number of bytes an ABI-encoded `bytes` value is padded to.
+fn padded_len(len: usize) -> usize {
+    (len + 31) / 32 * 32
+}
+
+fn uint_word(value: u64) -> [u8; 32] {
+    let mut word = [0; 32];
+    word[24..].copy_from_slice(&value.to_be_bytes());
+    word
+}
+
+fn address_word(address: &[u8; 20]) -> [u8; 32] {
+    let mut word = [0; 32];
+    word[12..].copy_from_slice(address);
+    word
+}
+
+/// Encodes a fixed-size `bytesN` value, which is left-aligned.
+fn bytes_word(value: &[u8]) -> [u8; 32] {
+    let mut word = [0; 32];
+    word[..value.len()].copy_from_slice(value);
+    word
+}
+
+/// Appends a dynamic `bytes` value, which is its length followed by its zero-padded contents.
+fn extend_with_bytes(payload: &mut Vec<u8>, value: &[u8]) {
+    payload.extend_from_slice(&uint_word(value.len() as u64));
+    payload.extend_from_slice(value);
+    payload.resize(payload.len() + padded_len(value.len()) - value.len(), 0);
+}
@@ -0,0 +1,8 @@
+mod admin;
+pub use admin::*;
+
+mod finalize_deposit;
+pub use finalize_deposit::*;
+
+mod initialize_deposit;
+pub use initialize_deposit::*;
@@ -0,0 +1,18 @@
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(Debug, InitSpace)]
+pub struct Config {
+    pub bump: u8,
+    pub authority: Pubkey,
+
+    /// Wormhole Gateway custodian, which finalizes deposits when it receives their tBTC.
+    pub gateway_custodian: Pubkey,
+    /// Address of `L1BTCDepositorWormhole`, which sends the tBTC minted for deposits. Only its
+    /// transfers finalize deposits.
+    pub l1_btc_depositor: [u8; 32],
+}
+
+impl Config {
+    pub const SEED_PREFIX: &'static [u8] = b"config";
+}
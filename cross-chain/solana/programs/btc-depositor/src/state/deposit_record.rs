@@ -0,0 +1,24 @@
+use anchor_lang::prelude::*;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, AnchorSerialize, AnchorDeserialize, InitSpace)]
+pub enum DepositState {
+    Initialized,
+    Finalized,
+}
+
+#[account]
+#[derive(Debug, InitSpace)]
+pub struct DepositRecord {
+    pub bump: u8,
+    /// Key of the deposit in the tBTC Bridge, which is keccak256(funding tx hash | output index).
+    pub deposit_key: [u8; 32],
+    pub recipient: Pubkey,
+    pub depositor: Pubkey,
+    pub state: DepositState,
+    /// Amount of tBTC received when the deposit was finalized.
+    pub amount: u64,
+}
+
+impl DepositRecord {
+    pub const SEED_PREFIX: &'static [u8] = b"deposit-record";
+}
@@ -0,0 +1,5 @@
+mod config;
+pub use config::*;
+
+mod deposit_record;
+pub use deposit_record::*;
@@ -0,0 +1,3 @@
+/// Seed prefix of the PDA that signs when sending tBTC with a payload through the Wormhole Gateway
+/// program.
+pub const PAYLOAD_SENDER_SEED_PREFIX: &[u8] = b"payload-sender";
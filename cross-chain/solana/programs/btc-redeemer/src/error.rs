@@ -0,0 +1,19 @@
+use anchor_lang::prelude::error_code;
+
+#[error_code]
+pub enum BtcRedeemerError {
+    #[msg("Only config authority is permitted for this action")]
+    IsNotAuthority = 0x10,
+
+    #[msg("Redeemer output script must be a standard P2PKH, P2WPKH, P2SH or P2WSH script")]
+    InvalidRedeemerOutputScript = 0x20,
+
+    #[msg("Amount is below the minimum redemption amount")]
+    AmountTooLowToRedeem = 0x30,
+
+    #[msg("Minimum redemption amount must not be 0")]
+    MinimumRedemptionAmountZero = 0x40,
+
+    #[msg("Redeemed amount exceeds u64")]
+    RedeemedAmountOverflow = 0x50,
+}
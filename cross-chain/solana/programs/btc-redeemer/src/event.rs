@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+
+/// Emits an event through a self-CPI when the `event-cpi` feature is enabled, so indexers can read
+/// it from instruction data even when program logs are truncated. Otherwise the event is written
+/// to program logs with `emit!`.
+///
+/// NOTE: The instruction's accounts must be annotated with `event_cpi` when the feature is enabled.
+macro_rules! emit_event {
+    ($ctx:ident, $event:expr) => {{
+        let event = $event;
+
+        #[cfg(feature = "event-cpi")]
+        {
+            let ctx = &$ctx;
+            anchor_lang::prelude::emit_cpi!(event);
+        }
+
+        #[cfg(not(feature = "event-cpi"))]
+        anchor_lang::prelude::emit!(event);
+    }};
+}
+
+#[event]
+pub struct RedemptionRequested {
+    pub redeemer: Pubkey,
+    pub amount: u64,
+    pub redeemer_output_script: Vec<u8>,
+    pub nonce: u32,
+}
+
+#[event]
+pub struct MinimumRedemptionAmountUpdated {
+    pub minimum_redemption_amount: u64,
+}
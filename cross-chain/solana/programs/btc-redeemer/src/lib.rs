@@ -0,0 +1,51 @@
+#![allow(clippy::result_large_err)]
+
+pub mod constants;
+
+pub mod error;
+
+#[macro_use]
+pub(crate) mod event;
+
+mod processor;
+pub(crate) use processor::*;
+
+mod state;
+pub use state::*;
+
+use anchor_lang::prelude::*;
+
+declare_id!("8xPdHeMpTxht5BTy5qk5hPubGZ9Vx2SQiFkaAM6daDN9");
+
+#[derive(Clone)]
+pub struct BtcRedeemer;
+
+impl Id for BtcRedeemer {
+    fn id() -> Pubkey {
+        ID
+    }
+}
+
+#[program]
+pub mod btc_redeemer {
+
+    use super::*;
+
+    pub fn initialize(ctx: Context<Initialize>, minimum_redemption_amount: u64) -> Result<()> {
+        processor::initialize(ctx, minimum_redemption_amount)
+    }
+
+    pub fn update_minimum_redemption_amount(
+        ctx: Context<UpdateMinimumRedemptionAmount>,
+        minimum_redemption_amount: u64,
+    ) -> Result<()> {
+        processor::update_minimum_redemption_amount(ctx, minimum_redemption_amount)
+    }
+
+    pub fn request_redemption<'info>(
+        ctx: Context<'_, '_, '_, 'info, RequestRedemption<'info>>,
+        args: RequestRedemptionArgs,
+    ) -> Result<()> {
+        processor::request_redemption(ctx, args)
+    }
+}
@@ -0,0 +1,36 @@
+use crate::{error::BtcRedeemerError, state::Config};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct Initialize<'info> {
+    #[account(mut)]
+    authority: Signer<'info>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + Config::INIT_SPACE,
+        seeds = [Config::SEED_PREFIX],
+        bump,
+    )]
+    config: Account<'info, Config>,
+
+    system_program: Program<'info, System>,
+}
+
+pub fn initialize(ctx: Context<Initialize>, minimum_redemption_amount: u64) -> Result<()> {
+    require_gt!(
+        minimum_redemption_amount,
+        0,
+        BtcRedeemerError::MinimumRedemptionAmountZero
+    );
+
+    ctx.accounts.config.set_inner(Config {
+        bump: ctx.bumps["config"],
+        authority: ctx.accounts.authority.key(),
+        minimum_redemption_amount,
+        redeemed_amount: 0,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,5 @@
+mod initialize;
+pub use initialize::*;
+
+mod update_minimum_redemption_amount;
+pub use update_minimum_redemption_amount::*;
@@ -0,0 +1,38 @@
+use crate::{error::BtcRedeemerError, state::Config};
+use anchor_lang::prelude::*;
+
+#[cfg_attr(feature = "event-cpi", event_cpi)]
+#[derive(Accounts)]
+pub struct UpdateMinimumRedemptionAmount<'info> {
+    #[account(
+        mut,
+        seeds = [Config::SEED_PREFIX],
+        bump = config.bump,
+        has_one = authority @ BtcRedeemerError::IsNotAuthority
+    )]
+    config: Account<'info, Config>,
+
+    authority: Signer<'info>,
+}
+
+pub fn update_minimum_redemption_amount(
+    ctx: Context<UpdateMinimumRedemptionAmount>,
+    minimum_redemption_amount: u64,
+) -> Result<()> {
+    require_gt!(
+        minimum_redemption_amount,
+        0,
+        BtcRedeemerError::MinimumRedemptionAmountZero
+    );
+
+    ctx.accounts.config.minimum_redemption_amount = minimum_redemption_amount;
+
+    emit_event!(
+        ctx,
+        crate::event::MinimumRedemptionAmountUpdated {
+            minimum_redemption_amount
+        }
+    );
+
+    Ok(())
+}
@@ -0,0 +1,5 @@
+mod admin;
+pub use admin::*;
+
+mod request_redemption;
+pub use request_redemption::*;
@@ -0,0 +1,245 @@
+use crate::{constants::PAYLOAD_SENDER_SEED_PREFIX, error::BtcRedeemerError, state::Config};
+use anchor_lang::prelude::*;
+
+#[cfg_attr(feature = "event-cpi", event_cpi)]
+#[derive(Accounts)]
+pub struct RequestRedemption<'info> {
+    #[account(
+        mut,
+        seeds = [Config::SEED_PREFIX],
+        bump = config.bump,
+    )]
+    config: Account<'info, Config>,
+
+    /// CHECK: This account is checked by the Wormhole Gateway program.
+    #[account(mut)]
+    custodian: UncheckedAccount<'info>,
+
+    /// CHECK: This account is checked by the Wormhole Gateway program.
+    allowed_chain: UncheckedAccount<'info>,
+
+    /// CHECK: This account is checked by the Wormhole Gateway program.
+    #[account(mut)]
+    wrapped_tbtc_token: UncheckedAccount<'info>,
+
+    /// CHECK: This account is checked by the Wormhole Gateway program.
+    #[account(mut)]
+    wrapped_tbtc_mint: UncheckedAccount<'info>,
+
+    /// CHECK: This account is checked by the Wormhole Gateway program.
+    #[account(mut)]
+    tbtc_mint: UncheckedAccount<'info>,
+
+    /// CHECK: This account is checked by the Wormhole Gateway program.
+    #[account(mut)]
+    sender_token: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    sender: Signer<'info>,
+
+    /// CHECK: This PDA signs for sending tBTC with a payload, which the Wormhole Gateway program
+    /// only allows for its configured payload sender.
+    #[account(
+        seeds = [PAYLOAD_SENDER_SEED_PREFIX],
+        bump,
+    )]
+    payload_sender: UncheckedAccount<'info>,
+
+    /// CHECK: This account is needed for the TBTC program.
+    #[account(mut)]
+    tbtc_config: UncheckedAccount<'info>,
+
+    /// CHECK: This account is needed for the Token Bridge program.
+    token_bridge_config: UncheckedAccount<'info>,
+
+    /// CHECK: This account is needed for the Token Bridge program.
+    token_bridge_wrapped_asset: UncheckedAccount<'info>,
+
+    /// CHECK: This account is needed for the Token Bridge program.
+    token_bridge_transfer_authority: UncheckedAccount<'info>,
+
+    /// CHECK: This account is needed for the Token Bridge program.
+    #[account(mut)]
+    core_bridge_data: UncheckedAccount<'info>,
+
+    /// CHECK: This account is checked by the Wormhole Gateway program.
+    #[account(mut)]
+    core_message: UncheckedAccount<'info>,
+
+    /// CHECK: This account is needed for the Token Bridge program.
+    token_bridge_core_emitter: UncheckedAccount<'info>,
+
+    /// CHECK: This account is checked by the Wormhole Gateway program.
+    #[account(mut)]
+    core_emitter_sequence: UncheckedAccount<'info>,
+
+    /// CHECK: This account is needed for the Token Bridge program.
+    #[account(mut)]
+    core_fee_collector: UncheckedAccount<'info>,
+
+    /// CHECK: This account is needed for the Token Bridge program.
+    clock: UncheckedAccount<'info>,
+
+    /// CHECK: This account is checked by the Wormhole Gateway program.
+    token_bridge_sender: UncheckedAccount<'info>,
+
+    /// CHECK: This account is needed for the Token Bridge program.
+    rent: UncheckedAccount<'info>,
+
+    /// CHECK: This account is checked by the Wormhole Gateway program.
+    tbtc_program: UncheckedAccount<'info>,
+
+    /// CHECK: This account is checked by the Wormhole Gateway program.
+    token_bridge_program: UncheckedAccount<'info>,
+
+    /// CHECK: This account is checked by the Wormhole Gateway program.
+    core_bridge_program: UncheckedAccount<'info>,
+
+    /// CHECK: This account is checked by the Wormhole Gateway program.
+    token_program: UncheckedAccount<'info>,
+
+    system_program: Program<'info, System>,
+
+    wormhole_gateway_program: Program<'info, wormhole_gateway::WormholeGateway>,
+}
+
+impl<'info> RequestRedemption<'info> {
+    fn constraints(ctx: &Context<Self>, args: &RequestRedemptionArgs) -> Result<()> {
+        // Validate the script before any tBTC is burned. The L1 redeemer passes it as-is to the
+        // tBTC Bridge, so funds sent with an invalid script could not be redeemed.
+        require!(
+            is_standard_output_script(&args.redeemer_output_script),
+            BtcRedeemerError::InvalidRedeemerOutputScript
+        );
+
+        // NOTE: tBTC on Solana has 8 decimals, so the amount does not have to be normalized before
+        // bridging.
+        require_gte!(
+            args.amount,
+            ctx.accounts.config.minimum_redemption_amount,
+            BtcRedeemerError::AmountTooLowToRedeem
+        );
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct RequestRedemptionArgs {
+    amount: u64,
+    /// Length-prefixed Bitcoin output script receiving the redeemed BTC.
+    redeemer_output_script: Vec<u8>,
+    nonce: u32,
+}
+
+#[access_control(RequestRedemption::constraints(&ctx, &args))]
+pub fn request_redemption<'info>(
+    ctx: Context<'_, '_, '_, 'info, RequestRedemption<'info>>,
+    args: RequestRedemptionArgs,
+) -> Result<()> {
+    let RequestRedemptionArgs {
+        amount,
+        redeemer_output_script,
+        nonce,
+    } = args;
+
+    let config = &mut ctx.accounts.config;
+    config.redeemed_amount = config
+        .redeemed_amount
+        .checked_add(amount)
+        .ok_or(BtcRedeemerError::RedeemedAmountOverflow)?;
+
+    emit_event!(
+        ctx,
+        crate::event::RedemptionRequested {
+            redeemer: ctx.accounts.sender.key(),
+            amount,
+            redeemer_output_script: redeemer_output_script.clone(),
+            nonce,
+        }
+    );
+
+    // With the `event-cpi` feature, the TBTC and Wormhole Gateway programs emit events through a
+    // self-CPI, which needs their event authorities. They are passed as the first remaining
+    // accounts, so that the account list is the same as without the feature. The gateway takes the
+    // TBTC program's event authority as its own first remaining account.
+    #[cfg(feature = "event-cpi")]
+    let (wormhole_gateway_event_authority, remaining_accounts) = match ctx.remaining_accounts {
+        [tbtc_event_authority, wormhole_gateway_event_authority, ..] => (
+            wormhole_gateway_event_authority,
+            vec![tbtc_event_authority.to_account_info()],
+        ),
+        _ => return err!(anchor_lang::error::ErrorCode::AccountNotEnoughKeys),
+    };
+    #[cfg(not(feature = "event-cpi"))]
+    let remaining_accounts = vec![];
+
+    // The sender's signature is passed along to the gateway, which burns the sender's tBTC and sends
+    // the wrapped tBTC to the L1 redeemer with the output script as the transfer's payload. The
+    // payload sender PDA signs to show that the request was validated.
+    wormhole_gateway::cpi::send_tbtc_with_payload(
+        CpiContext::new_with_signer(
+            ctx.accounts.wormhole_gateway_program.to_account_info(),
+            wormhole_gateway::cpi::accounts::SendTbtcWithPayload {
+                custodian: ctx.accounts.custodian.to_account_info(),
+                allowed_chain: ctx.accounts.allowed_chain.to_account_info(),
+                wrapped_tbtc_token: ctx.accounts.wrapped_tbtc_token.to_account_info(),
+                wrapped_tbtc_mint: ctx.accounts.wrapped_tbtc_mint.to_account_info(),
+                tbtc_mint: ctx.accounts.tbtc_mint.to_account_info(),
+                sender_token: ctx.accounts.sender_token.to_account_info(),
+                sender: ctx.accounts.sender.to_account_info(),
+                payload_sender: ctx.accounts.payload_sender.to_account_info(),
+                tbtc_config: ctx.accounts.tbtc_config.to_account_info(),
+                token_bridge_config: ctx.accounts.token_bridge_config.to_account_info(),
+                token_bridge_wrapped_asset: ctx
+                    .accounts
+                    .token_bridge_wrapped_asset
+                    .to_account_info(),
+                token_bridge_transfer_authority: ctx
+                    .accounts
+                    .token_bridge_transfer_authority
+                    .to_account_info(),
+                core_bridge_data: ctx.accounts.core_bridge_data.to_account_info(),
+                core_message: ctx.accounts.core_message.to_account_info(),
+                token_bridge_core_emitter: ctx.accounts.token_bridge_core_emitter.to_account_info(),
+                core_emitter_sequence: ctx.accounts.core_emitter_sequence.to_account_info(),
+                core_fee_collector: ctx.accounts.core_fee_collector.to_account_info(),
+                clock: ctx.accounts.clock.to_account_info(),
+                token_bridge_sender: ctx.accounts.token_bridge_sender.to_account_info(),
+                rent: ctx.accounts.rent.to_account_info(),
+                tbtc_program: ctx.accounts.tbtc_program.to_account_info(),
+                token_bridge_program: ctx.accounts.token_bridge_program.to_account_info(),
+                core_bridge_program: ctx.accounts.core_bridge_program.to_account_info(),
+                token_program: ctx.accounts.token_program.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+                #[cfg(feature = "event-cpi")]
+                event_authority: wormhole_gateway_event_authority.to_account_info(),
+                #[cfg(feature = "event-cpi")]
+                program: ctx.accounts.wormhole_gateway_program.to_account_info(),
+            },
+            &[&[PAYLOAD_SENDER_SEED_PREFIX, &[ctx.bumps["payload_sender"]]]],
+        )
+        .with_remaining_accounts(remaining_accounts),
+        wormhole_gateway::SendTbtcWithPayloadArgs {
+            amount,
+            nonce,
+            payload: redeemer_output_script,
+        },
+    )
+}
+
+/// Returns whether the length-prefixed output script is a standard P2PKH, P2WPKH, P2SH or P2WSH
+/// script. This follows `BTCUtils.extractHashAt`, which the EVM redeemers use for validation.
+fn is_standard_output_script(script: &[u8]) -> bool {
+    match script {
+        // P2PKH: OP_DUP OP_HASH160 <20-byte hash> OP_EQUALVERIFY OP_CHECKSIG.
+        [0x19, 0x76, 0xa9, 0x14, .., 0x88, 0xac] => script.len() == 26,
+        // P2SH: OP_HASH160 <20-byte hash> OP_EQUAL.
+        [0x17, 0xa9, 0x14, .., 0x87] => script.len() == 24,
+        // P2WPKH: OP_0 <20-byte hash>.
+        [0x16, 0x00, 0x14, ..] => script.len() == 23,
+        // P2WSH: OP_0 <32-byte hash>.
+        [0x22, 0x00, 0x20, ..] => script.len() == 35,
+        _ => false,
+    }
+}
@@ -0,0 +1,17 @@
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(Debug, InitSpace)]
+pub struct Config {
+    pub bump: u8,
+    pub authority: Pubkey,
+
+    /// Minimum amount of tBTC that can be redeemed.
+    pub minimum_redemption_amount: u64,
+    /// Total amount of tBTC redeemed through this program.
+    pub redeemed_amount: u64,
+}
+
+impl Config {
+    pub const SEED_PREFIX: &'static [u8] = b"config";
+}
@@ -11,6 +11,9 @@ pub enum TbtcError {
     #[msg("No pending authority")]
     NoPendingAuthorityChange = 0x24,
 
+    #[msg("Authority change delay has not elapsed")]
+    AuthorityChangeDelayNotElapsed = 0x26,
+
     #[msg("This address is already a guardian")]
     GuardianAlreadyExists = 0x30,
 
@@ -29,9 +32,18 @@ pub enum TbtcError {
     #[msg("Caller is not a minter")]
     SignerNotMinter = 0x44,
 
+    #[msg("Amount exceeds the minter's remaining allowance")]
+    MintAllowanceExceeded = 0x46,
+
     #[msg("Program is paused")]
     IsPaused = 0x50,
 
     #[msg("Program is not paused")]
     IsNotPaused = 0x52,
+
+    #[msg("Amount exceeds the mint rate limit")]
+    RateLimitExceeded = 0x60,
+
+    #[msg("Account is already migrated")]
+    AlreadyMigrated = 0x70,
 }
@@ -1,5 +1,76 @@
+use crate::state::Role;
 use anchor_lang::prelude::*;
 
+/// Emits an event through a self-CPI when the `event-cpi` feature is enabled, so indexers can read
+/// it from instruction data even when program logs are truncated. Otherwise the event is written
+/// to program logs with `emit!`.
+///
+/// NOTE: The instruction's accounts must be annotated with `event_cpi` when the feature is enabled.
+macro_rules! emit_event {
+    ($ctx:ident, $event:expr) => {{
+        let event = $event;
+
+        #[cfg(feature = "event-cpi")]
+        {
+            let ctx = &$ctx;
+            anchor_lang::prelude::emit_cpi!(event);
+        }
+
+        #[cfg(not(feature = "event-cpi"))]
+        anchor_lang::prelude::emit!(event);
+    }};
+}
+
+#[event]
+pub struct AuthorityChangeRequested {
+    pub authority: Pubkey,
+    pub pending_authority: Pubkey,
+}
+
+#[event]
+pub struct AuthorityChangeCancelled {
+    pub authority: Pubkey,
+    pub pending_authority: Pubkey,
+}
+
+#[event]
+pub struct AuthorityTaken {
+    pub old_authority: Pubkey,
+    pub new_authority: Pubkey,
+}
+
+#[event]
+pub struct AuthorityChangeDelayDecreaseRequested {
+    pub delay: u32,
+}
+
+#[event]
+pub struct AuthorityChangeDelayUpdated {
+    pub delay: u32,
+}
+
+#[event]
+pub struct Paused {
+    pub guardian: Pubkey,
+}
+
+#[event]
+pub struct Unpaused {
+    pub unpauser: Pubkey,
+}
+
+#[event]
+pub struct RoleGranted {
+    pub role: Role,
+    pub member: Pubkey,
+}
+
+#[event]
+pub struct RoleRevoked {
+    pub role: Role,
+    pub member: Pubkey,
+}
+
 #[event]
 pub struct MinterAdded {
     pub minter: Pubkey,
@@ -10,6 +81,50 @@ pub struct MinterRemoved {
     pub minter: Pubkey,
 }
 
+#[event]
+pub struct MinterAllowanceUpdated {
+    pub minter: Pubkey,
+    pub allowance: u64,
+}
+
+#[event]
+pub struct MintRateLimitUpdated {
+    pub capacity: u64,
+    pub window: u32,
+}
+
+#[event]
+pub struct Burned {
+    pub owner: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct TokenAccountFrozen {
+    pub token_account: Pubkey,
+    pub guardian: Pubkey,
+}
+
+#[event]
+pub struct TokenAccountThawed {
+    pub token_account: Pubkey,
+}
+
+#[event]
+pub struct TokensRecovered {
+    pub mint: Pubkey,
+    pub source_token: Pubkey,
+    pub recipient_token: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct MetadataUpdated {
+    pub name: String,
+    pub symbol: String,
+    pub uri: String,
+}
+
 #[event]
 pub struct GuardianAdded {
     pub guardian: Pubkey,
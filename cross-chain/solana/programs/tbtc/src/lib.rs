@@ -5,6 +5,7 @@ pub use constants::*;
 
 pub mod error;
 
+#[macro_use]
 pub(crate) mod event;
 
 mod processor;
@@ -46,14 +47,45 @@ pub mod tbtc {
         processor::take_authority(ctx)
     }
 
-    pub fn add_minter(ctx: Context<AddMinter>) -> Result<()> {
-        processor::add_minter(ctx)
+    pub fn set_authority_change_delay(
+        ctx: Context<SetAuthorityChangeDelay>,
+        delay: u32,
+    ) -> Result<()> {
+        processor::set_authority_change_delay(ctx, delay)
+    }
+
+    pub fn grant_role(ctx: Context<GrantRole>, role: Role) -> Result<()> {
+        processor::grant_role(ctx, role)
+    }
+
+    pub fn revoke_role(ctx: Context<RevokeRole>, role: Role) -> Result<()> {
+        processor::revoke_role(ctx, role)
+    }
+
+    pub fn add_minter(ctx: Context<AddMinter>, allowance: u64) -> Result<()> {
+        processor::add_minter(ctx, allowance)
     }
 
     pub fn remove_minter(ctx: Context<RemoveMinter>) -> Result<()> {
         processor::remove_minter(ctx)
     }
 
+    pub fn set_minter_allowance(ctx: Context<SetMinterAllowance>, allowance: u64) -> Result<()> {
+        processor::set_minter_allowance(ctx, allowance)
+    }
+
+    pub fn migrate_config(ctx: Context<MigrateConfig>) -> Result<()> {
+        processor::migrate_config(ctx)
+    }
+
+    pub fn migrate_mint_rate_limit(ctx: Context<MigrateMintRateLimit>) -> Result<()> {
+        processor::migrate_mint_rate_limit(ctx)
+    }
+
+    pub fn migrate_minter_info(ctx: Context<MigrateMinterInfo>, allowance: u64) -> Result<()> {
+        processor::migrate_minter_info(ctx, allowance)
+    }
+
     pub fn add_guardian(ctx: Context<AddGuardian>) -> Result<()> {
         processor::add_guardian(ctx)
     }
@@ -62,6 +94,21 @@ pub mod tbtc {
         processor::remove_guardian(ctx)
     }
 
+    pub fn set_mint_rate_limit(
+        ctx: Context<SetMintRateLimit>,
+        args: SetMintRateLimitArgs,
+    ) -> Result<()> {
+        processor::set_mint_rate_limit(ctx, args)
+    }
+
+    pub fn recover_tokens(ctx: Context<RecoverTokens>, amount: u64) -> Result<()> {
+        processor::recover_tokens(ctx, amount)
+    }
+
+    pub fn update_metadata(ctx: Context<UpdateMetadata>, args: UpdateMetadataArgs) -> Result<()> {
+        processor::update_metadata(ctx, args)
+    }
+
     pub fn pause(ctx: Context<Pause>) -> Result<()> {
         processor::pause(ctx)
     }
@@ -70,7 +117,23 @@ pub mod tbtc {
         processor::unpause(ctx)
     }
 
+    pub fn freeze_account(ctx: Context<FreezeAccount>) -> Result<()> {
+        processor::freeze_account(ctx)
+    }
+
+    pub fn thaw_account(ctx: Context<ThawAccount>) -> Result<()> {
+        processor::thaw_account(ctx)
+    }
+
     pub fn mint(ctx: Context<Mint>, amount: u64) -> Result<()> {
         processor::mint(ctx, amount)
     }
+
+    pub fn burn(ctx: Context<Burn>, amount: u64) -> Result<()> {
+        processor::burn(ctx, amount)
+    }
+
+    pub fn burn_from(ctx: Context<BurnFrom>, amount: u64) -> Result<()> {
+        processor::burn_from(ctx, amount)
+    }
 }
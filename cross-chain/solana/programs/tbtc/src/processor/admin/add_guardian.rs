@@ -1,35 +1,42 @@
-use crate::{
-    error::TbtcError,
-    state::{Config, GuardianInfo, Guardians},
-};
+use crate::state::{Config, GuardianInfo, Guardians, Role, RoleMember};
 use anchor_lang::prelude::*;
 
+#[cfg_attr(feature = "event-cpi", event_cpi)]
 #[derive(Accounts)]
 pub struct AddGuardian<'info> {
     #[account(
         mut,
         seeds = [Config::SEED_PREFIX],
         bump,
-        has_one = authority @ TbtcError::IsNotAuthority
     )]
     config: Account<'info, Config>,
 
+    #[account(
+        seeds = [
+            RoleMember::SEED_PREFIX,
+            &[Role::GuardianAdmin as u8],
+            guardian_admin.key().as_ref()
+        ],
+        bump = role_member.bump,
+    )]
+    role_member: Account<'info, RoleMember>,
+
     #[account(mut)]
-    authority: Signer<'info>,
+    guardian_admin: Signer<'info>,
 
     #[account(
         mut,
         seeds = [Guardians::SEED_PREFIX],
         bump = guardians.bump,
         realloc = Guardians::compute_size(guardians.keys.len() + 1),
-        realloc::payer = authority,
+        realloc::payer = guardian_admin,
         realloc::zero = true,
     )]
     guardians: Account<'info, Guardians>,
 
     #[account(
         init,
-        payer = authority,
+        payer = guardian_admin,
         space = 8 + GuardianInfo::INIT_SPACE,
         seeds = [GuardianInfo::SEED_PREFIX, guardian.key().as_ref()],
         bump
@@ -58,7 +65,7 @@ pub fn add_guardian(ctx: Context<AddGuardian>) -> Result<()> {
     // Update config.
     ctx.accounts.config.num_guardians += 1;
 
-    emit!(crate::event::GuardianAdded { guardian });
+    emit_event!(ctx, crate::event::GuardianAdded { guardian });
 
     Ok(())
 }
@@ -1,35 +1,42 @@
-use crate::{
-    error::TbtcError,
-    state::{Config, MinterInfo, Minters},
-};
+use crate::state::{Config, MinterInfo, Minters, Role, RoleMember};
 use anchor_lang::prelude::*;
 
+#[cfg_attr(feature = "event-cpi", event_cpi)]
 #[derive(Accounts)]
 pub struct AddMinter<'info> {
     #[account(
         mut,
         seeds = [Config::SEED_PREFIX],
         bump,
-        has_one = authority @ TbtcError::IsNotAuthority
     )]
     config: Account<'info, Config>,
 
+    #[account(
+        seeds = [
+            RoleMember::SEED_PREFIX,
+            &[Role::MinterAdmin as u8],
+            minter_admin.key().as_ref()
+        ],
+        bump = role_member.bump,
+    )]
+    role_member: Account<'info, RoleMember>,
+
     #[account(mut)]
-    authority: Signer<'info>,
+    minter_admin: Signer<'info>,
 
     #[account(
         mut,
         seeds = [Minters::SEED_PREFIX],
         bump = minters.bump,
         realloc = Minters::compute_size(minters.keys.len() + 1),
-        realloc::payer = authority,
+        realloc::payer = minter_admin,
         realloc::zero = true,
     )]
     minters: Account<'info, Minters>,
 
     #[account(
         init,
-        payer = authority,
+        payer = minter_admin,
         space = 8 + MinterInfo::INIT_SPACE,
         seeds = [MinterInfo::SEED_PREFIX, minter.key().as_ref()],
         bump
@@ -42,13 +49,15 @@ pub struct AddMinter<'info> {
     system_program: Program<'info, System>,
 }
 
-pub fn add_minter(ctx: Context<AddMinter>) -> Result<()> {
+pub fn add_minter(ctx: Context<AddMinter>, allowance: u64) -> Result<()> {
     let minter = ctx.accounts.minter.key();
 
     // Set account data.
     ctx.accounts.minter_info.set_inner(MinterInfo {
         bump: ctx.bumps["minter_info"],
         minter,
+        allowance,
+        minted: 0,
     });
 
     // Push pubkey to minters account.
@@ -57,7 +66,7 @@ pub fn add_minter(ctx: Context<AddMinter>) -> Result<()> {
     // Update config.
     ctx.accounts.config.num_minters += 1;
 
-    emit!(crate::event::MinterAdded { minter });
+    emit_event!(ctx, crate::event::MinterAdded { minter });
 
     Ok(())
 }
@@ -1,6 +1,7 @@
 use crate::{error::TbtcError, state::Config};
 use anchor_lang::prelude::*;
 
+#[cfg_attr(feature = "event-cpi", event_cpi)]
 #[derive(Accounts)]
 
 pub struct CancelAuthorityChange<'info> {
@@ -17,6 +18,19 @@ pub struct CancelAuthorityChange<'info> {
 }
 
 pub fn cancel_authority_change(ctx: Context<CancelAuthorityChange>) -> Result<()> {
-    ctx.accounts.config.pending_authority = None;
+    let config = &mut ctx.accounts.config;
+
+    // The constraint above guarantees there is a pending authority.
+    let pending_authority = config.pending_authority.take().unwrap();
+    config.pending_authority_since = 0;
+
+    emit_event!(
+        ctx,
+        crate::event::AuthorityChangeCancelled {
+            authority: ctx.accounts.authority.key(),
+            pending_authority,
+        }
+    );
+
     Ok(())
 }
@@ -0,0 +1,64 @@
+use crate::{
+    constants::SEED_PREFIX_TBTC_MINT,
+    state::{Config, GuardianInfo},
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token;
+
+#[cfg_attr(feature = "event-cpi", event_cpi)]
+#[derive(Accounts)]
+pub struct FreezeAccount<'info> {
+    // Freezing requires the config to be the mint's freeze authority, which is only the case for
+    // mints created with it.
+    #[account(
+        seeds = [SEED_PREFIX_TBTC_MINT],
+        bump = config.mint_bump,
+        mint::freeze_authority = config,
+    )]
+    mint: Account<'info, token::Mint>,
+
+    #[account(
+        seeds = [Config::SEED_PREFIX],
+        bump = config.bump,
+    )]
+    config: Account<'info, Config>,
+
+    #[account(
+        has_one = guardian,
+        seeds = [GuardianInfo::SEED_PREFIX, guardian.key().as_ref()],
+        bump = guardian_info.bump
+    )]
+    guardian_info: Account<'info, GuardianInfo>,
+
+    guardian: Signer<'info>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+    )]
+    token_account: Account<'info, token::TokenAccount>,
+
+    token_program: Program<'info, token::Token>,
+}
+
+pub fn freeze_account(ctx: Context<FreezeAccount>) -> Result<()> {
+    token::freeze_account(CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        token::FreezeAccount {
+            account: ctx.accounts.token_account.to_account_info(),
+            mint: ctx.accounts.mint.to_account_info(),
+            authority: ctx.accounts.config.to_account_info(),
+        },
+        &[&[Config::SEED_PREFIX, &[ctx.accounts.config.bump]]],
+    ))?;
+
+    emit_event!(
+        ctx,
+        crate::event::TokenAccountFrozen {
+            token_account: ctx.accounts.token_account.key(),
+            guardian: ctx.accounts.guardian.key(),
+        }
+    );
+
+    Ok(())
+}
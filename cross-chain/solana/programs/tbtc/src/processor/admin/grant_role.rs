@@ -0,0 +1,48 @@
+use crate::{
+    error::TbtcError,
+    state::{Config, Role, RoleMember},
+};
+use anchor_lang::prelude::*;
+
+#[cfg_attr(feature = "event-cpi", event_cpi)]
+#[derive(Accounts)]
+#[instruction(role: Role)]
+pub struct GrantRole<'info> {
+    #[account(
+        seeds = [Config::SEED_PREFIX],
+        bump = config.bump,
+        has_one = authority @ TbtcError::IsNotAuthority
+    )]
+    config: Account<'info, Config>,
+
+    #[account(mut)]
+    authority: Signer<'info>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + RoleMember::INIT_SPACE,
+        seeds = [RoleMember::SEED_PREFIX, &[role as u8], member.key().as_ref()],
+        bump
+    )]
+    role_member: Account<'info, RoleMember>,
+
+    /// CHECK: Account being granted the role. This pubkey lives in `RoleMember`.
+    member: AccountInfo<'info>,
+
+    system_program: Program<'info, System>,
+}
+
+pub fn grant_role(ctx: Context<GrantRole>, role: Role) -> Result<()> {
+    let member = ctx.accounts.member.key();
+
+    ctx.accounts.role_member.set_inner(RoleMember {
+        bump: ctx.bumps["role_member"],
+        role,
+        member,
+    });
+
+    emit_event!(ctx, crate::event::RoleGranted { role, member });
+
+    Ok(())
+}
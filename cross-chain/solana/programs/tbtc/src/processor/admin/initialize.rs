@@ -1,6 +1,6 @@
 use crate::{
     constants::SEED_PREFIX_TBTC_MINT,
-    state::{Config, Guardians, Minters},
+    state::{Config, Guardians, MintRateLimit, Minters},
 };
 use anchor_lang::prelude::*;
 use anchor_spl::{metadata, token};
@@ -16,6 +16,7 @@ pub struct Initialize<'info> {
         payer = authority,
         mint::decimals = 8,
         mint::authority = config,
+        mint::freeze_authority = config,
     )]
     mint: Account<'info, token::Mint>,
 
@@ -46,6 +47,15 @@ pub struct Initialize<'info> {
     )]
     minters: Account<'info, Minters>,
 
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + MintRateLimit::INIT_SPACE,
+        seeds = [MintRateLimit::SEED_PREFIX],
+        bump,
+    )]
+    mint_rate_limit: Account<'info, MintRateLimit>,
+
     #[account(mut)]
     authority: Signer<'info>,
 
@@ -72,6 +82,11 @@ pub fn initialize(ctx: Context<Initialize>) -> Result<()> {
         num_minters: 0,
         num_guardians: 0,
         paused: false,
+        total_burned: 0,
+        pending_authority_since: 0,
+        authority_change_delay: 0,
+        pending_authority_change_delay: None,
+        pending_authority_change_delay_since: 0,
     });
 
     // Set Guardians account data with empty vec.
@@ -86,6 +101,15 @@ pub fn initialize(ctx: Context<Initialize>) -> Result<()> {
         keys: Vec::new(),
     });
 
+    // Minting is not rate limited until the authority configures a limit.
+    ctx.accounts.mint_rate_limit.set_inner(MintRateLimit {
+        bump: ctx.bumps["mint_rate_limit"],
+        capacity: u64::MAX,
+        window: 0,
+        available: u64::MAX,
+        last_updated: Clock::get()?.unix_timestamp,
+    });
+
     // Create metadata for tBTC.
     metadata::create_metadata_accounts_v3(
         CpiContext::new_with_signer(
@@ -0,0 +1,40 @@
+use super::realloc_account;
+use crate::{error::TbtcError, state::Config};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct MigrateConfig<'info> {
+    /// CHECK: Configs created with an older layout cannot be deserialized until they are
+    /// reallocated, so the authority is checked after the realloc.
+    #[account(
+        mut,
+        seeds = [Config::SEED_PREFIX],
+        bump,
+    )]
+    config: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    authority: Signer<'info>,
+
+    system_program: Program<'info, System>,
+}
+
+pub fn migrate_config(ctx: Context<MigrateConfig>) -> Result<()> {
+    let config = ctx.accounts.config.to_account_info();
+    realloc_account(
+        &config,
+        &ctx.accounts.authority,
+        &ctx.accounts.system_program,
+        8 + Config::INIT_SPACE,
+    )?;
+
+    // The appended fields start at zero, so only the authority needs to be checked.
+    let config = Account::<Config>::try_from(&config)?;
+    require_keys_eq!(
+        config.authority,
+        ctx.accounts.authority.key(),
+        TbtcError::IsNotAuthority
+    );
+
+    Ok(())
+}
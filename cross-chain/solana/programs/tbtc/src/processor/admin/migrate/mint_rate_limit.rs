@@ -0,0 +1,52 @@
+use crate::state::{MintRateLimit, Role, RoleMember};
+use anchor_lang::prelude::*;
+
+/// Programs initialized before the rate limit existed have no rate limit account, so every mint
+/// fails until it is created. This creates it with the same unlimited bucket as `initialize`.
+#[cfg_attr(feature = "event-cpi", event_cpi)]
+#[derive(Accounts)]
+pub struct MigrateMintRateLimit<'info> {
+    #[account(
+        seeds = [
+            RoleMember::SEED_PREFIX,
+            &[Role::LimitManager as u8],
+            limit_manager.key().as_ref()
+        ],
+        bump = role_member.bump,
+    )]
+    role_member: Account<'info, RoleMember>,
+
+    #[account(mut)]
+    limit_manager: Signer<'info>,
+
+    #[account(
+        init,
+        payer = limit_manager,
+        space = 8 + MintRateLimit::INIT_SPACE,
+        seeds = [MintRateLimit::SEED_PREFIX],
+        bump,
+    )]
+    mint_rate_limit: Account<'info, MintRateLimit>,
+
+    system_program: Program<'info, System>,
+}
+
+pub fn migrate_mint_rate_limit(ctx: Context<MigrateMintRateLimit>) -> Result<()> {
+    ctx.accounts.mint_rate_limit.set_inner(MintRateLimit {
+        bump: ctx.bumps["mint_rate_limit"],
+        capacity: u64::MAX,
+        window: 0,
+        available: u64::MAX,
+        last_updated: Clock::get()?.unix_timestamp,
+    });
+
+    emit_event!(
+        ctx,
+        crate::event::MintRateLimitUpdated {
+            capacity: u64::MAX,
+            window: 0
+        }
+    );
+
+    Ok(())
+}
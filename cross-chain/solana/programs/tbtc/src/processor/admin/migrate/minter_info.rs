@@ -0,0 +1,59 @@
+use super::realloc_account;
+use crate::state::{MinterInfo, Role, RoleMember};
+use anchor_lang::prelude::*;
+
+#[cfg_attr(feature = "event-cpi", event_cpi)]
+#[derive(Accounts)]
+pub struct MigrateMinterInfo<'info> {
+    #[account(
+        seeds = [
+            RoleMember::SEED_PREFIX,
+            &[Role::MinterAdmin as u8],
+            minter_admin.key().as_ref()
+        ],
+        bump = role_member.bump,
+    )]
+    role_member: Account<'info, RoleMember>,
+
+    #[account(mut)]
+    minter_admin: Signer<'info>,
+
+    /// CHECK: Minter infos created before allowances were added cannot be deserialized until they
+    /// are reallocated, so the data is checked after the realloc.
+    #[account(
+        mut,
+        seeds = [MinterInfo::SEED_PREFIX, minter.key().as_ref()],
+        bump,
+    )]
+    minter_info: UncheckedAccount<'info>,
+
+    /// CHECK: Required authority to mint tokens. This pubkey lives in `MinterInfo`.
+    minter: AccountInfo<'info>,
+
+    system_program: Program<'info, System>,
+}
+
+pub fn migrate_minter_info(ctx: Context<MigrateMinterInfo>, allowance: u64) -> Result<()> {
+    let minter_info = ctx.accounts.minter_info.to_account_info();
+    realloc_account(
+        &minter_info,
+        &ctx.accounts.minter_admin,
+        &ctx.accounts.system_program,
+        8 + MinterInfo::INIT_SPACE,
+    )?;
+
+    // The minter has not minted against an allowance yet, so only the allowance needs to be set.
+    let mut minter_info = Account::<MinterInfo>::try_from(&minter_info)?;
+    minter_info.allowance = allowance;
+    minter_info.exit(&crate::ID)?;
+
+    emit_event!(
+        ctx,
+        crate::event::MinterAllowanceUpdated {
+            minter: ctx.accounts.minter.key(),
+            allowance
+        }
+    );
+
+    Ok(())
+}
@@ -10,20 +10,50 @@ pub use cancel_authority_change::*;
 mod change_authority;
 pub use change_authority::*;
 
+mod freeze_account;
+pub use freeze_account::*;
+
+mod grant_role;
+pub use grant_role::*;
+
 mod initialize;
 pub use initialize::*;
 
+mod migrate;
+pub use migrate::*;
+
 mod pause;
 pub use pause::*;
 
+mod recover_tokens;
+pub use recover_tokens::*;
+
 mod remove_guardian;
 pub use remove_guardian::*;
 
 mod remove_minter;
 pub use remove_minter::*;
 
+mod revoke_role;
+pub use revoke_role::*;
+
+mod set_authority_change_delay;
+pub use set_authority_change_delay::*;
+
+mod set_mint_rate_limit;
+pub use set_mint_rate_limit::*;
+
+mod set_minter_allowance;
+pub use set_minter_allowance::*;
+
 mod take_authority;
 pub use take_authority::*;
 
+mod thaw_account;
+pub use thaw_account::*;
+
 mod unpause;
 pub use unpause::*;
+
+mod update_metadata;
+pub use update_metadata::*;
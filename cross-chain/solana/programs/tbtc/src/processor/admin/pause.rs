@@ -4,6 +4,7 @@ use crate::{
 };
 use anchor_lang::prelude::*;
 
+#[cfg_attr(feature = "event-cpi", event_cpi)]
 #[derive(Accounts)]
 pub struct Pause<'info> {
     #[account(
@@ -34,5 +35,13 @@ impl<'info> Pause<'info> {
 #[access_control(Pause::constraints(&ctx))]
 pub fn pause(ctx: Context<Pause>) -> Result<()> {
     ctx.accounts.config.paused = true;
+
+    emit_event!(
+        ctx,
+        crate::event::Paused {
+            guardian: ctx.accounts.guardian.key(),
+        }
+    );
+
     Ok(())
 }
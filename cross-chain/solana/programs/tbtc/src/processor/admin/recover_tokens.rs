@@ -0,0 +1,58 @@
+use crate::{error::TbtcError, state::Config};
+use anchor_lang::prelude::*;
+use anchor_spl::token;
+
+#[cfg_attr(feature = "event-cpi", event_cpi)]
+#[derive(Accounts)]
+pub struct RecoverTokens<'info> {
+    #[account(
+        has_one = authority @ TbtcError::IsNotAuthority,
+        seeds = [Config::SEED_PREFIX],
+        bump = config.bump,
+    )]
+    config: Account<'info, Config>,
+
+    authority: Signer<'info>,
+
+    /// Any token account owned by the config.
+    #[account(
+        mut,
+        token::authority = config,
+    )]
+    source_token: Account<'info, token::TokenAccount>,
+
+    #[account(
+        mut,
+        token::mint = source_token.mint,
+    )]
+    recipient_token: Account<'info, token::TokenAccount>,
+
+    token_program: Program<'info, token::Token>,
+}
+
+pub fn recover_tokens(ctx: Context<RecoverTokens>, amount: u64) -> Result<()> {
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            token::Transfer {
+                from: ctx.accounts.source_token.to_account_info(),
+                to: ctx.accounts.recipient_token.to_account_info(),
+                authority: ctx.accounts.config.to_account_info(),
+            },
+            &[&[Config::SEED_PREFIX, &[ctx.accounts.config.bump]]],
+        ),
+        amount,
+    )?;
+
+    emit_event!(
+        ctx,
+        crate::event::TokensRecovered {
+            mint: ctx.accounts.source_token.mint,
+            source_token: ctx.accounts.source_token.key(),
+            recipient_token: ctx.accounts.recipient_token.key(),
+            amount,
+        }
+    );
+
+    Ok(())
+}
@@ -1,26 +1,31 @@
-use crate::{
-    error::TbtcError,
-    state::{Config, GuardianInfo, Guardians},
-};
+use crate::state::{Config, GuardianInfo, Guardians, Role, RoleMember};
 use anchor_lang::prelude::*;
 
+#[cfg_attr(feature = "event-cpi", event_cpi)]
 #[derive(Accounts)]
 pub struct RemoveGuardian<'info> {
+    #[account(mut)]
+    config: Account<'info, Config>,
+
     #[account(
-        mut,
-        has_one = authority @ TbtcError::IsNotAuthority,
+        seeds = [
+            RoleMember::SEED_PREFIX,
+            &[Role::GuardianAdmin as u8],
+            guardian_admin.key().as_ref()
+        ],
+        bump = role_member.bump,
     )]
-    config: Account<'info, Config>,
+    role_member: Account<'info, RoleMember>,
 
     #[account(mut)]
-    authority: Signer<'info>,
+    guardian_admin: Signer<'info>,
 
     #[account(
         mut,
         seeds = [Guardians::SEED_PREFIX],
         bump = guardians.bump,
         realloc = Guardians::compute_size(guardians.keys.len().saturating_sub(1)),
-        realloc::payer = authority,
+        realloc::payer = guardian_admin,
         realloc::zero = true,
     )]
     guardians: Account<'info, Guardians>,
@@ -28,7 +33,7 @@ pub struct RemoveGuardian<'info> {
     #[account(
         mut,
         has_one = guardian,
-        close = authority,
+        close = guardian_admin,
         seeds = [GuardianInfo::SEED_PREFIX, guardian.key().as_ref()],
         bump = guardian_info.bump,
     )]
@@ -57,7 +62,7 @@ pub fn remove_guardian(ctx: Context<RemoveGuardian>) -> Result<()> {
     // Update config.
     ctx.accounts.config.num_guardians -= 1;
 
-    emit!(crate::event::GuardianRemoved { guardian: removed });
+    emit_event!(ctx, crate::event::GuardianRemoved { guardian: removed });
 
     Ok(())
 }
@@ -1,28 +1,35 @@
-use crate::{
-    error::TbtcError,
-    state::{Config, MinterInfo, Minters},
-};
+use crate::state::{Config, MinterInfo, Minters, Role, RoleMember};
 use anchor_lang::prelude::*;
 
+#[cfg_attr(feature = "event-cpi", event_cpi)]
 #[derive(Accounts)]
 pub struct RemoveMinter<'info> {
     #[account(
         mut,
         seeds = [Config::SEED_PREFIX],
         bump,
-        has_one = authority @ TbtcError::IsNotAuthority
     )]
     config: Account<'info, Config>,
 
+    #[account(
+        seeds = [
+            RoleMember::SEED_PREFIX,
+            &[Role::MinterAdmin as u8],
+            minter_admin.key().as_ref()
+        ],
+        bump = role_member.bump,
+    )]
+    role_member: Account<'info, RoleMember>,
+
     #[account(mut)]
-    authority: Signer<'info>,
+    minter_admin: Signer<'info>,
 
     #[account(
         mut,
         seeds = [Minters::SEED_PREFIX],
         bump = minters.bump,
         realloc = Minters::compute_size(minters.keys.len().saturating_sub(1)),
-        realloc::payer = authority,
+        realloc::payer = minter_admin,
         realloc::zero = true,
     )]
     minters: Account<'info, Minters>,
@@ -30,7 +37,7 @@ pub struct RemoveMinter<'info> {
     #[account(
         mut,
         has_one = minter,
-        close = authority,
+        close = minter_admin,
         seeds = [MinterInfo::SEED_PREFIX, minter.key().as_ref()],
         bump = minter_info.bump,
     )]
@@ -59,7 +66,7 @@ pub fn remove_minter(ctx: Context<RemoveMinter>) -> Result<()> {
     // Update config.
     ctx.accounts.config.num_minters -= 1;
 
-    emit!(crate::event::MinterRemoved { minter: removed });
+    emit_event!(ctx, crate::event::MinterRemoved { minter: removed });
 
     Ok(())
 }
@@ -0,0 +1,53 @@
+use crate::{error::TbtcError, state::Config};
+use anchor_lang::prelude::*;
+
+#[cfg_attr(feature = "event-cpi", event_cpi)]
+#[derive(Accounts)]
+pub struct SetAuthorityChangeDelay<'info> {
+    #[account(
+        mut,
+        seeds = [Config::SEED_PREFIX],
+        bump = config.bump,
+        has_one = authority @ TbtcError::IsNotAuthority,
+    )]
+    config: Account<'info, Config>,
+
+    authority: Signer<'info>,
+}
+
+pub fn set_authority_change_delay(ctx: Context<SetAuthorityChangeDelay>, delay: u32) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+
+    // Lowering the delay is itself delayed by the current delay, so that the authority cannot
+    // shorten it right before changing authority. The lower delay is requested first and set by
+    // calling this instruction again with the same delay once the current delay has elapsed.
+    if delay < config.authority_change_delay {
+        let now = Clock::get()?.unix_timestamp;
+
+        if config.pending_authority_change_delay != Some(delay) {
+            config.pending_authority_change_delay = Some(delay);
+            config.pending_authority_change_delay_since = now;
+
+            emit_event!(
+                ctx,
+                crate::event::AuthorityChangeDelayDecreaseRequested { delay }
+            );
+
+            return Ok(());
+        }
+
+        require_gte!(
+            now.saturating_sub(config.pending_authority_change_delay_since),
+            i64::from(config.authority_change_delay),
+            TbtcError::AuthorityChangeDelayNotElapsed
+        );
+    }
+
+    config.authority_change_delay = delay;
+    config.pending_authority_change_delay = None;
+    config.pending_authority_change_delay_since = 0;
+
+    emit_event!(ctx, crate::event::AuthorityChangeDelayUpdated { delay });
+
+    Ok(())
+}
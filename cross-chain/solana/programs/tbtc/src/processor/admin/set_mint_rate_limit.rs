@@ -0,0 +1,58 @@
+use crate::state::{MintRateLimit, Role, RoleMember};
+use anchor_lang::prelude::*;
+
+#[cfg_attr(feature = "event-cpi", event_cpi)]
+#[derive(Accounts)]
+pub struct SetMintRateLimit<'info> {
+    #[account(
+        seeds = [
+            RoleMember::SEED_PREFIX,
+            &[Role::LimitManager as u8],
+            limit_manager.key().as_ref()
+        ],
+        bump = role_member.bump,
+    )]
+    role_member: Account<'info, RoleMember>,
+
+    #[account(mut)]
+    limit_manager: Signer<'info>,
+
+    /// NOTE: This account is created by the initialize instruction. It is initialized here if
+    /// needed to support programs that were initialized before the rate limit existed.
+    #[account(
+        init_if_needed,
+        payer = limit_manager,
+        space = 8 + MintRateLimit::INIT_SPACE,
+        seeds = [MintRateLimit::SEED_PREFIX],
+        bump,
+    )]
+    mint_rate_limit: Account<'info, MintRateLimit>,
+
+    system_program: Program<'info, System>,
+}
+
+#[derive(Debug, Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct SetMintRateLimitArgs {
+    capacity: u64,
+    window: u32,
+}
+
+pub fn set_mint_rate_limit(
+    ctx: Context<SetMintRateLimit>,
+    args: SetMintRateLimitArgs,
+) -> Result<()> {
+    let SetMintRateLimitArgs { capacity, window } = args;
+
+    // Updating the limit starts a new window with a full bucket.
+    ctx.accounts.mint_rate_limit.set_inner(MintRateLimit {
+        bump: ctx.bumps["mint_rate_limit"],
+        capacity,
+        window,
+        available: capacity,
+        last_updated: Clock::get()?.unix_timestamp,
+    });
+
+    emit_event!(ctx, crate::event::MintRateLimitUpdated { capacity, window });
+
+    Ok(())
+}
@@ -0,0 +1,45 @@
+use crate::state::{MinterInfo, Role, RoleMember};
+use anchor_lang::prelude::*;
+
+#[cfg_attr(feature = "event-cpi", event_cpi)]
+#[derive(Accounts)]
+pub struct SetMinterAllowance<'info> {
+    #[account(
+        seeds = [
+            RoleMember::SEED_PREFIX,
+            &[Role::MinterAdmin as u8],
+            minter_admin.key().as_ref()
+        ],
+        bump = role_member.bump,
+    )]
+    role_member: Account<'info, RoleMember>,
+
+    minter_admin: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = minter,
+        seeds = [MinterInfo::SEED_PREFIX, minter.key().as_ref()],
+        bump = minter_info.bump,
+    )]
+    minter_info: Account<'info, MinterInfo>,
+
+    /// CHECK: Required authority to mint tokens. This pubkey lives in `MinterInfo`.
+    minter: AccountInfo<'info>,
+}
+
+pub fn set_minter_allowance(ctx: Context<SetMinterAllowance>, allowance: u64) -> Result<()> {
+    // The minted amount is kept as-is. Setting an allowance below it prevents the minter from
+    // minting any more tBTC.
+    ctx.accounts.minter_info.allowance = allowance;
+
+    emit_event!(
+        ctx,
+        crate::event::MinterAllowanceUpdated {
+            minter: ctx.accounts.minter.key(),
+            allowance
+        }
+    );
+
+    Ok(())
+}
@@ -1,6 +1,7 @@
 use crate::{error::TbtcError, state::Config};
 use anchor_lang::prelude::*;
 
+#[cfg_attr(feature = "event-cpi", event_cpi)]
 #[derive(Accounts)]
 pub struct TakeAuthority<'info> {
     #[account(
@@ -23,6 +24,17 @@ impl<'info> TakeAuthority<'info> {
                     TbtcError::IsNotPendingAuthority
                 );
 
+                // The pending authority can only take over after the delay has elapsed.
+                let config = &ctx.accounts.config;
+                let elapsed = Clock::get()?
+                    .unix_timestamp
+                    .saturating_sub(config.pending_authority_since);
+                require_gte!(
+                    elapsed,
+                    i64::from(config.authority_change_delay),
+                    TbtcError::AuthorityChangeDelayNotElapsed
+                );
+
                 Ok(())
             }
             None => err!(TbtcError::NoPendingAuthorityChange),
@@ -32,7 +44,20 @@ impl<'info> TakeAuthority<'info> {
 
 #[access_control(TakeAuthority::constraints(&ctx))]
 pub fn take_authority(ctx: Context<TakeAuthority>) -> Result<()> {
-    ctx.accounts.config.authority = ctx.accounts.pending_authority.key();
-    ctx.accounts.config.pending_authority = None;
+    let config = &mut ctx.accounts.config;
+    let old_authority = config.authority;
+
+    config.authority = ctx.accounts.pending_authority.key();
+    config.pending_authority = None;
+    config.pending_authority_since = 0;
+
+    emit_event!(
+        ctx,
+        crate::event::AuthorityTaken {
+            old_authority,
+            new_authority: config.authority,
+        }
+    );
+
     Ok(())
 }
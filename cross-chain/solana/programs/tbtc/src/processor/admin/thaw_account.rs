@@ -0,0 +1,52 @@
+use crate::{constants::SEED_PREFIX_TBTC_MINT, error::TbtcError, state::Config};
+use anchor_lang::prelude::*;
+use anchor_spl::token;
+
+#[cfg_attr(feature = "event-cpi", event_cpi)]
+#[derive(Accounts)]
+pub struct ThawAccount<'info> {
+    #[account(
+        seeds = [SEED_PREFIX_TBTC_MINT],
+        bump = config.mint_bump,
+        mint::freeze_authority = config,
+    )]
+    mint: Account<'info, token::Mint>,
+
+    #[account(
+        has_one = authority @ TbtcError::IsNotAuthority,
+        seeds = [Config::SEED_PREFIX],
+        bump = config.bump,
+    )]
+    config: Account<'info, Config>,
+
+    authority: Signer<'info>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+    )]
+    token_account: Account<'info, token::TokenAccount>,
+
+    token_program: Program<'info, token::Token>,
+}
+
+pub fn thaw_account(ctx: Context<ThawAccount>) -> Result<()> {
+    token::thaw_account(CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        token::ThawAccount {
+            account: ctx.accounts.token_account.to_account_info(),
+            mint: ctx.accounts.mint.to_account_info(),
+            authority: ctx.accounts.config.to_account_info(),
+        },
+        &[&[Config::SEED_PREFIX, &[ctx.accounts.config.bump]]],
+    ))?;
+
+    emit_event!(
+        ctx,
+        crate::event::TokenAccountThawed {
+            token_account: ctx.accounts.token_account.key(),
+        }
+    );
+
+    Ok(())
+}
@@ -1,17 +1,30 @@
-use crate::{error::TbtcError, state::Config};
+use crate::{
+    error::TbtcError,
+    state::{Config, Role, RoleMember},
+};
 use anchor_lang::prelude::*;
 
+#[cfg_attr(feature = "event-cpi", event_cpi)]
 #[derive(Accounts)]
 pub struct Unpause<'info> {
     #[account(
         mut,
-        has_one = authority @ TbtcError::IsNotAuthority,
         seeds = [Config::SEED_PREFIX],
         bump,
     )]
     config: Account<'info, Config>,
 
-    authority: Signer<'info>,
+    #[account(
+        seeds = [
+            RoleMember::SEED_PREFIX,
+            &[Role::Unpauser as u8],
+            unpauser.key().as_ref()
+        ],
+        bump = role_member.bump,
+    )]
+    role_member: Account<'info, RoleMember>,
+
+    unpauser: Signer<'info>,
 }
 
 impl<'info> Unpause<'info> {
@@ -25,5 +38,13 @@ impl<'info> Unpause<'info> {
 #[access_control(Unpause::constraints(&ctx))]
 pub fn unpause(ctx: Context<Unpause>) -> Result<()> {
     ctx.accounts.config.paused = false;
+
+    emit_event!(
+        ctx,
+        crate::event::Unpaused {
+            unpauser: ctx.accounts.unpauser.key(),
+        }
+    );
+
     Ok(())
 }
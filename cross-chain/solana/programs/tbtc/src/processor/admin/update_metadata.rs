@@ -0,0 +1,61 @@
+use crate::{error::TbtcError, state::Config};
+use anchor_lang::prelude::*;
+use anchor_spl::metadata;
+
+#[derive(Debug, Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct UpdateMetadataArgs {
+    pub name: String,
+    pub symbol: String,
+    pub uri: String,
+}
+
+#[cfg_attr(feature = "event-cpi", event_cpi)]
+#[derive(Accounts)]
+pub struct UpdateMetadata<'info> {
+    #[account(
+        has_one = authority @ TbtcError::IsNotAuthority,
+        seeds = [Config::SEED_PREFIX],
+        bump = config.bump,
+    )]
+    config: Account<'info, Config>,
+
+    authority: Signer<'info>,
+
+    /// CHECK: This account is needed for the MPL Token Metadata program, which verifies that the
+    /// config is its update authority.
+    #[account(mut)]
+    tbtc_metadata: UncheckedAccount<'info>,
+
+    mpl_token_metadata_program: Program<'info, metadata::Metadata>,
+}
+
+pub fn update_metadata(ctx: Context<UpdateMetadata>, args: UpdateMetadataArgs) -> Result<()> {
+    let UpdateMetadataArgs { name, symbol, uri } = args;
+
+    metadata::update_metadata_accounts_v2(
+        CpiContext::new_with_signer(
+            ctx.accounts.mpl_token_metadata_program.to_account_info(),
+            metadata::UpdateMetadataAccountsV2 {
+                metadata: ctx.accounts.tbtc_metadata.to_account_info(),
+                update_authority: ctx.accounts.config.to_account_info(),
+            },
+            &[&[Config::SEED_PREFIX, &[ctx.accounts.config.bump]]],
+        ),
+        None,
+        Some(mpl_token_metadata::state::DataV2 {
+            symbol: symbol.clone(),
+            name: name.clone(),
+            uri: uri.clone(),
+            seller_fee_basis_points: 0,
+            creators: None,
+            collection: None,
+            uses: None,
+        }),
+        None,
+        None,
+    )?;
+
+    emit_event!(ctx, crate::event::MetadataUpdated { name, symbol, uri });
+
+    Ok(())
+}
@@ -0,0 +1,71 @@
+use crate::{constants::SEED_PREFIX_TBTC_MINT, error::TbtcError, state::Config};
+use anchor_lang::prelude::*;
+use anchor_spl::token;
+
+#[cfg_attr(feature = "event-cpi", event_cpi)]
+#[derive(Accounts)]
+pub struct Burn<'info> {
+    // Use the correct token mint for the program.
+    #[account(
+        mut,
+        seeds = [SEED_PREFIX_TBTC_MINT],
+        bump = config.mint_bump,
+    )]
+    mint: Account<'info, token::Mint>,
+
+    #[account(
+        mut,
+        seeds = [Config::SEED_PREFIX],
+        bump = config.bump,
+    )]
+    config: Account<'info, Config>,
+
+    owner: Signer<'info>,
+
+    // Burn from the owner's token account.
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = owner,
+    )]
+    owner_token: Account<'info, token::TokenAccount>,
+
+    token_program: Program<'info, token::Token>,
+}
+
+impl<'info> Burn<'info> {
+    fn constraints(ctx: &Context<Self>) -> Result<()> {
+        // Can not burn when paused.
+        require!(!ctx.accounts.config.paused, TbtcError::IsPaused);
+
+        Ok(())
+    }
+}
+
+#[access_control(Burn::constraints(&ctx))]
+pub fn burn(ctx: Context<Burn>, amount: u64) -> Result<()> {
+    token::burn(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            token::Burn {
+                mint: ctx.accounts.mint.to_account_info(),
+                from: ctx.accounts.owner_token.to_account_info(),
+                authority: ctx.accounts.owner.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+
+    let config = &mut ctx.accounts.config;
+    config.total_burned = config.total_burned.saturating_add(amount);
+
+    emit_event!(
+        ctx,
+        crate::event::Burned {
+            owner: ctx.accounts.owner.key(),
+            amount,
+        }
+    );
+
+    Ok(())
+}
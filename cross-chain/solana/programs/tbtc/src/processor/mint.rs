@@ -1,12 +1,13 @@
 use crate::{
     constants::SEED_PREFIX_TBTC_MINT,
     error::TbtcError,
-    state::{Config, MinterInfo},
+    state::{Config, MintRateLimit, MinterInfo},
 };
 use anchor_lang::prelude::*;
 use anchor_spl::token;
 
 #[derive(Accounts)]
+#[instruction(amount: u64)]
 pub struct Mint<'info> {
     // Use the correct token mint for the program.
     #[account(
@@ -25,6 +26,7 @@ pub struct Mint<'info> {
 
     // Require the signing minter to match a valid minter info.
     #[account(
+        mut,
         has_one = minter,
         seeds = [MinterInfo::SEED_PREFIX, minter.key().as_ref()],
         bump = minter_info.bump,
@@ -33,6 +35,13 @@ pub struct Mint<'info> {
 
     minter: Signer<'info>,
 
+    #[account(
+        mut,
+        seeds = [MintRateLimit::SEED_PREFIX],
+        bump = mint_rate_limit.bump,
+    )]
+    mint_rate_limit: Account<'info, MintRateLimit>,
+
     // Use the associated token account for the recipient.
     #[account(
         mut,
@@ -44,16 +53,32 @@ pub struct Mint<'info> {
 }
 
 impl<'info> Mint<'info> {
-    fn constraints(ctx: &Context<Self>) -> Result<()> {
+    fn constraints(ctx: &Context<Self>, amount: u64) -> Result<()> {
         // Can not mint when paused.
         require!(!ctx.accounts.config.paused, TbtcError::IsPaused);
 
+        // Can not mint more than the minter's remaining allowance.
+        require_gte!(
+            ctx.accounts.minter_info.remaining_allowance(),
+            amount,
+            TbtcError::MintAllowanceExceeded
+        );
+
         Ok(())
     }
 }
 
-#[access_control(Mint::constraints(&ctx))]
+#[access_control(Mint::constraints(&ctx, amount))]
 pub fn mint(ctx: Context<Mint>, amount: u64) -> Result<()> {
+    // Account for minted amount. This cannot overflow because the allowance check in access
+    // control guarantees the updated amount is not greater than the allowance.
+    ctx.accounts.minter_info.minted += amount;
+
+    // Draw the amount from the rate limit shared by all minters.
+    ctx.accounts
+        .mint_rate_limit
+        .consume(amount, Clock::get()?.unix_timestamp)?;
+
     token::mint_to(
         CpiContext::new_with_signer(
             ctx.accounts.token_program.to_account_info(),
@@ -1,5 +1,11 @@
 mod admin;
 pub use admin::*;
 
+mod burn;
+pub use burn::*;
+
+mod burn_from;
+pub use burn_from::*;
+
 mod mint;
 pub use mint::*;
@@ -1,5 +1,7 @@
 use anchor_lang::prelude::*;
 
+/// NOTE: New fields must be appended, so that configs created with an older layout can be migrated
+/// with `migrate_config`.
 #[account]
 #[derive(Debug, InitSpace)]
 pub struct Config {
@@ -17,6 +19,21 @@ pub struct Config {
     pub num_minters: u32,
     pub num_guardians: u32,
     pub paused: bool,
+
+    /// Total amount of tBTC burned through this program.
+    pub total_burned: u64,
+
+    /// When the pending authority was set.
+    pub pending_authority_since: i64,
+
+    /// Seconds the pending authority must wait before taking authority.
+    pub authority_change_delay: u32,
+
+    /// Lower authority change delay, which takes effect once the current delay has elapsed.
+    pub pending_authority_change_delay: Option<u32>,
+
+    /// When the lower authority change delay was requested.
+    pub pending_authority_change_delay_since: i64,
 }
 
 impl Config {
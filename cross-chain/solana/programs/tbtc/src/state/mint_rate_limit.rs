@@ -0,0 +1,129 @@
+use crate::error::TbtcError;
+use anchor_lang::prelude::*;
+
+/// Token bucket limiting how much tBTC can be minted across all minters over a rolling window.
+///
+/// The bucket holds up to `capacity` tBTC and refills linearly, so that a full `capacity` becomes
+/// available again after `window` seconds. A zero window refills the bucket instantly, in which
+/// case `capacity` only caps the amount of a single mint.
+#[account]
+#[derive(Debug, InitSpace)]
+pub struct MintRateLimit {
+    pub bump: u8,
+
+    /// Maximum amount of tBTC that can be minted within one window.
+    pub capacity: u64,
+    /// Length of the window in seconds.
+    pub window: u32,
+
+    /// Amount of tBTC that can currently be minted.
+    pub available: u64,
+    /// Unix timestamp of the last refill.
+    pub last_updated: i64,
+}
+
+impl MintRateLimit {
+    pub const SEED_PREFIX: &'static [u8] = b"mint-rate-limit";
+
+    /// Amount of tBTC that can be minted at `now`, accounting for the time elapsed since the last
+    /// update.
+    pub fn available_at(&self, now: i64) -> u64 {
+        if self.window == 0 {
+            self.capacity
+        } else {
+            let elapsed = u64::try_from(now.saturating_sub(self.last_updated)).unwrap_or_default();
+            let refilled =
+                u128::from(self.capacity) * u128::from(elapsed) / u128::from(self.window);
+            u64::try_from(refilled)
+                .map(|refilled| self.available.saturating_add(refilled))
+                .unwrap_or(u64::MAX)
+                .min(self.capacity)
+        }
+    }
+
+    /// Refill the bucket for the time elapsed since the last update.
+    ///
+    /// Refills are truncated to whole units, so the last update only advances by the time needed
+    /// to refill the credited amount. Otherwise frequent updates would keep losing refill time.
+    pub(crate) fn refill(&mut self, now: i64) {
+        let available = self.available_at(now);
+        if self.window == 0 || available == self.capacity {
+            self.last_updated = now;
+        } else {
+            // Round up, so that the time credited never exceeds the time elapsed.
+            let refilled = u128::from(available - self.available);
+            let capacity = u128::from(self.capacity);
+            let credited = (refilled * u128::from(self.window) + capacity - 1) / capacity;
+            self.last_updated = self.last_updated.saturating_add(credited as i64);
+        }
+        self.available = available;
+    }
+
+    /// Take `amount` out of the bucket, failing if there is not enough available.
+    pub(crate) fn consume(&mut self, amount: u64, now: i64) -> Result<()> {
+        self.refill(now);
+
+        self.available = self
+            .available
+            .checked_sub(amount)
+            .ok_or(TbtcError::RateLimitExceeded)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rate_limit(capacity: u64, window: u32) -> MintRateLimit {
+        MintRateLimit {
+            bump: 0,
+            capacity,
+            window,
+            available: 0,
+            last_updated: 0,
+        }
+    }
+
+    #[test]
+    fn refill_keeps_partial_refill_time() {
+        // One unit refills every 36 seconds.
+        let mut limit = rate_limit(100, 3600);
+
+        limit.refill(20);
+        assert_eq!(limit.available, 0);
+        assert_eq!(limit.last_updated, 0);
+
+        limit.refill(40);
+        assert_eq!(limit.available, 1);
+        assert_eq!(limit.last_updated, 36);
+
+        limit.refill(72);
+        assert_eq!(limit.available, 2);
+        assert_eq!(limit.last_updated, 72);
+    }
+
+    #[test]
+    fn refill_rounds_credited_time_up() {
+        // Three units refill every 10 seconds.
+        let mut limit = rate_limit(3, 10);
+
+        limit.refill(4);
+        assert_eq!(limit.available, 1);
+        assert_eq!(limit.last_updated, 4);
+
+        limit.refill(7);
+        assert_eq!(limit.available, 1);
+        assert_eq!(limit.last_updated, 4);
+    }
+
+    #[test]
+    fn refill_full_bucket_resets_last_updated() {
+        let mut limit = rate_limit(100, 3600);
+
+        limit.refill(10_000);
+        assert_eq!(limit.available, 100);
+        assert_eq!(limit.last_updated, 10_000);
+    }
+}
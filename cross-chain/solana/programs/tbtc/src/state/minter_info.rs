@@ -5,8 +5,18 @@ use anchor_lang::prelude::*;
 pub struct MinterInfo {
     pub minter: Pubkey,
     pub bump: u8,
+
+    /// Maximum amount of tBTC this minter is allowed to mint over its lifetime.
+    pub allowance: u64,
+    /// Amount of tBTC this minter has minted so far.
+    pub minted: u64,
 }
 
 impl MinterInfo {
     pub const SEED_PREFIX: &'static [u8] = b"minter-info";
+
+    /// Amount of tBTC this minter can still mint before hitting its allowance.
+    pub fn remaining_allowance(&self) -> u64 {
+        self.allowance.saturating_sub(self.minted)
+    }
 }
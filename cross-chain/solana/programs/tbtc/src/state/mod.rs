@@ -7,8 +7,14 @@ pub use guardian_info::*;
 mod guardians;
 pub use guardians::*;
 
+mod mint_rate_limit;
+pub use mint_rate_limit::*;
+
 mod minter_info;
 pub use minter_info::*;
 
 mod minters;
 pub use minters::*;
+
+mod role_member;
+pub use role_member::*;
@@ -0,0 +1,26 @@
+use anchor_lang::prelude::*;
+
+/// Roles the authority can grant to narrow down who can perform each admin action.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, AnchorSerialize, AnchorDeserialize, InitSpace)]
+pub enum Role {
+    /// Can add and remove minters and set their allowances.
+    MinterAdmin,
+    /// Can add and remove guardians.
+    GuardianAdmin,
+    /// Can set the mint rate limit.
+    LimitManager,
+    /// Can unpause the program.
+    Unpauser,
+}
+
+#[account]
+#[derive(Debug, InitSpace)]
+pub struct RoleMember {
+    pub bump: u8,
+    pub role: Role,
+    pub member: Pubkey,
+}
+
+impl RoleMember {
+    pub const SEED_PREFIX: &'static [u8] = b"role-member";
+}
@@ -14,20 +14,38 @@ pub enum WormholeGatewayError {
     #[msg("No pending authority")]
     NoPendingAuthorityChange = 0x24,
 
+    #[msg("Authority change delay has not elapsed")]
+    AuthorityChangeDelayNotElapsed = 0x26,
+
     #[msg("0x0 recipient not allowed")]
     ZeroRecipient = 0x30,
 
+    #[msg("Recipient chain is not allowed")]
+    RecipientChainNotAllowed = 0x32,
+
+    #[msg("Recipient is not a left-padded 20-byte EVM address")]
+    InvalidEvmRecipient = 0x34,
+
     #[msg("Not enough wormhole tBTC in the gateway to bridge")]
     NotEnoughWrappedTbtc = 0x40,
 
     #[msg("Amount must not be 0")]
     ZeroAmount = 0x50,
 
+    #[msg("Gateway is paused")]
+    IsPaused = 0x60,
+
+    #[msg("Gateway is not paused")]
+    IsNotPaused = 0x62,
+
     #[msg("Token Bridge transfer already redeemed")]
     TransferAlreadyRedeemed = 0x70,
 
-    #[msg("Token chain and address do not match Ethereum's tBTC")]
-    InvalidEthereumTbtc = 0x80,
+    #[msg("Token chain and address do not match the canonical tBTC")]
+    InvalidCanonicalToken = 0x80,
+
+    #[msg("Transfer was not sent by the registered gateway of the source chain")]
+    UnknownSourceGateway = 0x82,
 
     #[msg("No tBTC transferred")]
     NoTbtcTransferred = 0x90,
@@ -40,4 +58,67 @@ pub enum WormholeGatewayError {
 
     #[msg("Minted amount after deposit exceeds u64")]
     MintedAmountOverflow = 0xb2,
+
+    #[msg("Pending amount after queueing exceeds u64")]
+    PendingAmountOverflow = 0xb4,
+
+    #[msg("Not enough minted for the destination gateway's chain to satisfy sending tBTC")]
+    GatewayMintedAmountUnderflow = 0xb6,
+
+    #[msg("Amount exceeds the wrapped tBTC held in excess of the minted amount")]
+    RecoverAmountExceedsExcess = 0xc0,
+
+    #[msg("No pending mint to claim")]
+    NoPendingMint = 0xd0,
+
+    #[msg("No pending canonical token update")]
+    NoPendingCanonicalTokenUpdate = 0xe0,
+
+    #[msg("Canonical token does not match the pending update")]
+    CanonicalTokenMismatch = 0xe2,
+
+    #[msg("Canonical token update delay has not elapsed")]
+    CanonicalTokenUpdateDelayNotElapsed = 0xe4,
+
+    #[msg("Wrapped tBTC custody must be empty to update the canonical token")]
+    CustodyNotEmpty = 0xe6,
+
+    #[msg("Gateway is disabled")]
+    GatewayDisabled = 0xf0,
+
+    #[msg("L1 redeemer is not set")]
+    L1RedeemerNotSet = 0xf2,
+
+    #[msg("BTC depositor accounts must all be provided to finalize a deposit")]
+    IncompleteDepositAccounts = 0xf4,
+
+    #[msg("Unsupported transfer payload version")]
+    UnsupportedPayloadVersion = 0xf6,
+
+    #[msg("Target program does not match the transfer payload")]
+    CallTargetMismatch = 0xf8,
+
+    #[msg("Deposit record does not match the transfer payload")]
+    DepositRecordMismatch = 0xfa,
+
+    #[msg("Maximum relayer fee cannot exceed 10000 basis points")]
+    MaxRelayerFeeTooHigh = 0xfc,
+
+    #[msg("Account is already migrated")]
+    AlreadyMigrated = 0xfe,
+
+    #[msg("Gateway cannot be removed while tBTC minted for its chain is outstanding")]
+    GatewayMintedAmountNotZero = 0x100,
+
+    #[msg("Only the payload sender is permitted to send tBTC with a payload")]
+    IsNotPayloadSender = 0x102,
+
+    #[msg("Transfer was not sent by the L1 BTC depositor")]
+    UnknownL1BtcDepositor = 0x104,
+
+    #[msg("Only the recipient or the custodian authority is permitted for this action")]
+    IsNotRecipientOrAuthority = 0x106,
+
+    #[msg("BTC Depositor config is not the BTC Depositor program's config")]
+    InvalidBtcDepositorConfig = 0x108,
 }
@@ -1,11 +1,125 @@
+use crate::state::{CanonicalToken, ReceiveMode, RecipientFormat, Role};
 use anchor_lang::prelude::*;
 
+/// Emits an event through a self-CPI when the `event-cpi` feature is enabled, so indexers can read
+/// it from instruction data even when program logs are truncated. Otherwise the event is written
+/// to program logs with `emit!`.
+///
+/// NOTE: The instruction's accounts must be annotated with `event_cpi` when the feature is enabled.
+macro_rules! emit_event {
+    ($ctx:ident, $event:expr) => {{
+        let event = $event;
+
+        #[cfg(feature = "event-cpi")]
+        {
+            let ctx = &$ctx;
+            anchor_lang::prelude::emit_cpi!(event);
+        }
+
+        #[cfg(not(feature = "event-cpi"))]
+        anchor_lang::prelude::emit!(event);
+    }};
+}
+
+#[event]
+pub struct AuthorityChangeRequested {
+    pub authority: Pubkey,
+    pub pending_authority: Pubkey,
+}
+
+#[event]
+pub struct AuthorityChangeCancelled {
+    pub authority: Pubkey,
+    pub pending_authority: Pubkey,
+}
+
+#[event]
+pub struct AuthorityTaken {
+    pub old_authority: Pubkey,
+    pub new_authority: Pubkey,
+}
+
+#[event]
+pub struct AuthorityChangeDelayDecreaseRequested {
+    pub delay: u32,
+}
+
+#[event]
+pub struct AuthorityChangeDelayUpdated {
+    pub delay: u32,
+}
+
+#[event]
+pub struct RoleGranted {
+    pub role: Role,
+    pub member: Pubkey,
+}
+
+#[event]
+pub struct RoleRevoked {
+    pub role: Role,
+    pub member: Pubkey,
+}
+
+#[event]
+pub struct Paused {
+    pub guardian: Pubkey,
+}
+
+#[event]
+pub struct Unpaused {
+    pub unpauser: Pubkey,
+}
+
 #[event]
 pub struct WormholeTbtcReceived {
     pub receiver: Pubkey,
     pub amount: u64,
 }
 
+#[event]
+pub struct WormholeTbtcReceivedSplit {
+    pub receiver: Pubkey,
+    pub minted_amount: u64,
+    pub wrapped_amount: u64,
+    pub queued_amount: u64,
+}
+
+#[event]
+pub struct PendingMintQueued {
+    pub recipient: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct PendingMintClaimed {
+    pub recipient: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct RelayerFeePaid {
+    pub relayer: Pubkey,
+    pub receiver: Pubkey,
+    pub fee: u64,
+}
+
+#[event]
+pub struct WormholeTbtcReceivedAndCalled {
+    pub receiver: Pubkey,
+    pub amount: u64,
+    pub target_program: Pubkey,
+    pub source_chain: u16,
+    pub sender: [u8; 32],
+}
+
+#[event]
+pub struct WormholeTbtcReceivedWithoutCall {
+    pub receiver: Pubkey,
+    pub amount: u64,
+    pub target_program: Pubkey,
+}
+
 #[event]
 pub struct WormholeTbtcSent {
     pub amount: u64,
@@ -16,19 +130,113 @@ pub struct WormholeTbtcSent {
     pub nonce: u32,
 }
 
+#[event]
+pub struct WormholeTbtcSentWithPayload {
+    pub sender: Pubkey,
+    pub amount: u64,
+    pub recipient_chain: u16,
+    pub l1_redeemer: [u8; 32],
+    pub nonce: u32,
+    pub payload: Vec<u8>,
+}
+
 #[event]
 pub struct WormholeTbtcDeposited {
     pub depositor: Pubkey,
     pub amount: u64,
 }
 
+#[event]
+pub struct AllowedChainUpdated {
+    pub chain: u16,
+    pub recipient_format: RecipientFormat,
+}
+
+#[event]
+pub struct AllowedChainRemoved {
+    pub chain: u16,
+}
+
+#[event]
+pub struct CallTargetAdded {
+    pub program: Pubkey,
+}
+
+#[event]
+pub struct CallTargetRemoved {
+    pub program: Pubkey,
+}
+
 #[event]
 pub struct GatewayAddressUpdated {
     pub chain: u16,
     pub gateway: [u8; 32],
 }
 
+#[event]
+pub struct GatewayAddressRemoved {
+    pub chain: u16,
+}
+
+#[event]
+pub struct GatewayDisabledUpdated {
+    pub chain: u16,
+    pub disabled: bool,
+}
+
+#[event]
+pub struct L1RedeemerUpdated {
+    pub l1_redeemer: [u8; 32],
+}
+
+#[event]
+pub struct PayloadSenderUpdated {
+    pub payload_sender: Pubkey,
+}
+
+#[event]
+pub struct MaxRelayerFeeUpdated {
+    pub max_relayer_fee_bps: u16,
+}
+
 #[event]
 pub struct MintingLimitUpdated {
     pub minting_limit: u64,
 }
+
+#[event]
+pub struct GatewayMintingLimitUpdated {
+    pub chain: u16,
+    pub minting_limit: u64,
+}
+
+#[event]
+pub struct ReceiveModeUpdated {
+    pub receive_mode: ReceiveMode,
+}
+
+#[event]
+pub struct CanonicalTokenUpdateRequested {
+    pub canonical_token: CanonicalToken,
+}
+
+#[event]
+pub struct CanonicalTokenUpdateCancelled {
+    pub canonical_token: CanonicalToken,
+}
+
+#[event]
+pub struct CanonicalTokenUpdated {
+    pub old_canonical_token: CanonicalToken,
+    pub new_canonical_token: CanonicalToken,
+    pub wrapped_tbtc_mint: Pubkey,
+    pub wrapped_tbtc_token: Pubkey,
+}
+
+#[event]
+pub struct TokensRecovered {
+    pub mint: Pubkey,
+    pub source_token: Pubkey,
+    pub recipient_token: Pubkey,
+    pub amount: u64,
+}
@@ -4,8 +4,11 @@ pub mod constants;
 
 pub mod error;
 
+#[macro_use]
 pub(crate) mod event;
 
+pub mod payload;
+
 mod processor;
 pub(crate) use processor::*;
 
@@ -30,8 +33,8 @@ pub mod wormhole_gateway {
 
     use super::*;
 
-    pub fn initialize(ctx: Context<Initialize>, minting_limit: u64) -> Result<()> {
-        processor::initialize(ctx, minting_limit)
+    pub fn initialize(ctx: Context<Initialize>, args: InitializeArgs) -> Result<()> {
+        processor::initialize(ctx, args)
     }
 
     pub fn change_authority(ctx: Context<ChangeAuthority>) -> Result<()> {
@@ -46,6 +49,75 @@ pub mod wormhole_gateway {
         processor::take_authority(ctx)
     }
 
+    pub fn set_authority_change_delay(
+        ctx: Context<SetAuthorityChangeDelay>,
+        delay: u32,
+    ) -> Result<()> {
+        processor::set_authority_change_delay(ctx, delay)
+    }
+
+    pub fn migrate_custodian(
+        ctx: Context<MigrateCustodian>,
+        canonical_token: CanonicalToken,
+    ) -> Result<()> {
+        processor::migrate_custodian(ctx, canonical_token)
+    }
+
+    pub fn migrate_gateway_info(
+        ctx: Context<MigrateGatewayInfo>,
+        args: MigrateGatewayInfoArgs,
+    ) -> Result<()> {
+        processor::migrate_gateway_info(ctx, args)
+    }
+
+    pub fn grant_role(ctx: Context<GrantRole>, role: Role) -> Result<()> {
+        processor::grant_role(ctx, role)
+    }
+
+    pub fn revoke_role(ctx: Context<RevokeRole>, role: Role) -> Result<()> {
+        processor::revoke_role(ctx, role)
+    }
+
+    pub fn request_canonical_token_update(
+        ctx: Context<RequestCanonicalTokenUpdate>,
+        canonical_token: CanonicalToken,
+    ) -> Result<()> {
+        processor::request_canonical_token_update(ctx, canonical_token)
+    }
+
+    pub fn cancel_canonical_token_update(ctx: Context<CancelCanonicalTokenUpdate>) -> Result<()> {
+        processor::cancel_canonical_token_update(ctx)
+    }
+
+    pub fn update_canonical_token(
+        ctx: Context<UpdateCanonicalToken>,
+        canonical_token: CanonicalToken,
+    ) -> Result<()> {
+        processor::update_canonical_token(ctx, canonical_token)
+    }
+
+    pub fn add_call_target(ctx: Context<AddCallTarget>) -> Result<()> {
+        processor::add_call_target(ctx)
+    }
+
+    pub fn remove_call_target(ctx: Context<RemoveCallTarget>) -> Result<()> {
+        processor::remove_call_target(ctx)
+    }
+
+    pub fn update_allowed_chain(
+        ctx: Context<UpdateAllowedChain>,
+        args: UpdateAllowedChainArgs,
+    ) -> Result<()> {
+        processor::update_allowed_chain(ctx, args)
+    }
+
+    pub fn remove_allowed_chain(
+        ctx: Context<RemoveAllowedChain>,
+        args: RemoveAllowedChainArgs,
+    ) -> Result<()> {
+        processor::remove_allowed_chain(ctx, args)
+    }
+
     pub fn update_gateway_address(
         ctx: Context<UpdateGatewayAddress>,
         args: UpdateGatewayAddressArgs,
@@ -53,28 +125,114 @@ pub mod wormhole_gateway {
         processor::update_gateway_address(ctx, args)
     }
 
+    pub fn remove_gateway_address(
+        ctx: Context<RemoveGatewayAddress>,
+        args: RemoveGatewayAddressArgs,
+    ) -> Result<()> {
+        processor::remove_gateway_address(ctx, args)
+    }
+
+    pub fn update_gateway_disabled(
+        ctx: Context<UpdateGatewayDisabled>,
+        args: UpdateGatewayDisabledArgs,
+    ) -> Result<()> {
+        processor::update_gateway_disabled(ctx, args)
+    }
+
+    pub fn update_gateway_minting_limit(
+        ctx: Context<UpdateGatewayMintingLimit>,
+        args: UpdateGatewayMintingLimitArgs,
+    ) -> Result<()> {
+        processor::update_gateway_minting_limit(ctx, args)
+    }
+
+    pub fn update_l1_redeemer(ctx: Context<UpdateL1Redeemer>, l1_redeemer: [u8; 32]) -> Result<()> {
+        processor::update_l1_redeemer(ctx, l1_redeemer)
+    }
+
+    pub fn update_payload_sender(
+        ctx: Context<UpdatePayloadSender>,
+        payload_sender: Pubkey,
+    ) -> Result<()> {
+        processor::update_payload_sender(ctx, payload_sender)
+    }
+
+    pub fn update_max_relayer_fee(
+        ctx: Context<UpdateMaxRelayerFee>,
+        max_relayer_fee_bps: u16,
+    ) -> Result<()> {
+        processor::update_max_relayer_fee(ctx, max_relayer_fee_bps)
+    }
+
     pub fn update_minting_limit(ctx: Context<UpdateMintingLimit>, new_limit: u64) -> Result<()> {
         processor::update_minting_limit(ctx, new_limit)
     }
 
-    pub fn receive_tbtc(ctx: Context<ReceiveTbtc>, message_hash: [u8; 32]) -> Result<()> {
+    pub fn update_receive_mode(
+        ctx: Context<UpdateReceiveMode>,
+        receive_mode: ReceiveMode,
+    ) -> Result<()> {
+        processor::update_receive_mode(ctx, receive_mode)
+    }
+
+    pub fn pause(ctx: Context<Pause>) -> Result<()> {
+        processor::pause(ctx)
+    }
+
+    pub fn unpause(ctx: Context<Unpause>) -> Result<()> {
+        processor::unpause(ctx)
+    }
+
+    pub fn recover_tokens(ctx: Context<RecoverTokens>, amount: u64) -> Result<()> {
+        processor::recover_tokens(ctx, amount)
+    }
+
+    pub fn receive_tbtc<'info>(
+        ctx: Context<'_, '_, '_, 'info, ReceiveTbtc<'info>>,
+        message_hash: [u8; 32],
+    ) -> Result<()> {
         processor::receive_tbtc(ctx, message_hash)
     }
 
-    pub fn send_tbtc_gateway(
-        ctx: Context<SendTbtcGateway>,
+    pub fn receive_tbtc_and_call<'info>(
+        ctx: Context<'_, '_, '_, 'info, ReceiveTbtcAndCall<'info>>,
+        message_hash: [u8; 32],
+    ) -> Result<()> {
+        processor::receive_tbtc_and_call(ctx, message_hash)
+    }
+
+    pub fn receive_tbtc_without_call(
+        ctx: Context<ReceiveTbtcWithoutCall>,
+        message_hash: [u8; 32],
+    ) -> Result<()> {
+        processor::receive_tbtc_without_call(ctx, message_hash)
+    }
+
+    pub fn claim_pending_mint(ctx: Context<ClaimPendingMint>) -> Result<()> {
+        processor::claim_pending_mint(ctx)
+    }
+
+    pub fn send_tbtc_gateway<'info>(
+        ctx: Context<'_, '_, '_, 'info, SendTbtcGateway<'info>>,
         args: SendTbtcGatewayArgs,
     ) -> Result<()> {
         processor::send_tbtc_gateway(ctx, args)
     }
 
-    pub fn send_tbtc_wrapped(
-        ctx: Context<SendTbtcWrapped>,
+    pub fn send_tbtc_wrapped<'info>(
+        ctx: Context<'_, '_, '_, 'info, SendTbtcWrapped<'info>>,
         args: SendTbtcWrappedArgs,
     ) -> Result<()> {
         processor::send_tbtc_wrapped(ctx, args)
     }
 
+    pub fn send_tbtc_with_payload<'info>(
+        ctx: Context<'_, '_, '_, 'info, SendTbtcWithPayload<'info>>,
+        args: SendTbtcWithPayloadArgs,
+    ) -> Result<()> {
+        processor::send_tbtc_with_payload(ctx, args)
+    }
+
     pub fn deposit_wormhole_tbtc(ctx: Context<DepositWormholeTbtc>, amount: u64) -> Result<()> {
         processor::deposit_wormhole_tbtc(ctx, amount)
     }
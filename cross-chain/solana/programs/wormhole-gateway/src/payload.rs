@@ -0,0 +1,454 @@
+//! Token Bridge message payloads exchanged between the gateway and the gateways on other chains.
+//!
+//! Every payload format is identified by a version. Version 0 is the raw 32-byte recipient address
+//! that gateways have always sent, so it has no version byte. Every later version starts with its
+//! version byte and is longer than 32 bytes.
+//!
+//! Gateways used to read the recipient from the leading 32 bytes and ignore any trailing data, so
+//! such a payload cannot be told apart from a later version by its leading byte alone. A payload is
+//! decoded as follows:
+//!
+//! 1. A 32-byte payload is version 0.
+//! 2. A longer payload starting with 1 is version 1 if it has the exact version 1 layout.
+//! 3. A payload starting with 2 that has the exact length of a [ReceiveAndCallPayload] is
+//!    rejected, since its leading bytes are not a recipient.
+//! 4. Anything else is version 0, whose recipient is read from the leading 32 bytes.
+//!
+//! So a legacy payload with trailing data is only misread if it also has the exact layout of a
+//! later version.
+//!
+//! Version 0 (32 bytes):
+//!
+//! | Offset | Size | Field                                                         |
+//! |--------|------|---------------------------------------------------------------|
+//! | 0      | 32   | Recipient                                                     |
+//!
+//! Version 1 (74 bytes plus the memo):
+//!
+//! | Offset | Size | Field                                                         |
+//! |--------|------|---------------------------------------------------------------|
+//! | 0      | 1    | Version (1)                                                   |
+//! | 1      | 32   | Recipient                                                     |
+//! | 33     | 8    | Relayer fee in tBTC, big-endian                               |
+//! | 41     | 32   | Deposit key of a deposit initialized on Solana, zero if none  |
+//! | 73     | 1    | Memo length, at most [MAX_MEMO_LEN]                           |
+//! | 74     | *    | Memo                                                          |
+//!
+//! Version 2 is the [ReceiveAndCallPayload], which is only accepted by `receive_tbtc_and_call`.
+//!
+//! Outbound transfers are encoded as version 0, which gateways on other chains decode as the
+//! recipient address.
+
+use anchor_lang::prelude::*;
+use std::io;
+
+/// Maximum length of a version 1 memo.
+pub const MAX_MEMO_LEN: usize = 64;
+
+/// Reasons a payload cannot be decoded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PayloadError {
+    InvalidLength,
+    UnsupportedVersion(u8),
+    MemoTooLong,
+}
+
+impl std::fmt::Display for PayloadError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PayloadError::InvalidLength => write!(f, "invalid payload length"),
+            PayloadError::UnsupportedVersion(version) => {
+                write!(f, "unsupported payload version {version}")
+            }
+            PayloadError::MemoTooLong => write!(f, "memo exceeds {MAX_MEMO_LEN} bytes"),
+        }
+    }
+}
+
+/// Payload of a tBTC transfer to a recipient, which is decoded from either version 0 or version 1.
+/// Fields missing from version 0 are zero or empty.
+///
+/// NOTE: Token Bridge payloads must be `Copy`, so the memo is stored in a fixed-size buffer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TransferPayload {
+    version: u8,
+    recipient: [u8; 32],
+    relayer_fee: u64,
+    deposit_key: Option<[u8; 32]>,
+    memo_len: u8,
+    memo: [u8; MAX_MEMO_LEN],
+}
+
+impl TransferPayload {
+    pub const V0: u8 = 0;
+    pub const V1: u8 = 1;
+
+    const V1_HEADER_LEN: usize = 74;
+
+    pub fn v0(recipient: [u8; 32]) -> Self {
+        Self {
+            version: Self::V0,
+            recipient,
+            relayer_fee: 0,
+            deposit_key: None,
+            memo_len: 0,
+            memo: [0; MAX_MEMO_LEN],
+        }
+    }
+
+    /// A zero deposit key is encoded the same way as no deposit key, so it is taken as none.
+    pub fn v1(
+        recipient: [u8; 32],
+        relayer_fee: u64,
+        deposit_key: Option<[u8; 32]>,
+        memo: &[u8],
+    ) -> std::result::Result<Self, PayloadError> {
+        if memo.len() > MAX_MEMO_LEN {
+            return Err(PayloadError::MemoTooLong);
+        }
+
+        let mut payload = Self {
+            version: Self::V1,
+            recipient,
+            relayer_fee,
+            deposit_key: deposit_key.filter(|key| *key != [0; 32]),
+            memo_len: memo.len() as u8,
+            memo: [0; MAX_MEMO_LEN],
+        };
+        payload.memo[..memo.len()].copy_from_slice(memo);
+
+        Ok(payload)
+    }
+
+    pub fn version(&self) -> u8 {
+        self.version
+    }
+
+    pub fn recipient(&self) -> [u8; 32] {
+        self.recipient
+    }
+
+    pub fn relayer_fee(&self) -> u64 {
+        self.relayer_fee
+    }
+
+    pub fn deposit_key(&self) -> Option<&[u8; 32]> {
+        self.deposit_key.as_ref()
+    }
+
+    pub fn memo(&self) -> &[u8] {
+        &self.memo[..usize::from(self.memo_len)]
+    }
+
+    pub fn encode(&self) -> Vec<u8> {
+        if self.version == Self::V0 {
+            return self.recipient.to_vec();
+        }
+
+        let mut out = Vec::with_capacity(Self::V1_HEADER_LEN + usize::from(self.memo_len));
+        out.push(self.version);
+        out.extend_from_slice(&self.recipient);
+        out.extend_from_slice(&self.relayer_fee.to_be_bytes());
+        out.extend_from_slice(&self.deposit_key.unwrap_or_default());
+        out.push(self.memo_len);
+        out.extend_from_slice(self.memo());
+        out
+    }
+
+    pub fn decode(payload: &[u8]) -> std::result::Result<Self, PayloadError> {
+        if payload.len() < 32 {
+            return Err(PayloadError::InvalidLength);
+        }
+
+        if payload.len() > 32 {
+            match payload[0] {
+                Self::V1 => {
+                    if let Ok(decoded) = Self::decode_v1(payload) {
+                        return Ok(decoded);
+                    }
+                }
+                // This payload is only accepted by `receive_tbtc_and_call`, and its leading bytes
+                // are not a recipient.
+                ReceiveAndCallPayload::VERSION if payload.len() == ReceiveAndCallPayload::LEN => {
+                    return Err(PayloadError::UnsupportedVersion(
+                        ReceiveAndCallPayload::VERSION,
+                    ));
+                }
+                _ => {}
+            }
+        }
+
+        // Not a later version, so this is a legacy payload.
+        Ok(Self::v0_from_leading_bytes(payload))
+    }
+
+    fn decode_v1(payload: &[u8]) -> std::result::Result<Self, PayloadError> {
+        if payload.len() < Self::V1_HEADER_LEN {
+            return Err(PayloadError::InvalidLength);
+        }
+
+        let memo_len = usize::from(payload[73]);
+        if memo_len > MAX_MEMO_LEN {
+            return Err(PayloadError::MemoTooLong);
+        }
+        if payload.len() != Self::V1_HEADER_LEN + memo_len {
+            return Err(PayloadError::InvalidLength);
+        }
+
+        let mut recipient = [0; 32];
+        recipient.copy_from_slice(&payload[1..33]);
+        let mut relayer_fee = [0; 8];
+        relayer_fee.copy_from_slice(&payload[33..41]);
+        let mut deposit_key = [0; 32];
+        deposit_key.copy_from_slice(&payload[41..73]);
+
+        Self::v1(
+            recipient,
+            u64::from_be_bytes(relayer_fee),
+            Some(deposit_key),
+            &payload[Self::V1_HEADER_LEN..],
+        )
+    }
+
+    fn v0_from_leading_bytes(payload: &[u8]) -> Self {
+        let mut recipient = [0; 32];
+        recipient.copy_from_slice(&payload[..32]);
+        Self::v0(recipient)
+    }
+}
+
+impl AnchorSerialize for TransferPayload {
+    fn serialize<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        writer.write_all(&self.encode())
+    }
+}
+
+impl AnchorDeserialize for TransferPayload {
+    /// The payload is the last field of a Token Bridge transfer, so it takes the rest of the data.
+    fn deserialize_reader<R: io::Read>(reader: &mut R) -> io::Result<Self> {
+        let mut payload = Vec::new();
+        reader.read_to_end(&mut payload)?;
+        Self::decode(&payload)
+            .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err.to_string()))
+    }
+}
+
+/// Payload of a transfer whose tBTC is minted to the recipient's token account before the target
+/// program is called. The recipient is typically a PDA of the target program.
+///
+/// NOTE: Token Bridge payloads are decoded as fixed-size types, so the sender pads the message.
+#[derive(Debug, Clone, Copy, AnchorSerialize, AnchorDeserialize)]
+pub struct ReceiveAndCallPayload {
+    pub version: u8,
+    pub target_program: Pubkey,
+    pub recipient: Pubkey,
+    /// Address of the sender on the source chain.
+    pub sender: [u8; 32],
+    /// Opaque message passed along to the target program.
+    pub message: [u8; 128],
+}
+
+impl ReceiveAndCallPayload {
+    pub const VERSION: u8 = 2;
+
+    /// Length of the encoded payload.
+    pub const LEN: usize = 1 + 32 + 32 + 32 + 128;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RECIPIENT: [u8; 32] = [0xab; 32];
+    const DEPOSIT_KEY: [u8; 32] = [0xcd; 32];
+
+    #[test]
+    fn v0_round_trip() {
+        let payload = TransferPayload::v0(RECIPIENT);
+        let encoded = payload.encode();
+        assert_eq!(encoded, RECIPIENT.to_vec());
+
+        let decoded = TransferPayload::decode(&encoded).unwrap();
+        assert_eq!(decoded, payload);
+        assert_eq!(decoded.version(), TransferPayload::V0);
+        assert_eq!(decoded.relayer_fee(), 0);
+        assert_eq!(decoded.deposit_key(), None);
+        assert!(decoded.memo().is_empty());
+    }
+
+    #[test]
+    fn v0_accepts_any_leading_byte() {
+        let mut recipient = RECIPIENT;
+        recipient[0] = TransferPayload::V1;
+
+        let decoded = TransferPayload::decode(&recipient).unwrap();
+        assert_eq!(decoded.version(), TransferPayload::V0);
+        assert_eq!(decoded.recipient(), recipient);
+    }
+
+    #[test]
+    fn v0_ignores_trailing_bytes() {
+        let mut encoded = RECIPIENT.to_vec();
+        encoded.extend_from_slice(&[0xef; 32]);
+
+        let decoded = TransferPayload::decode(&encoded).unwrap();
+        assert_eq!(decoded, TransferPayload::v0(RECIPIENT));
+
+        // Any leading byte but a known version is part of the recipient.
+        encoded[0] = 0xff;
+        let decoded = TransferPayload::decode(&encoded).unwrap();
+        assert_eq!(decoded.version(), TransferPayload::V0);
+        assert_eq!(decoded.recipient(), encoded[..32]);
+    }
+
+    #[test]
+    fn v0_with_trailing_bytes_and_version_byte() {
+        // A legacy recipient may start with a version byte. Unless the payload has the exact
+        // layout of that version, it is still version 0.
+        for version in [TransferPayload::V1, ReceiveAndCallPayload::VERSION] {
+            let mut encoded = RECIPIENT.to_vec();
+            encoded[0] = version;
+            encoded.extend_from_slice(&[0xef; 32]);
+
+            let decoded = TransferPayload::decode(&encoded).unwrap();
+            assert_eq!(decoded.version(), TransferPayload::V0);
+            assert_eq!(decoded.recipient(), encoded[..32]);
+        }
+    }
+
+    #[test]
+    fn v1_round_trip() {
+        let payload = TransferPayload::v1(RECIPIENT, 1_000, Some(DEPOSIT_KEY), b"hello").unwrap();
+        let encoded = payload.encode();
+        assert_eq!(encoded.len(), 74 + 5);
+        assert_eq!(encoded[0], TransferPayload::V1);
+        assert_eq!(encoded[1..33], RECIPIENT);
+        assert_eq!(encoded[33..41], 1_000u64.to_be_bytes());
+        assert_eq!(encoded[41..73], DEPOSIT_KEY);
+        assert_eq!(encoded[73], 5);
+        assert_eq!(&encoded[74..], b"hello");
+
+        let decoded = TransferPayload::decode(&encoded).unwrap();
+        assert_eq!(decoded, payload);
+        assert_eq!(decoded.recipient(), RECIPIENT);
+        assert_eq!(decoded.relayer_fee(), 1_000);
+        assert_eq!(decoded.deposit_key(), Some(&DEPOSIT_KEY));
+        assert_eq!(decoded.memo(), b"hello");
+    }
+
+    #[test]
+    fn v1_round_trip_without_optional_fields() {
+        let payload = TransferPayload::v1(RECIPIENT, 0, None, &[]).unwrap();
+        let encoded = payload.encode();
+        assert_eq!(encoded.len(), 74);
+        assert_eq!(encoded[41..73], [0; 32]);
+
+        let decoded = TransferPayload::decode(&encoded).unwrap();
+        assert_eq!(decoded, payload);
+        assert_eq!(decoded.deposit_key(), None);
+    }
+
+    #[test]
+    fn v1_round_trip_max_memo() {
+        let memo = [0x11; MAX_MEMO_LEN];
+        let payload = TransferPayload::v1(RECIPIENT, u64::MAX, None, &memo).unwrap();
+
+        let decoded = TransferPayload::decode(&payload.encode()).unwrap();
+        assert_eq!(decoded, payload);
+        assert_eq!(decoded.memo(), memo);
+    }
+
+    #[test]
+    fn v1_zero_deposit_key_is_none() {
+        let payload = TransferPayload::v1(RECIPIENT, 0, Some([0; 32]), &[]).unwrap();
+        assert_eq!(payload.deposit_key(), None);
+    }
+
+    #[test]
+    fn v1_memo_too_long() {
+        assert_eq!(
+            TransferPayload::v1(RECIPIENT, 0, None, &[0; MAX_MEMO_LEN + 1]),
+            Err(PayloadError::MemoTooLong)
+        );
+
+        // A payload with a memo that is too long is not version 1.
+        let mut encoded = TransferPayload::v1(RECIPIENT, 0, None, &[])
+            .unwrap()
+            .encode();
+        encoded[73] = MAX_MEMO_LEN as u8 + 1;
+        encoded.extend_from_slice(&[0; MAX_MEMO_LEN + 1]);
+        assert_eq!(
+            TransferPayload::decode(&encoded).unwrap().version(),
+            TransferPayload::V0
+        );
+    }
+
+    #[test]
+    fn malformed_lengths() {
+        let encoded = TransferPayload::v1(RECIPIENT, 1, Some(DEPOSIT_KEY), b"memo")
+            .unwrap()
+            .encode();
+
+        // Payloads without the exact version 1 layout are decoded as version 0.
+        let mut trailing = encoded.clone();
+        trailing.push(0);
+        for malformed in [
+            // Truncated header.
+            &encoded[..73],
+            // Truncated memo.
+            &encoded[..encoded.len() - 1],
+            // Trailing bytes.
+            &trailing[..],
+        ] {
+            let decoded = TransferPayload::decode(malformed).unwrap();
+            assert_eq!(decoded.version(), TransferPayload::V0);
+            assert_eq!(decoded.recipient(), malformed[..32]);
+        }
+
+        // Payloads too short for a recipient.
+        assert_eq!(
+            TransferPayload::decode(&[]),
+            Err(PayloadError::InvalidLength)
+        );
+        assert_eq!(
+            TransferPayload::decode(&[0; 31]),
+            Err(PayloadError::InvalidLength)
+        );
+    }
+
+    #[test]
+    fn unsupported_versions() {
+        let encoded = ReceiveAndCallPayload {
+            version: ReceiveAndCallPayload::VERSION,
+            target_program: Pubkey::new_unique(),
+            recipient: Pubkey::new_unique(),
+            sender: [0xef; 32],
+            message: [0x11; 128],
+        }
+        .try_to_vec()
+        .unwrap();
+        assert_eq!(encoded.len(), ReceiveAndCallPayload::LEN);
+
+        assert_eq!(
+            TransferPayload::decode(&encoded),
+            Err(PayloadError::UnsupportedVersion(
+                ReceiveAndCallPayload::VERSION
+            ))
+        );
+    }
+
+    #[test]
+    fn borsh_consumes_whole_payload() {
+        let payload = TransferPayload::v1(RECIPIENT, 42, Some(DEPOSIT_KEY), b"memo").unwrap();
+        let encoded = payload.try_to_vec().unwrap();
+        assert_eq!(encoded, payload.encode());
+
+        let mut buf = encoded.as_slice();
+        let decoded = TransferPayload::deserialize(&mut buf).unwrap();
+        assert_eq!(decoded, payload);
+        assert!(buf.is_empty());
+
+        let mut buf = &[TransferPayload::V1, 0][..];
+        assert!(TransferPayload::deserialize(&mut buf).is_err());
+    }
+}
@@ -0,0 +1,47 @@
+use crate::{
+    error::WormholeGatewayError,
+    state::{CallTarget, Custodian},
+};
+use anchor_lang::prelude::*;
+
+#[cfg_attr(feature = "event-cpi", event_cpi)]
+#[derive(Accounts)]
+pub struct AddCallTarget<'info> {
+    #[account(
+        seeds = [Custodian::SEED_PREFIX],
+        bump = custodian.bump,
+        has_one = authority @ WormholeGatewayError::IsNotAuthority,
+    )]
+    custodian: Account<'info, Custodian>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + CallTarget::INIT_SPACE,
+        seeds = [CallTarget::SEED_PREFIX, program.key().as_ref()],
+        bump,
+    )]
+    call_target: Account<'info, CallTarget>,
+
+    /// CHECK: Program to allow calling after minting tBTC.
+    #[account(executable)]
+    program: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    authority: Signer<'info>,
+
+    system_program: Program<'info, System>,
+}
+
+pub fn add_call_target(ctx: Context<AddCallTarget>) -> Result<()> {
+    let program = ctx.accounts.program.key();
+
+    ctx.accounts.call_target.set_inner(CallTarget {
+        bump: ctx.bumps["call_target"],
+        program,
+    });
+
+    emit_event!(ctx, crate::event::CallTargetAdded { program });
+
+    Ok(())
+}
@@ -1,6 +1,7 @@
 use crate::{error::WormholeGatewayError, state::Custodian};
 use anchor_lang::prelude::*;
 
+#[cfg_attr(feature = "event-cpi", event_cpi)]
 #[derive(Accounts)]
 
 pub struct CancelAuthorityChange<'info> {
@@ -17,6 +18,19 @@ pub struct CancelAuthorityChange<'info> {
 }
 
 pub fn cancel_authority_change(ctx: Context<CancelAuthorityChange>) -> Result<()> {
-    ctx.accounts.custodian.pending_authority = None;
+    let custodian = &mut ctx.accounts.custodian;
+
+    // The constraint above guarantees there is a pending authority.
+    let pending_authority = custodian.pending_authority.take().unwrap();
+    custodian.pending_authority_since = 0;
+
+    emit_event!(
+        ctx,
+        crate::event::AuthorityChangeCancelled {
+            authority: ctx.accounts.authority.key(),
+            pending_authority,
+        }
+    );
+
     Ok(())
 }
@@ -0,0 +1,41 @@
+use crate::{error::WormholeGatewayError, state::Custodian};
+use anchor_lang::prelude::*;
+
+#[cfg_attr(feature = "event-cpi", event_cpi)]
+#[derive(Accounts)]
+pub struct CancelCanonicalTokenUpdate<'info> {
+    #[account(
+        mut,
+        seeds = [Custodian::SEED_PREFIX],
+        bump = custodian.bump,
+        has_one = authority @ WormholeGatewayError::IsNotAuthority,
+    )]
+    custodian: Account<'info, Custodian>,
+
+    authority: Signer<'info>,
+}
+
+impl<'info> CancelCanonicalTokenUpdate<'info> {
+    fn constraints(ctx: &Context<Self>) -> Result<()> {
+        require!(
+            ctx.accounts.custodian.pending_canonical_token.is_some(),
+            WormholeGatewayError::NoPendingCanonicalTokenUpdate
+        );
+
+        Ok(())
+    }
+}
+
+#[access_control(CancelCanonicalTokenUpdate::constraints(&ctx))]
+pub fn cancel_canonical_token_update(ctx: Context<CancelCanonicalTokenUpdate>) -> Result<()> {
+    let custodian = &mut ctx.accounts.custodian;
+    let canonical_token = custodian.pending_canonical_token.take().unwrap();
+    custodian.pending_canonical_token_since = 0;
+
+    emit_event!(
+        ctx,
+        crate::event::CanonicalTokenUpdateCancelled { canonical_token }
+    );
+
+    Ok(())
+}
@@ -1,6 +1,7 @@
 use crate::{error::WormholeGatewayError, state::Custodian};
 use anchor_lang::prelude::*;
 
+#[cfg_attr(feature = "event-cpi", event_cpi)]
 #[derive(Accounts)]
 pub struct ChangeAuthority<'info> {
     #[account(
@@ -18,6 +19,19 @@ pub struct ChangeAuthority<'info> {
 }
 
 pub fn change_authority(ctx: Context<ChangeAuthority>) -> Result<()> {
-    ctx.accounts.custodian.pending_authority = Some(ctx.accounts.new_authority.key());
+    let custodian = &mut ctx.accounts.custodian;
+    custodian.pending_authority = Some(ctx.accounts.new_authority.key());
+
+    // Start the delay over for every request.
+    custodian.pending_authority_since = Clock::get()?.unix_timestamp;
+
+    emit_event!(
+        ctx,
+        crate::event::AuthorityChangeRequested {
+            authority: ctx.accounts.authority.key(),
+            pending_authority: ctx.accounts.new_authority.key(),
+        }
+    );
+
     Ok(())
 }
@@ -1,12 +1,13 @@
 use crate::{
-    constants::{TBTC_ETHEREUM_TOKEN_ADDRESS, TBTC_ETHEREUM_TOKEN_CHAIN},
-    state::Custodian,
+    constants::WRAPPED_TBTC_TOKEN_SEED_PREFIX,
+    state::{CanonicalToken, Custodian, ReceiveMode},
 };
 use anchor_lang::prelude::*;
 use anchor_spl::token;
 use wormhole_anchor_sdk::token_bridge;
 
 #[derive(Accounts)]
+#[instruction(args: InitializeArgs)]
 pub struct Initialize<'info> {
     #[account(mut)]
     authority: Signer<'info>,
@@ -33,8 +34,8 @@ pub struct Initialize<'info> {
     #[account(
         seeds = [
             token_bridge::WrappedMint::SEED_PREFIX,
-            &TBTC_ETHEREUM_TOKEN_CHAIN.to_be_bytes(),
-            TBTC_ETHEREUM_TOKEN_ADDRESS.as_ref()
+            &args.canonical_token.chain.to_be_bytes(),
+            args.canonical_token.address.as_ref()
         ],
         bump,
         seeds::program = token_bridge::program::ID
@@ -46,7 +47,7 @@ pub struct Initialize<'info> {
         payer = authority,
         token::mint = wrapped_tbtc_mint,
         token::authority = custodian,
-        seeds = [b"wrapped-token"],
+        seeds = [WRAPPED_TBTC_TOKEN_SEED_PREFIX],
         bump
     )]
     wrapped_tbtc_token: Account<'info, token::TokenAccount>,
@@ -63,7 +64,19 @@ pub struct Initialize<'info> {
     token_program: Program<'info, token::Token>,
 }
 
-pub fn initialize(ctx: Context<Initialize>, minting_limit: u64) -> Result<()> {
+#[derive(Debug, Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct InitializeArgs {
+    pub minting_limit: u64,
+    /// Origin chain and address of the tBTC token whose wrapped version is held in custody.
+    pub canonical_token: CanonicalToken,
+}
+
+pub fn initialize(ctx: Context<Initialize>, args: InitializeArgs) -> Result<()> {
+    let InitializeArgs {
+        minting_limit,
+        canonical_token,
+    } = args;
+
     ctx.accounts.custodian.set_inner(Custodian {
         bump: ctx.bumps["custodian"],
         authority: ctx.accounts.authority.key(),
@@ -75,6 +88,19 @@ pub fn initialize(ctx: Context<Initialize>, minting_limit: u64) -> Result<()> {
         token_bridge_sender_bump: ctx.bumps["token_bridge_sender"],
         minting_limit,
         minted_amount: 0,
+        pending_authority_since: 0,
+        authority_change_delay: 0,
+        pending_amount: 0,
+        receive_mode: ReceiveMode::AllOrNothing,
+        paused: false,
+        canonical_token,
+        pending_canonical_token: None,
+        pending_canonical_token_since: 0,
+        l1_redeemer: None,
+        max_relayer_fee_bps: 0,
+        pending_authority_change_delay: None,
+        pending_authority_change_delay_since: 0,
+        payload_sender: None,
     });
 
     Ok(())
@@ -0,0 +1,67 @@
+use super::realloc_account;
+use crate::{
+    error::WormholeGatewayError,
+    state::{CanonicalToken, Custodian},
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token;
+use wormhole_anchor_sdk::token_bridge;
+
+#[derive(Accounts)]
+#[instruction(canonical_token: CanonicalToken)]
+pub struct MigrateCustodian<'info> {
+    /// CHECK: Custodians created with an older layout cannot be deserialized until they are
+    /// reallocated, so the authority is checked after the realloc.
+    #[account(
+        mut,
+        seeds = [Custodian::SEED_PREFIX],
+        bump,
+    )]
+    custodian: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    authority: Signer<'info>,
+
+    /// Wrapped tBTC mint of the canonical token, which must be the mint already held in custody.
+    #[account(
+        seeds = [
+            token_bridge::WrappedMint::SEED_PREFIX,
+            &canonical_token.chain.to_be_bytes(),
+            canonical_token.address.as_ref()
+        ],
+        bump,
+        seeds::program = token_bridge::program::ID
+    )]
+    wrapped_tbtc_mint: Account<'info, token::Mint>,
+
+    system_program: Program<'info, System>,
+}
+
+pub fn migrate_custodian(
+    ctx: Context<MigrateCustodian>,
+    canonical_token: CanonicalToken,
+) -> Result<()> {
+    let custodian = ctx.accounts.custodian.to_account_info();
+    realloc_account(
+        &custodian,
+        &ctx.accounts.authority,
+        &ctx.accounts.system_program,
+        8 + Custodian::INIT_SPACE,
+    )?;
+
+    let mut custodian = Account::<Custodian>::try_from(&custodian)?;
+    require_keys_eq!(
+        custodian.authority,
+        ctx.accounts.authority.key(),
+        WormholeGatewayError::IsNotAuthority
+    );
+    require_keys_eq!(
+        custodian.wrapped_tbtc_mint,
+        ctx.accounts.wrapped_tbtc_mint.key(),
+        WormholeGatewayError::InvalidCanonicalToken
+    );
+
+    // The other appended fields start at zero, so only the canonical token needs to be set.
+    custodian.canonical_token = canonical_token;
+    custodian.exit(&crate::ID)
+}
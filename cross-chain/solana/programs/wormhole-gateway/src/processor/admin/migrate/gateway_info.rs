@@ -0,0 +1,73 @@
+use super::realloc_account;
+use crate::{
+    error::WormholeGatewayError,
+    state::{Custodian, GatewayInfo},
+};
+use anchor_lang::prelude::*;
+
+/// NOTE: The custodian must be migrated first, since it is deserialized to check the authority.
+#[cfg_attr(feature = "event-cpi", event_cpi)]
+#[derive(Accounts)]
+#[instruction(args: MigrateGatewayInfoArgs)]
+pub struct MigrateGatewayInfo<'info> {
+    #[account(
+        seeds = [Custodian::SEED_PREFIX],
+        bump = custodian.bump,
+        has_one = authority @ WormholeGatewayError::IsNotAuthority,
+    )]
+    custodian: Account<'info, Custodian>,
+
+    #[account(mut)]
+    authority: Signer<'info>,
+
+    /// CHECK: Gateway infos created with an older layout cannot be deserialized until they are
+    /// reallocated.
+    #[account(
+        mut,
+        seeds = [GatewayInfo::SEED_PREFIX, &args.chain.to_le_bytes()],
+        bump,
+    )]
+    gateway_info: UncheckedAccount<'info>,
+
+    system_program: Program<'info, System>,
+}
+
+#[derive(Debug, Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct MigrateGatewayInfoArgs {
+    chain: u16,
+    minting_limit: u64,
+}
+
+pub fn migrate_gateway_info(
+    ctx: Context<MigrateGatewayInfo>,
+    args: MigrateGatewayInfoArgs,
+) -> Result<()> {
+    let MigrateGatewayInfoArgs {
+        chain,
+        minting_limit,
+    } = args;
+
+    let gateway_info = ctx.accounts.gateway_info.to_account_info();
+    realloc_account(
+        &gateway_info,
+        &ctx.accounts.authority,
+        &ctx.accounts.system_program,
+        8 + GatewayInfo::INIT_SPACE,
+    )?;
+
+    // Nothing has been minted against the gateway's own limit yet, so only the limit needs to be
+    // set. A zero limit would block transfers from this chain.
+    let mut gateway_info = Account::<GatewayInfo>::try_from(&gateway_info)?;
+    gateway_info.minting_limit = minting_limit;
+    gateway_info.exit(&crate::ID)?;
+
+    emit_event!(
+        ctx,
+        crate::event::GatewayMintingLimitUpdated {
+            chain,
+            minting_limit
+        }
+    );
+
+    Ok(())
+}
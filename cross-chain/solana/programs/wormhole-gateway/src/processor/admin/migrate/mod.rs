@@ -0,0 +1,43 @@
+mod custodian;
+pub use custodian::*;
+
+mod gateway_info;
+pub use gateway_info::*;
+
+use crate::error::WormholeGatewayError;
+use anchor_lang::{prelude::*, system_program};
+
+/// Grow an account created with an older layout to `new_len` bytes, topping up its rent from
+/// `payer`. The new bytes are zeroed, so fields appended to the layout start with zero values.
+fn realloc_account<'info>(
+    account: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    new_len: usize,
+) -> Result<()> {
+    require_gt!(
+        new_len,
+        account.data_len(),
+        WormholeGatewayError::AlreadyMigrated
+    );
+
+    let lamports = Rent::get()?
+        .minimum_balance(new_len)
+        .saturating_sub(account.lamports());
+    if lamports > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.clone(),
+                system_program::Transfer {
+                    from: payer.clone(),
+                    to: account.clone(),
+                },
+            ),
+            lamports,
+        )?;
+    }
+
+    account.realloc(new_len, true)?;
+
+    Ok(())
+}
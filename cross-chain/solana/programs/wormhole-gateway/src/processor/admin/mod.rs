@@ -1,17 +1,80 @@
+mod add_call_target;
+pub use add_call_target::*;
+
 mod cancel_authority_change;
 pub use cancel_authority_change::*;
 
+mod cancel_canonical_token_update;
+pub use cancel_canonical_token_update::*;
+
 mod change_authority;
 pub use change_authority::*;
 
+mod grant_role;
+pub use grant_role::*;
+
 mod initialize;
 pub use initialize::*;
 
+mod migrate;
+pub use migrate::*;
+
+mod pause;
+pub use pause::*;
+
+mod recover_tokens;
+pub use recover_tokens::*;
+
+mod remove_allowed_chain;
+pub use remove_allowed_chain::*;
+
+mod remove_call_target;
+pub use remove_call_target::*;
+
+mod remove_gateway_address;
+pub use remove_gateway_address::*;
+
+mod request_canonical_token_update;
+pub use request_canonical_token_update::*;
+
+mod revoke_role;
+pub use revoke_role::*;
+
+mod set_authority_change_delay;
+pub use set_authority_change_delay::*;
+
 mod take_authority;
 pub use take_authority::*;
 
+mod unpause;
+pub use unpause::*;
+
+mod update_allowed_chain;
+pub use update_allowed_chain::*;
+
+mod update_canonical_token;
+pub use update_canonical_token::*;
+
 mod update_gateway_address;
 pub use update_gateway_address::*;
 
+mod update_gateway_disabled;
+pub use update_gateway_disabled::*;
+
+mod update_gateway_minting_limit;
+pub use update_gateway_minting_limit::*;
+
+mod update_l1_redeemer;
+pub use update_l1_redeemer::*;
+
+mod update_max_relayer_fee;
+pub use update_max_relayer_fee::*;
+
 mod update_minting_limit;
 pub use update_minting_limit::*;
+
+mod update_payload_sender;
+pub use update_payload_sender::*;
+
+mod update_receive_mode;
+pub use update_receive_mode::*;
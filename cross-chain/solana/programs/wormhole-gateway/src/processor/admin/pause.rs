@@ -0,0 +1,49 @@
+use crate::{error::WormholeGatewayError, state::Custodian};
+use anchor_lang::prelude::*;
+
+#[cfg_attr(feature = "event-cpi", event_cpi)]
+#[derive(Accounts)]
+pub struct Pause<'info> {
+    #[account(
+        mut,
+        seeds = [Custodian::SEED_PREFIX],
+        bump = custodian.bump,
+    )]
+    custodian: Account<'info, Custodian>,
+
+    /// Guardians of the TBTC program can also pause this program.
+    #[account(
+        has_one = guardian,
+        seeds = [tbtc::GuardianInfo::SEED_PREFIX, guardian.key().as_ref()],
+        bump = guardian_info.bump,
+        seeds::program = tbtc::ID
+    )]
+    guardian_info: Account<'info, tbtc::GuardianInfo>,
+
+    guardian: Signer<'info>,
+}
+
+impl<'info> Pause<'info> {
+    fn constraints(ctx: &Context<Self>) -> Result<()> {
+        require!(
+            !ctx.accounts.custodian.paused,
+            WormholeGatewayError::IsPaused
+        );
+
+        Ok(())
+    }
+}
+
+#[access_control(Pause::constraints(&ctx))]
+pub fn pause(ctx: Context<Pause>) -> Result<()> {
+    ctx.accounts.custodian.paused = true;
+
+    emit_event!(
+        ctx,
+        crate::event::Paused {
+            guardian: ctx.accounts.guardian.key(),
+        }
+    );
+
+    Ok(())
+}
@@ -0,0 +1,83 @@
+use crate::{error::WormholeGatewayError, state::Custodian};
+use anchor_lang::prelude::*;
+use anchor_spl::token;
+
+#[cfg_attr(feature = "event-cpi", event_cpi)]
+#[derive(Accounts)]
+#[instruction(amount: u64)]
+pub struct RecoverTokens<'info> {
+    #[account(
+        seeds = [Custodian::SEED_PREFIX],
+        bump = custodian.bump,
+        has_one = authority @ WormholeGatewayError::IsNotAuthority,
+    )]
+    custodian: Account<'info, Custodian>,
+
+    authority: Signer<'info>,
+
+    /// Any token account owned by the custodian, including the wrapped tBTC custody.
+    #[account(
+        mut,
+        token::authority = custodian,
+    )]
+    source_token: Account<'info, token::TokenAccount>,
+
+    #[account(
+        mut,
+        token::mint = source_token.mint,
+    )]
+    recipient_token: Account<'info, token::TokenAccount>,
+
+    token_program: Program<'info, token::Token>,
+}
+
+impl<'info> RecoverTokens<'info> {
+    fn constraints(ctx: &Context<Self>, amount: u64) -> Result<()> {
+        require_gt!(amount, 0, WormholeGatewayError::ZeroAmount);
+
+        // Wrapped tBTC in custody backs the tBTC minted by this program and the pending mints. Only
+        // the excess over both can be recovered.
+        let custodian = &ctx.accounts.custodian;
+        if ctx.accounts.source_token.key() == custodian.wrapped_tbtc_token {
+            require_gte!(
+                ctx.accounts
+                    .source_token
+                    .amount
+                    .saturating_sub(custodian.minted_amount)
+                    .saturating_sub(custodian.pending_amount),
+                amount,
+                WormholeGatewayError::RecoverAmountExceedsExcess
+            );
+        }
+
+        Ok(())
+    }
+}
+
+#[access_control(RecoverTokens::constraints(&ctx, amount))]
+pub fn recover_tokens(ctx: Context<RecoverTokens>, amount: u64) -> Result<()> {
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            token::Transfer {
+                from: ctx.accounts.source_token.to_account_info(),
+                to: ctx.accounts.recipient_token.to_account_info(),
+                authority: ctx.accounts.custodian.to_account_info(),
+            },
+            &[&[Custodian::SEED_PREFIX, &[ctx.accounts.custodian.bump]]],
+        ),
+        amount,
+    )?;
+
+    emit_event!(
+        ctx,
+        crate::event::TokensRecovered {
+            mint: ctx.accounts.source_token.mint,
+            source_token: ctx.accounts.source_token.key(),
+            recipient_token: ctx.accounts.recipient_token.key(),
+            amount,
+        }
+    );
+
+    Ok(())
+}
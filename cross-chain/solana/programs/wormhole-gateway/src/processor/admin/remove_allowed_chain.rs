@@ -0,0 +1,42 @@
+use crate::state::{AllowedChain, Role, RoleMember};
+use anchor_lang::prelude::*;
+
+#[cfg_attr(feature = "event-cpi", event_cpi)]
+#[derive(Accounts)]
+#[instruction(args: RemoveAllowedChainArgs)]
+pub struct RemoveAllowedChain<'info> {
+    #[account(
+        mut,
+        close = gateway_manager,
+        seeds = [AllowedChain::SEED_PREFIX, &args.chain.to_le_bytes()],
+        bump = allowed_chain.bump,
+    )]
+    allowed_chain: Account<'info, AllowedChain>,
+
+    #[account(
+        seeds = [
+            RoleMember::SEED_PREFIX,
+            &[Role::GatewayManager as u8],
+            gateway_manager.key().as_ref()
+        ],
+        bump = role_member.bump,
+    )]
+    role_member: Account<'info, RoleMember>,
+
+    #[account(mut)]
+    gateway_manager: Signer<'info>,
+}
+
+#[derive(Debug, Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct RemoveAllowedChainArgs {
+    chain: u16,
+}
+
+pub fn remove_allowed_chain(
+    ctx: Context<RemoveAllowedChain>,
+    args: RemoveAllowedChainArgs,
+) -> Result<()> {
+    emit_event!(ctx, crate::event::AllowedChainRemoved { chain: args.chain });
+
+    Ok(())
+}
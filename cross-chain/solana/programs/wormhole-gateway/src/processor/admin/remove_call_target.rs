@@ -0,0 +1,38 @@
+use crate::{
+    error::WormholeGatewayError,
+    state::{CallTarget, Custodian},
+};
+use anchor_lang::prelude::*;
+
+#[cfg_attr(feature = "event-cpi", event_cpi)]
+#[derive(Accounts)]
+pub struct RemoveCallTarget<'info> {
+    #[account(
+        seeds = [Custodian::SEED_PREFIX],
+        bump = custodian.bump,
+        has_one = authority @ WormholeGatewayError::IsNotAuthority,
+    )]
+    custodian: Account<'info, Custodian>,
+
+    #[account(
+        mut,
+        close = authority,
+        seeds = [CallTarget::SEED_PREFIX, call_target.program.as_ref()],
+        bump = call_target.bump,
+    )]
+    call_target: Account<'info, CallTarget>,
+
+    #[account(mut)]
+    authority: Signer<'info>,
+}
+
+pub fn remove_call_target(ctx: Context<RemoveCallTarget>) -> Result<()> {
+    emit_event!(
+        ctx,
+        crate::event::CallTargetRemoved {
+            program: ctx.accounts.call_target.program
+        }
+    );
+
+    Ok(())
+}
@@ -0,0 +1,53 @@
+use crate::{
+    error::WormholeGatewayError,
+    state::{GatewayInfo, Role, RoleMember},
+};
+use anchor_lang::prelude::*;
+
+#[cfg_attr(feature = "event-cpi", event_cpi)]
+#[derive(Accounts)]
+#[instruction(args: RemoveGatewayAddressArgs)]
+pub struct RemoveGatewayAddress<'info> {
+    /// The gateway can only be removed once nothing minted for transfers from its chain is left
+    /// outstanding, since its minted amount would be lost.
+    #[account(
+        mut,
+        close = gateway_manager,
+        seeds = [GatewayInfo::SEED_PREFIX, &args.chain.to_le_bytes()],
+        bump = gateway_info.bump,
+        constraint = gateway_info.minted_amount == 0 @ WormholeGatewayError::GatewayMintedAmountNotZero,
+    )]
+    gateway_info: Account<'info, GatewayInfo>,
+
+    #[account(
+        seeds = [
+            RoleMember::SEED_PREFIX,
+            &[Role::GatewayManager as u8],
+            gateway_manager.key().as_ref()
+        ],
+        bump = role_member.bump,
+    )]
+    role_member: Account<'info, RoleMember>,
+
+    #[account(mut)]
+    gateway_manager: Signer<'info>,
+}
+
+#[derive(Debug, Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct RemoveGatewayAddressArgs {
+    chain: u16,
+}
+
+pub fn remove_gateway_address(
+    ctx: Context<RemoveGatewayAddress>,
+    args: RemoveGatewayAddressArgs,
+) -> Result<()> {
+    // NOTE: The gateway's minting limit is discarded along with its address. If the gateway is
+    // added again, it starts with a zero minting limit.
+    emit_event!(
+        ctx,
+        crate::event::GatewayAddressRemoved { chain: args.chain }
+    );
+
+    Ok(())
+}
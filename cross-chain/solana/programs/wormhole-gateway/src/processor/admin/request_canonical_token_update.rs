@@ -0,0 +1,35 @@
+use crate::{
+    error::WormholeGatewayError,
+    state::{CanonicalToken, Custodian},
+};
+use anchor_lang::prelude::*;
+
+#[cfg_attr(feature = "event-cpi", event_cpi)]
+#[derive(Accounts)]
+pub struct RequestCanonicalTokenUpdate<'info> {
+    #[account(
+        mut,
+        seeds = [Custodian::SEED_PREFIX],
+        bump = custodian.bump,
+        has_one = authority @ WormholeGatewayError::IsNotAuthority,
+    )]
+    custodian: Account<'info, Custodian>,
+
+    authority: Signer<'info>,
+}
+
+pub fn request_canonical_token_update(
+    ctx: Context<RequestCanonicalTokenUpdate>,
+    canonical_token: CanonicalToken,
+) -> Result<()> {
+    let custodian = &mut ctx.accounts.custodian;
+    custodian.pending_canonical_token = Some(canonical_token);
+    custodian.pending_canonical_token_since = Clock::get()?.unix_timestamp;
+
+    emit_event!(
+        ctx,
+        crate::event::CanonicalTokenUpdateRequested { canonical_token }
+    );
+
+    Ok(())
+}
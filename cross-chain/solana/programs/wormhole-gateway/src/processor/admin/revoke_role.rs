@@ -0,0 +1,44 @@
+use crate::{
+    error::WormholeGatewayError,
+    state::{Custodian, Role, RoleMember},
+};
+use anchor_lang::prelude::*;
+
+#[cfg_attr(feature = "event-cpi", event_cpi)]
+#[derive(Accounts)]
+#[instruction(role: Role)]
+pub struct RevokeRole<'info> {
+    #[account(
+        seeds = [Custodian::SEED_PREFIX],
+        bump = custodian.bump,
+        has_one = authority @ WormholeGatewayError::IsNotAuthority
+    )]
+    custodian: Account<'info, Custodian>,
+
+    #[account(mut)]
+    authority: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = member,
+        close = authority,
+        seeds = [RoleMember::SEED_PREFIX, &[role as u8], member.key().as_ref()],
+        bump = role_member.bump,
+    )]
+    role_member: Account<'info, RoleMember>,
+
+    /// CHECK: Account having the role revoked. This pubkey lives in `RoleMember`.
+    member: AccountInfo<'info>,
+}
+
+pub fn revoke_role(ctx: Context<RevokeRole>, role: Role) -> Result<()> {
+    emit_event!(
+        ctx,
+        crate::event::RoleRevoked {
+            role,
+            member: ctx.accounts.member.key(),
+        }
+    );
+
+    Ok(())
+}
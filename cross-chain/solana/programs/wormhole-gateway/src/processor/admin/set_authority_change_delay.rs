@@ -0,0 +1,53 @@
+use crate::{error::WormholeGatewayError, state::Custodian};
+use anchor_lang::prelude::*;
+
+#[cfg_attr(feature = "event-cpi", event_cpi)]
+#[derive(Accounts)]
+pub struct SetAuthorityChangeDelay<'info> {
+    #[account(
+        mut,
+        seeds = [Custodian::SEED_PREFIX],
+        bump = custodian.bump,
+        has_one = authority @ WormholeGatewayError::IsNotAuthority,
+    )]
+    custodian: Account<'info, Custodian>,
+
+    authority: Signer<'info>,
+}
+
+pub fn set_authority_change_delay(ctx: Context<SetAuthorityChangeDelay>, delay: u32) -> Result<()> {
+    let custodian = &mut ctx.accounts.custodian;
+
+    // Lowering the delay is itself delayed by the current delay, so that the authority cannot
+    // shorten it right before changing authority. The lower delay is requested first and set by
+    // calling this instruction again with the same delay once the current delay has elapsed.
+    if delay < custodian.authority_change_delay {
+        let now = Clock::get()?.unix_timestamp;
+
+        if custodian.pending_authority_change_delay != Some(delay) {
+            custodian.pending_authority_change_delay = Some(delay);
+            custodian.pending_authority_change_delay_since = now;
+
+            emit_event!(
+                ctx,
+                crate::event::AuthorityChangeDelayDecreaseRequested { delay }
+            );
+
+            return Ok(());
+        }
+
+        require_gte!(
+            now.saturating_sub(custodian.pending_authority_change_delay_since),
+            i64::from(custodian.authority_change_delay),
+            WormholeGatewayError::AuthorityChangeDelayNotElapsed
+        );
+    }
+
+    custodian.authority_change_delay = delay;
+    custodian.pending_authority_change_delay = None;
+    custodian.pending_authority_change_delay_since = 0;
+
+    emit_event!(ctx, crate::event::AuthorityChangeDelayUpdated { delay });
+
+    Ok(())
+}
@@ -1,6 +1,7 @@
 use crate::{error::WormholeGatewayError, state::Custodian};
 use anchor_lang::prelude::*;
 
+#[cfg_attr(feature = "event-cpi", event_cpi)]
 #[derive(Accounts)]
 pub struct TakeAuthority<'info> {
     #[account(
@@ -23,6 +24,17 @@ impl<'info> TakeAuthority<'info> {
                     WormholeGatewayError::IsNotPendingAuthority
                 );
 
+                // The pending authority can only take over after the delay has elapsed.
+                let custodian = &ctx.accounts.custodian;
+                let elapsed = Clock::get()?
+                    .unix_timestamp
+                    .saturating_sub(custodian.pending_authority_since);
+                require_gte!(
+                    elapsed,
+                    i64::from(custodian.authority_change_delay),
+                    WormholeGatewayError::AuthorityChangeDelayNotElapsed
+                );
+
                 Ok(())
             }
             None => err!(WormholeGatewayError::NoPendingAuthorityChange),
@@ -32,7 +44,20 @@ impl<'info> TakeAuthority<'info> {
 
 #[access_control(TakeAuthority::constraints(&ctx))]
 pub fn take_authority(ctx: Context<TakeAuthority>) -> Result<()> {
-    ctx.accounts.custodian.authority = ctx.accounts.pending_authority.key();
-    ctx.accounts.custodian.pending_authority = None;
+    let custodian = &mut ctx.accounts.custodian;
+    let old_authority = custodian.authority;
+
+    custodian.authority = ctx.accounts.pending_authority.key();
+    custodian.pending_authority = None;
+    custodian.pending_authority_since = 0;
+
+    emit_event!(
+        ctx,
+        crate::event::AuthorityTaken {
+            old_authority,
+            new_authority: custodian.authority,
+        }
+    );
+
     Ok(())
 }
@@ -0,0 +1,53 @@
+use crate::{
+    error::WormholeGatewayError,
+    state::{Custodian, Role, RoleMember},
+};
+use anchor_lang::prelude::*;
+
+#[cfg_attr(feature = "event-cpi", event_cpi)]
+#[derive(Accounts)]
+pub struct Unpause<'info> {
+    #[account(
+        mut,
+        seeds = [Custodian::SEED_PREFIX],
+        bump = custodian.bump,
+    )]
+    custodian: Account<'info, Custodian>,
+
+    #[account(
+        seeds = [
+            RoleMember::SEED_PREFIX,
+            &[Role::Unpauser as u8],
+            unpauser.key().as_ref()
+        ],
+        bump = role_member.bump,
+    )]
+    role_member: Account<'info, RoleMember>,
+
+    unpauser: Signer<'info>,
+}
+
+impl<'info> Unpause<'info> {
+    fn constraints(ctx: &Context<Self>) -> Result<()> {
+        require!(
+            ctx.accounts.custodian.paused,
+            WormholeGatewayError::IsNotPaused
+        );
+
+        Ok(())
+    }
+}
+
+#[access_control(Unpause::constraints(&ctx))]
+pub fn unpause(ctx: Context<Unpause>) -> Result<()> {
+    ctx.accounts.custodian.paused = false;
+
+    emit_event!(
+        ctx,
+        crate::event::Unpaused {
+            unpauser: ctx.accounts.unpauser.key(),
+        }
+    );
+
+    Ok(())
+}
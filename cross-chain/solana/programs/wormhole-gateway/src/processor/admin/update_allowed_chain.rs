@@ -0,0 +1,62 @@
+use crate::state::{AllowedChain, RecipientFormat, Role, RoleMember};
+use anchor_lang::prelude::*;
+
+#[cfg_attr(feature = "event-cpi", event_cpi)]
+#[derive(Accounts)]
+#[instruction(args: UpdateAllowedChainArgs)]
+pub struct UpdateAllowedChain<'info> {
+    #[account(
+        init_if_needed,
+        payer = gateway_manager,
+        space = 8 + AllowedChain::INIT_SPACE,
+        seeds = [AllowedChain::SEED_PREFIX, &args.chain.to_le_bytes()],
+        bump,
+    )]
+    allowed_chain: Account<'info, AllowedChain>,
+
+    #[account(
+        seeds = [
+            RoleMember::SEED_PREFIX,
+            &[Role::GatewayManager as u8],
+            gateway_manager.key().as_ref()
+        ],
+        bump = role_member.bump,
+    )]
+    role_member: Account<'info, RoleMember>,
+
+    #[account(mut)]
+    gateway_manager: Signer<'info>,
+
+    system_program: Program<'info, System>,
+}
+
+#[derive(Debug, Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct UpdateAllowedChainArgs {
+    chain: u16,
+    recipient_format: RecipientFormat,
+}
+
+pub fn update_allowed_chain(
+    ctx: Context<UpdateAllowedChain>,
+    args: UpdateAllowedChainArgs,
+) -> Result<()> {
+    let UpdateAllowedChainArgs {
+        chain,
+        recipient_format,
+    } = args;
+
+    ctx.accounts.allowed_chain.set_inner(AllowedChain {
+        bump: ctx.bumps["allowed_chain"],
+        recipient_format,
+    });
+
+    emit_event!(
+        ctx,
+        crate::event::AllowedChainUpdated {
+            chain,
+            recipient_format
+        }
+    );
+
+    Ok(())
+}
@@ -0,0 +1,120 @@
+use crate::{
+    constants::{CANONICAL_TOKEN_UPDATE_DELAY, WRAPPED_TBTC_TOKEN_SEED_PREFIX},
+    error::WormholeGatewayError,
+    state::{CanonicalToken, Custodian},
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token;
+use wormhole_anchor_sdk::token_bridge;
+
+#[cfg_attr(feature = "event-cpi", event_cpi)]
+#[derive(Accounts)]
+#[instruction(canonical_token: CanonicalToken)]
+pub struct UpdateCanonicalToken<'info> {
+    #[account(mut)]
+    authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [Custodian::SEED_PREFIX],
+        bump = custodian.bump,
+        has_one = authority @ WormholeGatewayError::IsNotAuthority,
+        has_one = wrapped_tbtc_token,
+    )]
+    custodian: Account<'info, Custodian>,
+
+    /// Current custody account, which must be empty.
+    wrapped_tbtc_token: Account<'info, token::TokenAccount>,
+
+    #[account(
+        seeds = [
+            token_bridge::WrappedMint::SEED_PREFIX,
+            &canonical_token.chain.to_be_bytes(),
+            canonical_token.address.as_ref()
+        ],
+        bump,
+        seeds::program = token_bridge::program::ID
+    )]
+    new_wrapped_tbtc_mint: Account<'info, token::Mint>,
+
+    /// Custody account for the new wrapped tBTC mint.
+    #[account(
+        init,
+        payer = authority,
+        token::mint = new_wrapped_tbtc_mint,
+        token::authority = custodian,
+        seeds = [WRAPPED_TBTC_TOKEN_SEED_PREFIX, new_wrapped_tbtc_mint.key().as_ref()],
+        bump
+    )]
+    new_wrapped_tbtc_token: Account<'info, token::TokenAccount>,
+
+    system_program: Program<'info, System>,
+    token_program: Program<'info, token::Token>,
+}
+
+impl<'info> UpdateCanonicalToken<'info> {
+    fn constraints(ctx: &Context<Self>, canonical_token: &CanonicalToken) -> Result<()> {
+        let custodian = &ctx.accounts.custodian;
+        match custodian.pending_canonical_token {
+            Some(pending_canonical_token) => {
+                require!(
+                    pending_canonical_token == *canonical_token,
+                    WormholeGatewayError::CanonicalTokenMismatch
+                );
+            }
+            None => return err!(WormholeGatewayError::NoPendingCanonicalTokenUpdate),
+        }
+
+        // Nothing can be backed by the current wrapped tBTC anymore.
+        require!(
+            ctx.accounts.wrapped_tbtc_token.amount == 0
+                && custodian.minted_amount == 0
+                && custodian.pending_amount == 0,
+            WormholeGatewayError::CustodyNotEmpty
+        );
+
+        let elapsed = Clock::get()?
+            .unix_timestamp
+            .saturating_sub(custodian.pending_canonical_token_since);
+        require_gte!(
+            elapsed,
+            CANONICAL_TOKEN_UPDATE_DELAY,
+            WormholeGatewayError::CanonicalTokenUpdateDelayNotElapsed
+        );
+
+        Ok(())
+    }
+}
+
+/// Replaces the canonical token and its wrapped tBTC custody.
+///
+/// NOTE: This is only meant to fix the canonical token before launch. The custody must be empty
+/// and nothing may be minted against it, so the canonical token cannot be updated once tBTC has
+/// been bridged to Solana. Wrapped tBTC held in custody would have to be migrated first, which is
+/// not supported.
+#[access_control(UpdateCanonicalToken::constraints(&ctx, &canonical_token))]
+pub fn update_canonical_token(
+    ctx: Context<UpdateCanonicalToken>,
+    canonical_token: CanonicalToken,
+) -> Result<()> {
+    let custodian = &mut ctx.accounts.custodian;
+    let old_canonical_token = custodian.canonical_token;
+
+    custodian.canonical_token = canonical_token;
+    custodian.pending_canonical_token = None;
+    custodian.pending_canonical_token_since = 0;
+    custodian.wrapped_tbtc_mint = ctx.accounts.new_wrapped_tbtc_mint.key();
+    custodian.wrapped_tbtc_token = ctx.accounts.new_wrapped_tbtc_token.key();
+
+    emit_event!(
+        ctx,
+        crate::event::CanonicalTokenUpdated {
+            old_canonical_token,
+            new_canonical_token: canonical_token,
+            wrapped_tbtc_mint: custodian.wrapped_tbtc_mint,
+            wrapped_tbtc_token: custodian.wrapped_tbtc_token,
+        }
+    );
+
+    Ok(())
+}
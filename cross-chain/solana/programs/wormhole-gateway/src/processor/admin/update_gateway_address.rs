@@ -1,30 +1,31 @@
-use crate::{
-    error::WormholeGatewayError,
-    state::{Custodian, GatewayInfo},
-};
+use crate::state::{GatewayInfo, Role, RoleMember};
 use anchor_lang::prelude::*;
 
+#[cfg_attr(feature = "event-cpi", event_cpi)]
 #[derive(Accounts)]
 #[instruction(args: UpdateGatewayAddressArgs)]
 pub struct UpdateGatewayAddress<'info> {
-    #[account(
-        seeds = [Custodian::SEED_PREFIX],
-        bump = custodian.bump,
-        has_one = authority @ WormholeGatewayError::IsNotAuthority,
-    )]
-    custodian: Account<'info, Custodian>,
-
     #[account(
         init_if_needed,
-        payer = authority,
+        payer = gateway_manager,
         space = 8 + GatewayInfo::INIT_SPACE,
         seeds = [GatewayInfo::SEED_PREFIX, &args.chain.to_le_bytes()],
         bump,
     )]
     gateway_info: Account<'info, GatewayInfo>,
 
+    #[account(
+        seeds = [
+            RoleMember::SEED_PREFIX,
+            &[Role::GatewayManager as u8],
+            gateway_manager.key().as_ref()
+        ],
+        bump = role_member.bump,
+    )]
+    role_member: Account<'info, RoleMember>,
+
     #[account(mut)]
-    authority: Signer<'info>,
+    gateway_manager: Signer<'info>,
 
     system_program: Program<'info, System>,
 }
@@ -41,15 +42,19 @@ pub fn update_gateway_address(
 ) -> Result<()> {
     let UpdateGatewayAddressArgs { chain, address } = args;
 
-    ctx.accounts.gateway_info.set_inner(GatewayInfo {
-        bump: ctx.bumps["gateway_info"],
-        address,
-    });
-
-    emit!(crate::event::GatewayAddressUpdated {
-        chain,
-        gateway: address
-    });
+    // NOTE: A new gateway starts with a zero minting limit, which has to be raised before tBTC can
+    // be minted for transfers from its chain.
+    let gateway_info = &mut ctx.accounts.gateway_info;
+    gateway_info.bump = ctx.bumps["gateway_info"];
+    gateway_info.address = address;
+
+    emit_event!(
+        ctx,
+        crate::event::GatewayAddressUpdated {
+            chain,
+            gateway: address
+        }
+    );
 
     Ok(())
 }
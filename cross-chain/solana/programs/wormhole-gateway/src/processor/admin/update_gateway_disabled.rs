@@ -0,0 +1,48 @@
+use crate::state::{GatewayInfo, Role, RoleMember};
+use anchor_lang::prelude::*;
+
+#[cfg_attr(feature = "event-cpi", event_cpi)]
+#[derive(Accounts)]
+#[instruction(args: UpdateGatewayDisabledArgs)]
+pub struct UpdateGatewayDisabled<'info> {
+    #[account(
+        mut,
+        seeds = [GatewayInfo::SEED_PREFIX, &args.chain.to_le_bytes()],
+        bump = gateway_info.bump,
+    )]
+    gateway_info: Account<'info, GatewayInfo>,
+
+    #[account(
+        seeds = [
+            RoleMember::SEED_PREFIX,
+            &[Role::GatewayManager as u8],
+            gateway_manager.key().as_ref()
+        ],
+        bump = role_member.bump,
+    )]
+    role_member: Account<'info, RoleMember>,
+
+    gateway_manager: Signer<'info>,
+}
+
+#[derive(Debug, Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct UpdateGatewayDisabledArgs {
+    chain: u16,
+    disabled: bool,
+}
+
+pub fn update_gateway_disabled(
+    ctx: Context<UpdateGatewayDisabled>,
+    args: UpdateGatewayDisabledArgs,
+) -> Result<()> {
+    let UpdateGatewayDisabledArgs { chain, disabled } = args;
+
+    ctx.accounts.gateway_info.disabled = disabled;
+
+    emit_event!(
+        ctx,
+        crate::event::GatewayDisabledUpdated { chain, disabled }
+    );
+
+    Ok(())
+}
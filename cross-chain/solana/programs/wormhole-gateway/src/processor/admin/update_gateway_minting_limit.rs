@@ -0,0 +1,54 @@
+use crate::state::{GatewayInfo, Role, RoleMember};
+use anchor_lang::prelude::*;
+
+#[cfg_attr(feature = "event-cpi", event_cpi)]
+#[derive(Accounts)]
+#[instruction(args: UpdateGatewayMintingLimitArgs)]
+pub struct UpdateGatewayMintingLimit<'info> {
+    #[account(
+        mut,
+        seeds = [GatewayInfo::SEED_PREFIX, &args.chain.to_le_bytes()],
+        bump = gateway_info.bump,
+    )]
+    gateway_info: Account<'info, GatewayInfo>,
+
+    #[account(
+        seeds = [
+            RoleMember::SEED_PREFIX,
+            &[Role::LimitManager as u8],
+            limit_manager.key().as_ref()
+        ],
+        bump = role_member.bump,
+    )]
+    role_member: Account<'info, RoleMember>,
+
+    limit_manager: Signer<'info>,
+}
+
+#[derive(Debug, Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct UpdateGatewayMintingLimitArgs {
+    chain: u16,
+    minting_limit: u64,
+}
+
+pub fn update_gateway_minting_limit(
+    ctx: Context<UpdateGatewayMintingLimit>,
+    args: UpdateGatewayMintingLimitArgs,
+) -> Result<()> {
+    let UpdateGatewayMintingLimitArgs {
+        chain,
+        minting_limit,
+    } = args;
+
+    ctx.accounts.gateway_info.minting_limit = minting_limit;
+
+    emit_event!(
+        ctx,
+        crate::event::GatewayMintingLimitUpdated {
+            chain,
+            minting_limit
+        }
+    );
+
+    Ok(())
+}
@@ -0,0 +1,33 @@
+use crate::state::{Custodian, Role, RoleMember};
+use anchor_lang::prelude::*;
+
+#[cfg_attr(feature = "event-cpi", event_cpi)]
+#[derive(Accounts)]
+pub struct UpdateL1Redeemer<'info> {
+    #[account(
+        mut,
+        seeds = [Custodian::SEED_PREFIX],
+        bump = custodian.bump,
+    )]
+    custodian: Account<'info, Custodian>,
+
+    #[account(
+        seeds = [
+            RoleMember::SEED_PREFIX,
+            &[Role::GatewayManager as u8],
+            gateway_manager.key().as_ref()
+        ],
+        bump = role_member.bump,
+    )]
+    role_member: Account<'info, RoleMember>,
+
+    gateway_manager: Signer<'info>,
+}
+
+pub fn update_l1_redeemer(ctx: Context<UpdateL1Redeemer>, l1_redeemer: [u8; 32]) -> Result<()> {
+    ctx.accounts.custodian.l1_redeemer = Some(l1_redeemer);
+
+    emit_event!(ctx, crate::event::L1RedeemerUpdated { l1_redeemer });
+
+    Ok(())
+}
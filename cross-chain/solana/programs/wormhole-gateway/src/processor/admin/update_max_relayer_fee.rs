@@ -0,0 +1,53 @@
+use crate::{
+    error::WormholeGatewayError,
+    state::{Custodian, Role, RoleMember},
+};
+use anchor_lang::prelude::*;
+
+/// Basis points of the whole transferred amount.
+const MAX_BPS: u16 = 10_000;
+
+#[cfg_attr(feature = "event-cpi", event_cpi)]
+#[derive(Accounts)]
+pub struct UpdateMaxRelayerFee<'info> {
+    #[account(
+        mut,
+        seeds = [Custodian::SEED_PREFIX],
+        bump = custodian.bump,
+    )]
+    custodian: Account<'info, Custodian>,
+
+    #[account(
+        seeds = [
+            RoleMember::SEED_PREFIX,
+            &[Role::GatewayManager as u8],
+            gateway_manager.key().as_ref()
+        ],
+        bump = role_member.bump,
+    )]
+    role_member: Account<'info, RoleMember>,
+
+    gateway_manager: Signer<'info>,
+}
+
+pub fn update_max_relayer_fee(
+    ctx: Context<UpdateMaxRelayerFee>,
+    max_relayer_fee_bps: u16,
+) -> Result<()> {
+    require_gte!(
+        MAX_BPS,
+        max_relayer_fee_bps,
+        WormholeGatewayError::MaxRelayerFeeTooHigh
+    );
+
+    ctx.accounts.custodian.max_relayer_fee_bps = max_relayer_fee_bps;
+
+    emit_event!(
+        ctx,
+        crate::event::MaxRelayerFeeUpdated {
+            max_relayer_fee_bps
+        }
+    );
+
+    Ok(())
+}
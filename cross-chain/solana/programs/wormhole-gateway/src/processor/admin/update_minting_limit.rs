@@ -1,25 +1,38 @@
-use crate::{error::WormholeGatewayError, state::Custodian};
+use crate::state::{Custodian, Role, RoleMember};
 use anchor_lang::prelude::*;
 
+#[cfg_attr(feature = "event-cpi", event_cpi)]
 #[derive(Accounts)]
 pub struct UpdateMintingLimit<'info> {
     #[account(
         mut,
         seeds = [Custodian::SEED_PREFIX],
         bump = custodian.bump,
-        has_one = authority @ WormholeGatewayError::IsNotAuthority,
     )]
     custodian: Account<'info, Custodian>,
 
-    authority: Signer<'info>,
+    #[account(
+        seeds = [
+            RoleMember::SEED_PREFIX,
+            &[Role::LimitManager as u8],
+            limit_manager.key().as_ref()
+        ],
+        bump = role_member.bump,
+    )]
+    role_member: Account<'info, RoleMember>,
+
+    limit_manager: Signer<'info>,
 }
 
 pub fn update_minting_limit(ctx: Context<UpdateMintingLimit>, new_limit: u64) -> Result<()> {
     ctx.accounts.custodian.minting_limit = new_limit;
 
-    emit!(crate::event::MintingLimitUpdated {
-        minting_limit: new_limit
-    });
+    emit_event!(
+        ctx,
+        crate::event::MintingLimitUpdated {
+            minting_limit: new_limit
+        }
+    );
 
     Ok(())
 }
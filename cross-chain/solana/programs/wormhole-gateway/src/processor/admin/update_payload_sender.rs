@@ -0,0 +1,36 @@
+use crate::state::{Custodian, Role, RoleMember};
+use anchor_lang::prelude::*;
+
+#[cfg_attr(feature = "event-cpi", event_cpi)]
+#[derive(Accounts)]
+pub struct UpdatePayloadSender<'info> {
+    #[account(
+        mut,
+        seeds = [Custodian::SEED_PREFIX],
+        bump = custodian.bump,
+    )]
+    custodian: Account<'info, Custodian>,
+
+    #[account(
+        seeds = [
+            RoleMember::SEED_PREFIX,
+            &[Role::GatewayManager as u8],
+            gateway_manager.key().as_ref()
+        ],
+        bump = role_member.bump,
+    )]
+    role_member: Account<'info, RoleMember>,
+
+    gateway_manager: Signer<'info>,
+}
+
+pub fn update_payload_sender(
+    ctx: Context<UpdatePayloadSender>,
+    payload_sender: Pubkey,
+) -> Result<()> {
+    ctx.accounts.custodian.payload_sender = Some(payload_sender);
+
+    emit_event!(ctx, crate::event::PayloadSenderUpdated { payload_sender });
+
+    Ok(())
+}
@@ -0,0 +1,36 @@
+use crate::state::{Custodian, ReceiveMode, Role, RoleMember};
+use anchor_lang::prelude::*;
+
+#[cfg_attr(feature = "event-cpi", event_cpi)]
+#[derive(Accounts)]
+pub struct UpdateReceiveMode<'info> {
+    #[account(
+        mut,
+        seeds = [Custodian::SEED_PREFIX],
+        bump = custodian.bump,
+    )]
+    custodian: Account<'info, Custodian>,
+
+    #[account(
+        seeds = [
+            RoleMember::SEED_PREFIX,
+            &[Role::LimitManager as u8],
+            limit_manager.key().as_ref()
+        ],
+        bump = role_member.bump,
+    )]
+    role_member: Account<'info, RoleMember>,
+
+    limit_manager: Signer<'info>,
+}
+
+pub fn update_receive_mode(
+    ctx: Context<UpdateReceiveMode>,
+    receive_mode: ReceiveMode,
+) -> Result<()> {
+    ctx.accounts.custodian.receive_mode = receive_mode;
+
+    emit_event!(ctx, crate::event::ReceiveModeUpdated { receive_mode });
+
+    Ok(())
+}
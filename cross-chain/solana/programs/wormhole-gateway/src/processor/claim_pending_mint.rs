@@ -0,0 +1,159 @@
+use crate::{
+    error::WormholeGatewayError,
+    state::{Custodian, PendingMint},
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token;
+
+#[cfg_attr(feature = "event-cpi", event_cpi)]
+#[derive(Accounts)]
+pub struct ClaimPendingMint<'info> {
+    /// NOTE: This account also acts as a minter for the TBTC program.
+    #[account(
+        mut,
+        seeds = [Custodian::SEED_PREFIX],
+        bump = custodian.bump,
+        has_one = tbtc_mint,
+    )]
+    custodian: Account<'info, Custodian>,
+
+    #[account(
+        mut,
+        seeds = [PendingMint::SEED_PREFIX, recipient.key().as_ref()],
+        bump = pending_mint.bump,
+        has_one = recipient,
+    )]
+    pending_mint: Account<'info, PendingMint>,
+
+    /// This mint is owned by the TBTC program. This PDA address is stored in the custodian account.
+    #[account(mut)]
+    tbtc_mint: Account<'info, token::Mint>,
+
+    /// Token account for minted tBTC.
+    #[account(
+        mut,
+        token::mint = tbtc_mint,
+        token::authority = recipient,
+    )]
+    recipient_token: Account<'info, token::TokenAccount>,
+
+    /// CHECK: Anyone can claim on behalf of the recipient, whose key is stored in the pending mint.
+    /// The recipient receives the pending mint's lamports once it is fully claimed.
+    #[account(mut)]
+    recipient: AccountInfo<'info>,
+
+    /// CHECK: TBTC program requires this account.
+    tbtc_config: UncheckedAccount<'info>,
+
+    /// The custodian's minter info, whose remaining allowance caps the amount of tBTC minted.
+    #[account(
+        mut,
+        seeds = [tbtc::MinterInfo::SEED_PREFIX, custodian.key().as_ref()],
+        bump = tbtc_minter_info.bump,
+        seeds::program = tbtc_program,
+    )]
+    tbtc_minter_info: Box<Account<'info, tbtc::MinterInfo>>,
+
+    /// The TBTC program's mint rate limit, whose available amount caps the amount of tBTC minted.
+    #[account(
+        mut,
+        seeds = [tbtc::MintRateLimit::SEED_PREFIX],
+        bump = tbtc_mint_rate_limit.bump,
+        seeds::program = tbtc_program,
+    )]
+    tbtc_mint_rate_limit: Box<Account<'info, tbtc::MintRateLimit>>,
+
+    token_program: Program<'info, token::Token>,
+    tbtc_program: Program<'info, tbtc::Tbtc>,
+}
+
+impl<'info> ClaimPendingMint<'info> {
+    /// Amount of tBTC the custodian can mint right now, which is capped by the minting limit, the
+    /// custodian's minter allowance and the TBTC program's mint rate limit.
+    fn mintable_amount(&self) -> Result<u64> {
+        let custodian = &self.custodian;
+        let now = Clock::get()?.unix_timestamp;
+
+        // The gateway's minting limit is not checked here, since queued amounts were already charged
+        // to its minted amount when the transfer was received.
+        Ok(custodian
+            .minting_limit
+            .saturating_sub(custodian.minted_amount)
+            .min(self.tbtc_minter_info.remaining_allowance())
+            .min(self.tbtc_mint_rate_limit.available_at(now)))
+    }
+
+    fn constraints(ctx: &Context<Self>) -> Result<()> {
+        require!(
+            !ctx.accounts.custodian.paused,
+            WormholeGatewayError::IsPaused
+        );
+
+        require_gt!(
+            ctx.accounts.pending_mint.amount,
+            0,
+            WormholeGatewayError::NoPendingMint
+        );
+
+        // There must be room under the minting limits to claim at least part of the pending mint.
+        require_gt!(
+            ctx.accounts.mintable_amount()?,
+            0,
+            WormholeGatewayError::MintingLimitExceeded
+        );
+
+        Ok(())
+    }
+}
+
+#[access_control(ClaimPendingMint::constraints(&ctx))]
+pub fn claim_pending_mint(ctx: Context<ClaimPendingMint>) -> Result<()> {
+    // Claim as much as the minting limits allow. The rest stays pending.
+    let amount = std::cmp::min(
+        ctx.accounts.pending_mint.amount,
+        ctx.accounts.mintable_amount()?,
+    );
+
+    ctx.accounts.pending_mint.amount -= amount;
+
+    let custodian = &mut ctx.accounts.custodian;
+    custodian.pending_amount = custodian.pending_amount.saturating_sub(amount);
+    custodian.minted_amount += amount;
+
+    let custodian = &ctx.accounts.custodian;
+
+    tbtc::cpi::mint(
+        CpiContext::new_with_signer(
+            ctx.accounts.tbtc_program.to_account_info(),
+            tbtc::cpi::accounts::Mint {
+                mint: ctx.accounts.tbtc_mint.to_account_info(),
+                config: ctx.accounts.tbtc_config.to_account_info(),
+                minter_info: ctx.accounts.tbtc_minter_info.to_account_info(),
+                minter: custodian.to_account_info(),
+                mint_rate_limit: ctx.accounts.tbtc_mint_rate_limit.to_account_info(),
+                recipient_token: ctx.accounts.recipient_token.to_account_info(),
+                token_program: ctx.accounts.token_program.to_account_info(),
+            },
+            &[&[Custodian::SEED_PREFIX, &[custodian.bump]]],
+        ),
+        amount,
+    )?;
+
+    emit_event!(
+        ctx,
+        crate::event::PendingMintClaimed {
+            recipient: ctx.accounts.recipient.key(),
+            amount,
+        }
+    );
+
+    // Close the pending mint once it is fully claimed. It is created again if more is queued for
+    // the recipient.
+    if ctx.accounts.pending_mint.amount == 0 {
+        ctx.accounts
+            .pending_mint
+            .close(ctx.accounts.recipient.to_account_info())?;
+    }
+
+    Ok(())
+}
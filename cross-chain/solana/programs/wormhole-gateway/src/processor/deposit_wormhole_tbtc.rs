@@ -7,7 +7,7 @@ use anchor_spl::token;
 pub struct DepositWormholeTbtc<'info> {
     /// NOTE: This account also acts as a minter for the TBTC program.
     #[account(
-        mut, 
+        mut,
         seeds = [Custodian::SEED_PREFIX],
         bump = custodian.bump,
         has_one = wrapped_tbtc_token,
@@ -52,14 +52,24 @@ pub struct DepositWormholeTbtc<'info> {
     tbtc_config: UncheckedAccount<'info>,
 
     /// CHECK: TBTC program requires this account.
+    #[account(mut)]
     tbtc_minter_info: UncheckedAccount<'info>,
 
+    /// CHECK: TBTC program requires this account.
+    #[account(mut)]
+    tbtc_mint_rate_limit: UncheckedAccount<'info>,
+
     token_program: Program<'info, token::Token>,
     tbtc_program: Program<'info, tbtc::Tbtc>,
 }
 
 impl<'info> DepositWormholeTbtc<'info> {
     fn constraints(ctx: &Context<Self>, amount: u64) -> Result<()> {
+        require!(
+            !ctx.accounts.custodian.paused,
+            WormholeGatewayError::IsPaused
+        );
+
         let updated_minted_amount = ctx
             .accounts
             .custodian
@@ -105,6 +115,7 @@ pub fn deposit_wormhole_tbtc(ctx: Context<DepositWormholeTbtc>, amount: u64) ->
                 config: ctx.accounts.tbtc_config.to_account_info(),
                 minter_info: ctx.accounts.tbtc_minter_info.to_account_info(),
                 minter: custodian.to_account_info(),
+                mint_rate_limit: ctx.accounts.tbtc_mint_rate_limit.to_account_info(),
                 recipient_token: ctx.accounts.recipient_token.to_account_info(),
                 token_program: ctx.accounts.token_program.to_account_info(),
             },
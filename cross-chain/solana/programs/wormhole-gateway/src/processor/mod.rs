@@ -1,11 +1,20 @@
 mod admin;
 pub use admin::*;
 
+mod claim_pending_mint;
+pub use claim_pending_mint::*;
+
 mod deposit_wormhole_tbtc;
 pub use deposit_wormhole_tbtc::*;
 
 mod receive_tbtc;
 pub use receive_tbtc::*;
 
+mod receive_tbtc_and_call;
+pub use receive_tbtc_and_call::*;
+
+mod receive_tbtc_without_call;
+pub use receive_tbtc_without_call::*;
+
 mod send_tbtc;
 pub use send_tbtc::*;
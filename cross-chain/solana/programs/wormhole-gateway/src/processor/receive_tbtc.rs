@@ -1,15 +1,17 @@
 use crate::{
-    constants::{TBTC_ETHEREUM_TOKEN_ADDRESS, TBTC_ETHEREUM_TOKEN_CHAIN},
     error::WormholeGatewayError,
-    state::Custodian,
+    payload::TransferPayload,
+    state::{Custodian, GatewayInfo, PendingMint, ReceiveMode},
 };
 use anchor_lang::prelude::*;
+use anchor_lang::system_program;
 use anchor_spl::{associated_token, token};
 use wormhole_anchor_sdk::{
     token_bridge::{self, program::TokenBridge},
     wormhole::{self as core_bridge, program::Wormhole as CoreBridge},
 };
 
+#[cfg_attr(feature = "event-cpi", event_cpi)]
 #[derive(Accounts)]
 #[instruction(message_hash: [u8; 32])]
 pub struct ReceiveTbtc<'info> {
@@ -31,7 +33,15 @@ pub struct ReceiveTbtc<'info> {
         bump,
         seeds::program = core_bridge_program
     )]
-    posted_vaa: Box<Account<'info, token_bridge::PostedTransferWith<[u8; 32]>>>,
+    posted_vaa: Box<Account<'info, token_bridge::PostedTransferWith<TransferPayload>>>,
+
+    /// Gateway registered for the chain this transfer was sent from.
+    #[account(
+        mut,
+        seeds = [GatewayInfo::SEED_PREFIX, &posted_vaa.emitter_chain().to_le_bytes()],
+        bump = gateway_info.bump,
+    )]
+    gateway_info: Account<'info, GatewayInfo>,
 
     /// CHECK: This claim account is created by the Token Bridge program when it redeems its inbound
     /// transfer. By checking whether this account exists is a short-circuit way of bailing out
@@ -52,23 +62,35 @@ pub struct ReceiveTbtc<'info> {
     #[account(mut)]
     tbtc_mint: Box<Account<'info, token::Mint>>,
 
-    /// Token account for minted tBTC.
+    /// CHECK: Associated token account for minted tBTC. The gateway will create it for the
+    /// recipient if it doesn't exist.
     ///
-    /// NOTE: Because the recipient is encoded in the transfer message payload, we can check the
-    /// authority from the deserialized VAA. But we should still check whether the authority is the
+    /// NOTE: Because the recipient is encoded in the transfer message payload, we can derive this
+    /// address from the deserialized VAA. But we should still check whether the recipient is the
     /// zero address in access control.
     #[account(
         mut,
-        token::mint = tbtc_mint,
-        token::authority = recipient,
+        address = associated_token::get_associated_token_address(
+            &recipient.key(),
+            &tbtc_mint.key()
+        ),
     )]
-    recipient_token: Box<Account<'info, token::TokenAccount>>,
+    recipient_token: AccountInfo<'info>,
 
     /// CHECK: This account needs to be in the context in case an associated token account needs to
     /// be created for him.
-    #[account(address = Pubkey::from(*posted_vaa.data().message()))]
+    #[account(address = Pubkey::from(posted_vaa.data().message().recipient()))]
     recipient: AccountInfo<'info>,
 
+    /// Token account for the relayer fee in tBTC, if the transfer payload specifies one. The fee is
+    /// waived if this account is not provided.
+    #[account(
+        mut,
+        token::mint = tbtc_mint,
+        token::authority = payer,
+    )]
+    payer_token: Option<Box<Account<'info, token::TokenAccount>>>,
+
     /// CHECK: This account exists just in case the minting limit is breached after this transfer.
     /// The gateway will create an associated token account for the recipient if it doesn't exist.
     ///
@@ -83,11 +105,45 @@ pub struct ReceiveTbtc<'info> {
     )]
     recipient_wrapped_token: AccountInfo<'info>,
 
+    /// CHECK: This account exists just in case the minting limit is breached after this transfer
+    /// and the custodian is in queue mode. The gateway will create the pending mint if it doesn't
+    /// exist.
+    #[account(
+        mut,
+        seeds = [PendingMint::SEED_PREFIX, recipient.key().as_ref()],
+        bump,
+    )]
+    pending_mint: AccountInfo<'info>,
+
+    /// CHECK: Deposit record of the BTC Depositor program, which is only needed when this transfer
+    /// finalizes a deposit initialized on Solana. This account is checked by the BTC Depositor
+    /// program.
+    #[account(mut)]
+    deposit_record: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Config of the BTC Depositor program, whose address is checked in access control.
+    btc_depositor_config: Option<UncheckedAccount<'info>>,
+
     /// CHECK: This account is needed for the TBTC program.
     tbtc_config: UncheckedAccount<'info>,
 
-    /// CHECK: This account is needed for the TBTC program.
-    tbtc_minter_info: UncheckedAccount<'info>,
+    /// The custodian's minter info, whose remaining allowance caps the amount of tBTC minted.
+    #[account(
+        mut,
+        seeds = [tbtc::MinterInfo::SEED_PREFIX, custodian.key().as_ref()],
+        bump = tbtc_minter_info.bump,
+        seeds::program = tbtc_program,
+    )]
+    tbtc_minter_info: Box<Account<'info, tbtc::MinterInfo>>,
+
+    /// The TBTC program's mint rate limit, whose available amount caps the amount of tBTC minted.
+    #[account(
+        mut,
+        seeds = [tbtc::MintRateLimit::SEED_PREFIX],
+        bump = tbtc_mint_rate_limit.bump,
+        seeds::program = tbtc_program,
+    )]
+    tbtc_mint_rate_limit: Box<Account<'info, tbtc::MintRateLimit>>,
 
     /// CHECK: This account is needed for the Token Bridge program.
     token_bridge_config: UncheckedAccount<'info>,
@@ -105,6 +161,8 @@ pub struct ReceiveTbtc<'info> {
     rent: UncheckedAccount<'info>,
 
     tbtc_program: Program<'info, tbtc::Tbtc>,
+    /// Its address is checked against the BTC Depositor program ID by the `Program` type.
+    btc_depositor_program: Option<Program<'info, btc_depositor::BtcDepositor>>,
     token_bridge_program: Program<'info, TokenBridge>,
     core_bridge_program: Program<'info, CoreBridge>,
     associated_token_program: Program<'info, associated_token::AssociatedToken>,
@@ -114,18 +172,35 @@ pub struct ReceiveTbtc<'info> {
 
 impl<'info> ReceiveTbtc<'info> {
     fn constraints(ctx: &Context<Self>) -> Result<()> {
+        require!(
+            !ctx.accounts.custodian.paused,
+            WormholeGatewayError::IsPaused
+        );
+
         // Check if transfer has already been claimed.
         require!(
             ctx.accounts.token_bridge_claim.data_is_empty(),
             WormholeGatewayError::TransferAlreadyRedeemed
         );
 
-        // Token info must match Ethereum's canonical tBTC token info.
+        // Token info must match the canonical tBTC token info.
         let transfer = ctx.accounts.posted_vaa.data();
+        let canonical_token = &ctx.accounts.custodian.canonical_token;
         require!(
-            transfer.token_chain() == TBTC_ETHEREUM_TOKEN_CHAIN
-                && *transfer.token_address() == TBTC_ETHEREUM_TOKEN_ADDRESS,
-            WormholeGatewayError::InvalidEthereumTbtc
+            transfer.token_chain() == canonical_token.chain
+                && *transfer.token_address() == canonical_token.address,
+            WormholeGatewayError::InvalidCanonicalToken
+        );
+
+        // Transfer must have been sent by the registered gateway of the source chain, which must not
+        // be disabled.
+        require!(
+            !ctx.accounts.gateway_info.disabled,
+            WormholeGatewayError::GatewayDisabled
+        );
+        require!(
+            *transfer.from_address() == ctx.accounts.gateway_info.address,
+            WormholeGatewayError::UnknownSourceGateway
         );
 
         // There must be an encoded amount.
@@ -142,12 +217,75 @@ impl<'info> ReceiveTbtc<'info> {
             WormholeGatewayError::RecipientZeroAddress
         );
 
+        // Either all or none of the accounts needed to finalize a deposit must be provided.
+        let deposit_accounts = [
+            ctx.accounts.deposit_record.is_some(),
+            ctx.accounts.btc_depositor_config.is_some(),
+            ctx.accounts.btc_depositor_program.is_some(),
+        ];
+        require!(
+            deposit_accounts
+                .iter()
+                .all(|&provided| provided == deposit_accounts[0]),
+            WormholeGatewayError::IncompleteDepositAccounts
+        );
+
+        // A transfer referencing a deposit initialized on Solana must finalize it, so a relayer
+        // cannot skip finalizing by leaving out the deposit accounts. Such transfers are only sent
+        // by `L1BTCDepositorWormhole`, which is checked below.
+        if transfer.message().deposit_key().is_some() {
+            require!(
+                deposit_accounts[0],
+                WormholeGatewayError::IncompleteDepositAccounts
+            );
+        }
+
+        // Only transfers sent by `L1BTCDepositorWormhole` can finalize deposits.
+        if let Some(btc_depositor_config) = &ctx.accounts.btc_depositor_config {
+            let (expected_btc_depositor_config, _) = Pubkey::find_program_address(
+                &[btc_depositor::Config::SEED_PREFIX],
+                &btc_depositor::ID,
+            );
+            require_keys_eq!(
+                btc_depositor_config.key(),
+                expected_btc_depositor_config,
+                WormholeGatewayError::InvalidBtcDepositorConfig
+            );
+
+            let btc_depositor_config =
+                Account::<btc_depositor::Config>::try_from(btc_depositor_config)?;
+            require!(
+                *transfer.from_address() == btc_depositor_config.l1_btc_depositor,
+                WormholeGatewayError::UnknownL1BtcDepositor
+            );
+        }
+
+        // If the transfer references a deposit initialized on Solana, it can only finalize that
+        // deposit.
+        if let (Some(deposit_key), Some(deposit_record)) = (
+            transfer.message().deposit_key(),
+            &ctx.accounts.deposit_record,
+        ) {
+            let (expected_deposit_record, _) = Pubkey::find_program_address(
+                &[btc_depositor::DepositRecord::SEED_PREFIX, deposit_key],
+                &btc_depositor::ID,
+            );
+            require_keys_eq!(
+                deposit_record.key(),
+                expected_deposit_record,
+                WormholeGatewayError::DepositRecordMismatch
+            );
+        }
+
         Ok(())
     }
 }
 
 #[access_control(ReceiveTbtc::constraints(&ctx))]
-pub fn receive_tbtc(ctx: Context<ReceiveTbtc>, _message_hash: [u8; 32]) -> Result<()> {
+pub fn receive_tbtc<'info>(
+    ctx: Context<'_, '_, '_, 'info, ReceiveTbtc<'info>>,
+    _message_hash: [u8; 32],
+) -> Result<()> {
     let wrapped_tbtc_token = &ctx.accounts.wrapped_tbtc_token;
     let wrapped_tbtc_mint = &ctx.accounts.wrapped_tbtc_mint;
 
@@ -184,17 +322,144 @@ pub fn receive_tbtc(ctx: Context<ReceiveTbtc>, _message_hash: [u8; 32]) -> Resul
     let amount = ctx.accounts.posted_vaa.data().amount();
     let recipient = &ctx.accounts.recipient;
 
-    emit!(crate::event::WormholeTbtcReceived {
-        receiver: recipient.key(),
+    emit_event!(
+        ctx,
+        crate::event::WormholeTbtcReceived {
+            receiver: recipient.key(),
+            amount
+        }
+    );
+
+    let custodian = &ctx.accounts.custodian;
+    let custodian_seeds = &[Custodian::SEED_PREFIX, &[custodian.bump]];
+
+    // If this transfer is the tBTC minted for a deposit initialized on Solana, mark the deposit as
+    // finalized. The BTC Depositor program checks that the deposit is for this recipient.
+    //
+    // NOTE: Finalizing is best-effort. A deposit that is already finalized is skipped, so that the
+    // transfer can always be redeemed.
+    //
+    // With the `event-cpi` feature, the BTC Depositor program's event authority is passed as the
+    // first remaining account.
+    if let (Some(deposit_record), Some(btc_depositor_config), Some(btc_depositor_program)) = (
+        &ctx.accounts.deposit_record,
+        &ctx.accounts.btc_depositor_config,
+        &ctx.accounts.btc_depositor_program,
+    ) {
+        let deposit_state =
+            Account::<btc_depositor::DepositRecord>::try_from(deposit_record)?.state;
+        if deposit_state == btc_depositor::DepositState::Initialized {
+            btc_depositor::cpi::finalize_deposit(
+                CpiContext::new_with_signer(
+                    btc_depositor_program.to_account_info(),
+                    btc_depositor::cpi::accounts::FinalizeDeposit {
+                        config: btc_depositor_config.to_account_info(),
+                        deposit_record: deposit_record.to_account_info(),
+                        recipient: recipient.to_account_info(),
+                        gateway_custodian: custodian.to_account_info(),
+                        #[cfg(feature = "event-cpi")]
+                        event_authority: ctx
+                            .remaining_accounts
+                            .first()
+                            .ok_or(anchor_lang::error::ErrorCode::AccountNotEnoughKeys)?
+                            .to_account_info(),
+                        #[cfg(feature = "event-cpi")]
+                        program: btc_depositor_program.to_account_info(),
+                    },
+                    &[custodian_seeds],
+                ),
+                amount,
+            )?;
+        }
+    }
+
+    let gateway_info = &ctx.accounts.gateway_info;
+
+    // We mint canonical tBTC as long as the minting limit, the source chain's minting limit, the
+    // custodian's minter allowance and the TBTC program's mint rate limit allow it. Otherwise we
+    // send Wormhole tBTC, either for the whole amount or, in split mode, only for the amount
+    // exceeding the limits. In queue mode, the amount exceeding any limit but the source chain's
+    // stays in custody as a pending mint, which counts towards the source chain's minted amount.
+    // Anything exceeding the source chain's minting limit is always sent as Wormhole tBTC.
+    let receive_mode = custodian.receive_mode;
+    let remaining_gateway_limit = gateway_info
+        .minting_limit
+        .saturating_sub(gateway_info.minted_amount);
+    let remaining_limit = custodian
+        .minting_limit
+        .saturating_sub(custodian.minted_amount)
+        .min(remaining_gateway_limit)
+        .min(ctx.accounts.tbtc_minter_info.remaining_allowance())
+        .min(
+            ctx.accounts
+                .tbtc_mint_rate_limit
+                .available_at(Clock::get()?.unix_timestamp),
+        );
+    let mint_amount = if amount <= remaining_limit {
         amount
-    });
+    } else {
+        match receive_mode {
+            ReceiveMode::AllOrNothing => 0,
+            ReceiveMode::Split | ReceiveMode::Queue => remaining_limit,
+        }
+    };
+    let queued_amount = match receive_mode {
+        ReceiveMode::Queue => amount.min(remaining_gateway_limit) - mint_amount,
+        _ => 0,
+    };
+    let wrapped_amount = amount - mint_amount - queued_amount;
+
+    // The relayer fee is paid out of the minted tBTC, up to the maximum fee of the minted amount.
+    // So no fee is paid if nothing is minted, and the recipient always keeps some minted tBTC.
+    let relayer_fee = if ctx.accounts.payer_token.is_some() {
+        let max_relayer_fee =
+            u128::from(mint_amount) * u128::from(custodian.max_relayer_fee_bps) / 10_000;
+        ctx.accounts
+            .posted_vaa
+            .data()
+            .message()
+            .relayer_fee()
+            .min(max_relayer_fee as u64)
+    } else {
+        0
+    };
 
-    let updated_minted_amount = ctx.accounts.custodian.minted_amount.saturating_add(amount);
-    let custodian_seeds = &[Custodian::SEED_PREFIX, &[ctx.accounts.custodian.bump]];
+    ctx.accounts.gateway_info.minted_amount += mint_amount + queued_amount;
 
-    // We send Wormhole tBTC OR mint canonical tBTC. We do not want to send dust. Sending Wormhole
-    // tBTC is an exceptional situation and we want to keep it simple.
-    if updated_minted_amount > ctx.accounts.custodian.minting_limit {
+    if queued_amount > 0 {
+        msg!("Insufficient minted amount. Queueing pending mint");
+
+        let pending_mint_info = &ctx.accounts.pending_mint;
+
+        // Create pending mint for recipient if it doesn't exist already.
+        if pending_mint_info.data_is_empty() {
+            create_pending_mint(&ctx, ctx.bumps["pending_mint"])?;
+        }
+
+        let mut pending_mint = Account::<PendingMint>::try_from(pending_mint_info)?;
+        pending_mint.amount = pending_mint
+            .amount
+            .checked_add(queued_amount)
+            .ok_or(WormholeGatewayError::PendingAmountOverflow)?;
+        pending_mint.exit(&crate::ID)?;
+
+        ctx.accounts.custodian.pending_amount = ctx
+            .accounts
+            .custodian
+            .pending_amount
+            .checked_add(queued_amount)
+            .ok_or(WormholeGatewayError::PendingAmountOverflow)?;
+
+        emit_event!(
+            ctx,
+            crate::event::PendingMintQueued {
+                recipient: recipient.key(),
+                amount: queued_amount,
+            }
+        );
+    }
+
+    if wrapped_amount > 0 {
         msg!("Insufficient minted amount. Sending Wormhole tBTC instead");
 
         let ata = &ctx.accounts.recipient_wrapped_token;
@@ -225,27 +490,143 @@ pub fn receive_tbtc(ctx: Context<ReceiveTbtc>, _message_hash: [u8; 32]) -> Resul
                 },
                 &[custodian_seeds],
             ),
-            amount,
-        )
-    } else {
+            wrapped_amount,
+        )?;
+    }
+
+    if mint_amount > 0 {
         // The function is non-reentrant given bridge.completeTransferWithPayload
         // call that does not allow to use the same VAA again.
-        ctx.accounts.custodian.minted_amount = updated_minted_amount;
+        ctx.accounts.custodian.minted_amount += mint_amount;
+
+        let recipient_amount = mint_amount - relayer_fee;
+        if recipient_amount > 0 {
+            let ata = &ctx.accounts.recipient_token;
+
+            // Create associated token account for recipient if it doesn't exist already.
+            if ata.data_is_empty() {
+                associated_token::create(CpiContext::new(
+                    ctx.accounts.associated_token_program.to_account_info(),
+                    associated_token::Create {
+                        payer: ctx.accounts.payer.to_account_info(),
+                        associated_token: ata.to_account_info(),
+                        authority: recipient.to_account_info(),
+                        mint: ctx.accounts.tbtc_mint.to_account_info(),
+                        token_program: ctx.accounts.token_program.to_account_info(),
+                        system_program: ctx.accounts.system_program.to_account_info(),
+                    },
+                ))?;
+            }
+
+            mint_tbtc(&ctx, ata.to_account_info(), recipient_amount)?;
+        }
 
-        tbtc::cpi::mint(
-            CpiContext::new_with_signer(
-                ctx.accounts.tbtc_program.to_account_info(),
-                tbtc::cpi::accounts::Mint {
-                    mint: ctx.accounts.tbtc_mint.to_account_info(),
-                    config: ctx.accounts.tbtc_config.to_account_info(),
-                    minter_info: ctx.accounts.tbtc_minter_info.to_account_info(),
-                    minter: ctx.accounts.custodian.to_account_info(),
-                    recipient_token: ctx.accounts.recipient_token.to_account_info(),
-                    token_program: ctx.accounts.token_program.to_account_info(),
+        if let Some(payer_token) = &ctx.accounts.payer_token {
+            if relayer_fee > 0 {
+                mint_tbtc(&ctx, payer_token.to_account_info(), relayer_fee)?;
+
+                emit_event!(
+                    ctx,
+                    crate::event::RelayerFeePaid {
+                        relayer: ctx.accounts.payer.key(),
+                        receiver: recipient.key(),
+                        fee: relayer_fee,
+                    }
+                );
+            }
+        }
+    }
+
+    if mint_amount > 0 && (wrapped_amount > 0 || queued_amount > 0) {
+        emit_event!(
+            ctx,
+            crate::event::WormholeTbtcReceivedSplit {
+                receiver: recipient.key(),
+                minted_amount: mint_amount,
+                wrapped_amount,
+                queued_amount,
+            }
+        );
+    }
+
+    Ok(())
+}
+
+fn mint_tbtc<'info>(
+    ctx: &Context<'_, '_, '_, 'info, ReceiveTbtc<'info>>,
+    token: AccountInfo<'info>,
+    amount: u64,
+) -> Result<()> {
+    tbtc::cpi::mint(
+        CpiContext::new_with_signer(
+            ctx.accounts.tbtc_program.to_account_info(),
+            tbtc::cpi::accounts::Mint {
+                mint: ctx.accounts.tbtc_mint.to_account_info(),
+                config: ctx.accounts.tbtc_config.to_account_info(),
+                minter_info: ctx.accounts.tbtc_minter_info.to_account_info(),
+                minter: ctx.accounts.custodian.to_account_info(),
+                mint_rate_limit: ctx.accounts.tbtc_mint_rate_limit.to_account_info(),
+                recipient_token: token,
+                token_program: ctx.accounts.token_program.to_account_info(),
+            },
+            &[&[Custodian::SEED_PREFIX, &[ctx.accounts.custodian.bump]]],
+        ),
+        amount,
+    )
+}
+
+/// Creates the recipient's pending mint with a zero amount. Lamports may already have been sent to
+/// this PDA, so the account is funded, allocated and assigned separately instead of relying on the
+/// System program's create account instruction.
+fn create_pending_mint(ctx: &Context<ReceiveTbtc>, bump: u8) -> Result<()> {
+    let pending_mint = &ctx.accounts.pending_mint;
+    let recipient = ctx.accounts.recipient.key();
+    let pending_mint_seeds = &[PendingMint::SEED_PREFIX, recipient.as_ref(), &[bump]];
+
+    let space = 8 + PendingMint::INIT_SPACE;
+    let required_lamports = Rent::get()?
+        .minimum_balance(space)
+        .saturating_sub(pending_mint.lamports());
+    if required_lamports > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.payer.to_account_info(),
+                    to: pending_mint.to_account_info(),
                 },
-                &[custodian_seeds],
             ),
-            amount,
-        )
+            required_lamports,
+        )?;
+    }
+
+    system_program::allocate(
+        CpiContext::new_with_signer(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Allocate {
+                account_to_allocate: pending_mint.to_account_info(),
+            },
+            &[pending_mint_seeds],
+        ),
+        space as u64,
+    )?;
+
+    system_program::assign(
+        CpiContext::new_with_signer(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Assign {
+                account_to_assign: pending_mint.to_account_info(),
+            },
+            &[pending_mint_seeds],
+        ),
+        &crate::ID,
+    )?;
+
+    let mut data = pending_mint.try_borrow_mut_data()?;
+    PendingMint {
+        bump,
+        recipient,
+        amount: 0,
     }
+    .try_serialize(&mut &mut data[..])
 }
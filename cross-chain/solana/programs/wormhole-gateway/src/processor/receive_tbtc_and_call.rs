@@ -0,0 +1,454 @@
+use crate::{
+    constants::CALL_AUTHORITY_SEED_PREFIX,
+    error::WormholeGatewayError,
+    payload::ReceiveAndCallPayload,
+    state::{CallTarget, Custodian, GatewayInfo},
+};
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{
+    hash,
+    instruction::{AccountMeta, Instruction},
+    program::invoke_signed,
+};
+use anchor_spl::token;
+use wormhole_anchor_sdk::{
+    token_bridge::{self, program::TokenBridge},
+    wormhole::{self as core_bridge, program::Wormhole as CoreBridge},
+};
+
+/// Arguments of the `on_tbtc_received` instruction the gateway calls on the target program. The
+/// call authority PDA is passed as the first account and signs, followed by the recipient's token
+/// account and then the remaining accounts of `receive_tbtc_and_call`.
+#[derive(Debug, Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct TbtcReceived {
+    pub source_chain: u16,
+    pub sender: [u8; 32],
+    pub amount: u64,
+    pub message: [u8; 128],
+}
+
+#[cfg_attr(feature = "event-cpi", event_cpi)]
+#[derive(Accounts)]
+#[instruction(message_hash: [u8; 32])]
+pub struct ReceiveTbtcAndCall<'info> {
+    #[account(mut)]
+    payer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [Custodian::SEED_PREFIX],
+        bump = custodian.bump,
+        has_one = wrapped_tbtc_token,
+        has_one = wrapped_tbtc_mint,
+        has_one = tbtc_mint
+    )]
+    custodian: Account<'info, Custodian>,
+
+    #[account(
+        seeds = [core_bridge::SEED_PREFIX_POSTED_VAA, &message_hash],
+        bump,
+        seeds::program = core_bridge_program
+    )]
+    posted_vaa: Box<Account<'info, token_bridge::PostedTransferWith<ReceiveAndCallPayload>>>,
+
+    /// Gateway registered for the chain this transfer was sent from.
+    #[account(
+        mut,
+        seeds = [GatewayInfo::SEED_PREFIX, &posted_vaa.emitter_chain().to_le_bytes()],
+        bump = gateway_info.bump,
+    )]
+    gateway_info: Account<'info, GatewayInfo>,
+
+    /// CHECK: This claim account is created by the Token Bridge program when it redeems its inbound
+    /// transfer. By checking whether this account exists is a short-circuit way of bailing out
+    /// early if this transfer has already been redeemed (as opposed to letting the Token Bridge
+    /// instruction fail).
+    #[account(mut)]
+    token_bridge_claim: AccountInfo<'info>,
+
+    /// Custody account.
+    #[account(mut)]
+    wrapped_tbtc_token: Box<Account<'info, token::TokenAccount>>,
+
+    /// This mint is owned by the Wormhole Token Bridge program. This PDA address is stored in the
+    /// custodian account.
+    #[account(mut)]
+    wrapped_tbtc_mint: Box<Account<'info, token::Mint>>,
+
+    #[account(mut)]
+    tbtc_mint: Box<Account<'info, token::Mint>>,
+
+    /// Token account for minted tBTC, which is passed along to the target program.
+    #[account(
+        mut,
+        token::mint = tbtc_mint,
+        token::authority = recipient,
+    )]
+    recipient_token: Box<Account<'info, token::TokenAccount>>,
+
+    /// CHECK: This account is the authority of the recipient token account, which is encoded in the
+    /// transfer message payload.
+    #[account(address = posted_vaa.data().message().recipient)]
+    recipient: AccountInfo<'info>,
+
+    /// Allowlist entry of the target program. It does not exist if the target is not allowed.
+    #[account(
+        seeds = [CallTarget::SEED_PREFIX, target_program.key().as_ref()],
+        bump = call_target.bump,
+    )]
+    call_target: Account<'info, CallTarget>,
+
+    /// CHECK: Program called after minting tBTC, which is encoded in the transfer message payload.
+    #[account(
+        executable,
+        address = posted_vaa.data().message().target_program
+            @ WormholeGatewayError::CallTargetMismatch,
+    )]
+    target_program: AccountInfo<'info>,
+
+    /// CHECK: This PDA signs for calling the target program, which can use it to verify that it is
+    /// called by the gateway. It is derived from the target program, so that a target cannot pass
+    /// its signature along to another target.
+    #[account(
+        seeds = [CALL_AUTHORITY_SEED_PREFIX, target_program.key().as_ref()],
+        bump,
+    )]
+    call_authority: AccountInfo<'info>,
+
+    /// CHECK: This account is needed for the TBTC program.
+    tbtc_config: UncheckedAccount<'info>,
+
+    /// The custodian's minter info, whose remaining allowance caps the amount of tBTC minted.
+    #[account(
+        mut,
+        seeds = [tbtc::MinterInfo::SEED_PREFIX, custodian.key().as_ref()],
+        bump = tbtc_minter_info.bump,
+        seeds::program = tbtc_program,
+    )]
+    tbtc_minter_info: Box<Account<'info, tbtc::MinterInfo>>,
+
+    /// The TBTC program's mint rate limit, whose available amount caps the amount of tBTC minted.
+    #[account(
+        mut,
+        seeds = [tbtc::MintRateLimit::SEED_PREFIX],
+        bump = tbtc_mint_rate_limit.bump,
+        seeds::program = tbtc_program,
+    )]
+    tbtc_mint_rate_limit: Box<Account<'info, tbtc::MintRateLimit>>,
+
+    /// CHECK: This account is needed for the Token Bridge program.
+    token_bridge_config: UncheckedAccount<'info>,
+
+    /// CHECK: This account is needed for the Token Bridge program.
+    token_bridge_registered_emitter: UncheckedAccount<'info>,
+
+    /// CHECK: This account is needed for the Token Bridge program.
+    token_bridge_wrapped_asset: UncheckedAccount<'info>,
+
+    /// CHECK: This account is needed for the Token Bridge program.
+    token_bridge_mint_authority: UncheckedAccount<'info>,
+
+    /// CHECK: This account is needed for the Token Bridge program.
+    rent: UncheckedAccount<'info>,
+
+    tbtc_program: Program<'info, tbtc::Tbtc>,
+    token_bridge_program: Program<'info, TokenBridge>,
+    core_bridge_program: Program<'info, CoreBridge>,
+    token_program: Program<'info, token::Token>,
+    system_program: Program<'info, System>,
+}
+
+impl<'info> ReceiveTbtcAndCall<'info> {
+    fn constraints(ctx: &Context<Self>) -> Result<()> {
+        validate_receive_call(
+            &ctx.accounts.custodian,
+            &ctx.accounts.posted_vaa,
+            &ctx.accounts.gateway_info,
+            &ctx.accounts.token_bridge_claim,
+            &ctx.accounts.recipient,
+            &ctx.accounts.tbtc_minter_info,
+            &ctx.accounts.tbtc_mint_rate_limit,
+        )
+    }
+}
+
+#[access_control(ReceiveTbtcAndCall::constraints(&ctx))]
+pub fn receive_tbtc_and_call<'info>(
+    ctx: Context<'_, '_, '_, 'info, ReceiveTbtcAndCall<'info>>,
+    _message_hash: [u8; 32],
+) -> Result<()> {
+    let amount = redeem_and_mint(RedeemAndMint {
+        payer: &ctx.accounts.payer,
+        custodian: &mut ctx.accounts.custodian,
+        posted_vaa: &ctx.accounts.posted_vaa,
+        gateway_info: &mut ctx.accounts.gateway_info,
+        token_bridge_claim: &ctx.accounts.token_bridge_claim,
+        wrapped_tbtc_token: &ctx.accounts.wrapped_tbtc_token,
+        wrapped_tbtc_mint: &ctx.accounts.wrapped_tbtc_mint,
+        tbtc_mint: &ctx.accounts.tbtc_mint,
+        recipient_token: &ctx.accounts.recipient_token,
+        tbtc_config: &ctx.accounts.tbtc_config,
+        tbtc_minter_info: &ctx.accounts.tbtc_minter_info,
+        tbtc_mint_rate_limit: &ctx.accounts.tbtc_mint_rate_limit,
+        token_bridge_config: &ctx.accounts.token_bridge_config,
+        token_bridge_registered_emitter: &ctx.accounts.token_bridge_registered_emitter,
+        token_bridge_wrapped_asset: &ctx.accounts.token_bridge_wrapped_asset,
+        token_bridge_mint_authority: &ctx.accounts.token_bridge_mint_authority,
+        rent: &ctx.accounts.rent,
+        tbtc_program: &ctx.accounts.tbtc_program,
+        token_bridge_program: &ctx.accounts.token_bridge_program,
+        core_bridge_program: &ctx.accounts.core_bridge_program,
+        token_program: &ctx.accounts.token_program,
+        system_program: &ctx.accounts.system_program,
+    })?;
+
+    let source_chain = ctx.accounts.posted_vaa.emitter_chain();
+    let payload = *ctx.accounts.posted_vaa.data().message();
+
+    emit_event!(
+        ctx,
+        crate::event::WormholeTbtcReceivedAndCalled {
+            receiver: payload.recipient,
+            amount,
+            target_program: payload.target_program,
+            source_chain,
+            sender: payload.sender,
+        }
+    );
+
+    // Finally call the target program, passing along the remaining accounts as they are.
+    let call_authority = &ctx.accounts.call_authority;
+    let recipient_token = ctx.accounts.recipient_token.to_account_info();
+
+    let mut accounts = vec![
+        AccountMeta::new_readonly(call_authority.key(), true),
+        AccountMeta::new(recipient_token.key(), false),
+    ];
+    let mut account_infos = vec![
+        call_authority.to_account_info(),
+        recipient_token,
+        ctx.accounts.target_program.to_account_info(),
+    ];
+    for account in ctx.remaining_accounts {
+        accounts.push(if account.is_writable {
+            AccountMeta::new(account.key(), account.is_signer)
+        } else {
+            AccountMeta::new_readonly(account.key(), account.is_signer)
+        });
+        account_infos.push(account.to_account_info());
+    }
+
+    let mut data = hash::hash(b"global:on_tbtc_received").to_bytes()[..8].to_vec();
+    TbtcReceived {
+        source_chain,
+        sender: payload.sender,
+        amount,
+        message: payload.message,
+    }
+    .serialize(&mut data)?;
+
+    invoke_signed(
+        &Instruction {
+            program_id: payload.target_program,
+            accounts,
+            data,
+        },
+        &account_infos,
+        &[&[
+            CALL_AUTHORITY_SEED_PREFIX,
+            payload.target_program.as_ref(),
+            &[ctx.bumps["call_authority"]],
+        ]],
+    )?;
+
+    Ok(())
+}
+
+/// Checks shared by `receive_tbtc_and_call` and `receive_tbtc_without_call`, which both mint the
+/// whole amount of a transfer with a [ReceiveAndCallPayload].
+pub fn validate_receive_call<'info>(
+    custodian: &Account<'info, Custodian>,
+    posted_vaa: &Account<'info, token_bridge::PostedTransferWith<ReceiveAndCallPayload>>,
+    gateway_info: &Account<'info, GatewayInfo>,
+    token_bridge_claim: &AccountInfo<'info>,
+    recipient: &AccountInfo<'info>,
+    tbtc_minter_info: &Account<'info, tbtc::MinterInfo>,
+    tbtc_mint_rate_limit: &Account<'info, tbtc::MintRateLimit>,
+) -> Result<()> {
+    require!(!custodian.paused, WormholeGatewayError::IsPaused);
+
+    // Check if transfer has already been claimed.
+    require!(
+        token_bridge_claim.data_is_empty(),
+        WormholeGatewayError::TransferAlreadyRedeemed
+    );
+
+    // Token info must match the canonical tBTC token info.
+    let transfer = posted_vaa.data();
+    let canonical_token = &custodian.canonical_token;
+    require!(
+        transfer.token_chain() == canonical_token.chain
+            && *transfer.token_address() == canonical_token.address,
+        WormholeGatewayError::InvalidCanonicalToken
+    );
+
+    // Transfer must have been sent by the registered gateway of the source chain, which must not
+    // be disabled.
+    require!(
+        !gateway_info.disabled,
+        WormholeGatewayError::GatewayDisabled
+    );
+    require!(
+        *transfer.from_address() == gateway_info.address,
+        WormholeGatewayError::UnknownSourceGateway
+    );
+
+    require_eq!(
+        transfer.message().version,
+        ReceiveAndCallPayload::VERSION,
+        WormholeGatewayError::UnsupportedPayloadVersion
+    );
+
+    // There must be an encoded amount.
+    let amount = transfer.amount();
+    require_gt!(amount, 0, WormholeGatewayError::NoTbtcTransferred);
+
+    // Recipient must not be zero address.
+    require_keys_neq!(
+        recipient.key(),
+        Pubkey::default(),
+        WormholeGatewayError::RecipientZeroAddress
+    );
+
+    // The target program is called with the whole amount, so all of it must be minted. If any
+    // minting limit would be exceeded, this transfer can be redeemed once it is raised.
+    require_gte!(
+        custodian
+            .minting_limit
+            .saturating_sub(custodian.minted_amount),
+        amount,
+        WormholeGatewayError::MintingLimitExceeded
+    );
+    require_gte!(
+        gateway_info
+            .minting_limit
+            .saturating_sub(gateway_info.minted_amount),
+        amount,
+        WormholeGatewayError::MintingLimitExceeded
+    );
+    require_gte!(
+        tbtc_minter_info.remaining_allowance(),
+        amount,
+        WormholeGatewayError::MintingLimitExceeded
+    );
+    require_gte!(
+        tbtc_mint_rate_limit.available_at(Clock::get()?.unix_timestamp),
+        amount,
+        WormholeGatewayError::MintingLimitExceeded
+    );
+
+    Ok(())
+}
+
+pub struct RedeemAndMint<'ctx, 'info> {
+    pub(super) payer: &'ctx Signer<'info>,
+    pub(super) custodian: &'ctx mut Account<'info, Custodian>,
+    pub(super) posted_vaa:
+        &'ctx Account<'info, token_bridge::PostedTransferWith<ReceiveAndCallPayload>>,
+    pub(super) gateway_info: &'ctx mut Account<'info, GatewayInfo>,
+    pub(super) token_bridge_claim: &'ctx AccountInfo<'info>,
+    pub(super) wrapped_tbtc_token: &'ctx Account<'info, token::TokenAccount>,
+    pub(super) wrapped_tbtc_mint: &'ctx Account<'info, token::Mint>,
+    pub(super) tbtc_mint: &'ctx Account<'info, token::Mint>,
+    pub(super) recipient_token: &'ctx Account<'info, token::TokenAccount>,
+    pub(super) tbtc_config: &'ctx AccountInfo<'info>,
+    pub(super) tbtc_minter_info: &'ctx Account<'info, tbtc::MinterInfo>,
+    pub(super) tbtc_mint_rate_limit: &'ctx Account<'info, tbtc::MintRateLimit>,
+    pub(super) token_bridge_config: &'ctx AccountInfo<'info>,
+    pub(super) token_bridge_registered_emitter: &'ctx AccountInfo<'info>,
+    pub(super) token_bridge_wrapped_asset: &'ctx AccountInfo<'info>,
+    pub(super) token_bridge_mint_authority: &'ctx AccountInfo<'info>,
+    pub(super) rent: &'ctx AccountInfo<'info>,
+    pub(super) tbtc_program: &'ctx Program<'info, tbtc::Tbtc>,
+    pub(super) token_bridge_program: &'ctx Program<'info, TokenBridge>,
+    pub(super) core_bridge_program: &'ctx Program<'info, CoreBridge>,
+    pub(super) token_program: &'ctx Program<'info, token::Token>,
+    pub(super) system_program: &'ctx Program<'info, System>,
+}
+
+/// Redeems the Token Bridge transfer and mints all of its amount to the recipient's token account,
+/// returning the minted amount.
+pub fn redeem_and_mint(redeem_and_mint: RedeemAndMint) -> Result<u64> {
+    let RedeemAndMint {
+        payer,
+        custodian,
+        posted_vaa,
+        gateway_info,
+        token_bridge_claim,
+        wrapped_tbtc_token,
+        wrapped_tbtc_mint,
+        tbtc_mint,
+        recipient_token,
+        tbtc_config,
+        tbtc_minter_info,
+        tbtc_mint_rate_limit,
+        token_bridge_config,
+        token_bridge_registered_emitter,
+        token_bridge_wrapped_asset,
+        token_bridge_mint_authority,
+        rent,
+        tbtc_program,
+        token_bridge_program,
+        core_bridge_program,
+        token_program,
+        system_program,
+    } = redeem_and_mint;
+
+    // Redeem the token transfer.
+    token_bridge::complete_transfer_wrapped_with_payload(CpiContext::new_with_signer(
+        token_bridge_program.to_account_info(),
+        token_bridge::CompleteTransferWrappedWithPayload {
+            payer: payer.to_account_info(),
+            config: token_bridge_config.to_account_info(),
+            vaa: posted_vaa.to_account_info(),
+            claim: token_bridge_claim.to_account_info(),
+            foreign_endpoint: token_bridge_registered_emitter.to_account_info(),
+            to: wrapped_tbtc_token.to_account_info(),
+            redeemer: custodian.to_account_info(),
+            wrapped_mint: wrapped_tbtc_mint.to_account_info(),
+            wrapped_metadata: token_bridge_wrapped_asset.to_account_info(),
+            mint_authority: token_bridge_mint_authority.to_account_info(),
+            rent: rent.to_account_info(),
+            system_program: system_program.to_account_info(),
+            token_program: token_program.to_account_info(),
+            wormhole_program: core_bridge_program.to_account_info(),
+        },
+        &[&[token_bridge::SEED_PREFIX_REDEEMER, &[custodian.bump]]],
+    ))?;
+
+    let amount = posted_vaa.data().amount();
+
+    // The function is non-reentrant given bridge.completeTransferWithPayload
+    // call that does not allow to use the same VAA again.
+    custodian.minted_amount += amount;
+    gateway_info.minted_amount += amount;
+
+    tbtc::cpi::mint(
+        CpiContext::new_with_signer(
+            tbtc_program.to_account_info(),
+            tbtc::cpi::accounts::Mint {
+                mint: tbtc_mint.to_account_info(),
+                config: tbtc_config.to_account_info(),
+                minter_info: tbtc_minter_info.to_account_info(),
+                minter: custodian.to_account_info(),
+                mint_rate_limit: tbtc_mint_rate_limit.to_account_info(),
+                recipient_token: recipient_token.to_account_info(),
+                token_program: token_program.to_account_info(),
+            },
+            &[&[Custodian::SEED_PREFIX, &[custodian.bump]]],
+        ),
+        amount,
+    )?;
+
+    Ok(amount)
+}
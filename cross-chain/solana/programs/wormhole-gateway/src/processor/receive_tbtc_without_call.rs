@@ -0,0 +1,191 @@
+use super::receive_tbtc_and_call::{redeem_and_mint, validate_receive_call, RedeemAndMint};
+use crate::{
+    error::WormholeGatewayError,
+    payload::ReceiveAndCallPayload,
+    state::{Custodian, GatewayInfo},
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token;
+use wormhole_anchor_sdk::{
+    token_bridge::{self, program::TokenBridge},
+    wormhole::{self as core_bridge, program::Wormhole as CoreBridge},
+};
+
+/// Fallback for a transfer meant for `receive_tbtc_and_call`, whose target program was removed or
+/// always fails. The tBTC is minted to the recipient's token account without calling the target.
+///
+/// The recipient is usually a PDA of the target program, which cannot sign for this instruction.
+/// Recovering such a transfer is then up to the custodian authority.
+#[cfg_attr(feature = "event-cpi", event_cpi)]
+#[derive(Accounts)]
+#[instruction(message_hash: [u8; 32])]
+pub struct ReceiveTbtcWithoutCall<'info> {
+    /// Either the recipient or the custodian authority. Only the authority can redeem for a PDA
+    /// recipient.
+    #[account(mut)]
+    payer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [Custodian::SEED_PREFIX],
+        bump = custodian.bump,
+        has_one = wrapped_tbtc_token,
+        has_one = wrapped_tbtc_mint,
+        has_one = tbtc_mint
+    )]
+    custodian: Account<'info, Custodian>,
+
+    #[account(
+        seeds = [core_bridge::SEED_PREFIX_POSTED_VAA, &message_hash],
+        bump,
+        seeds::program = core_bridge_program
+    )]
+    posted_vaa: Box<Account<'info, token_bridge::PostedTransferWith<ReceiveAndCallPayload>>>,
+
+    /// Gateway registered for the chain this transfer was sent from.
+    #[account(
+        mut,
+        seeds = [GatewayInfo::SEED_PREFIX, &posted_vaa.emitter_chain().to_le_bytes()],
+        bump = gateway_info.bump,
+    )]
+    gateway_info: Account<'info, GatewayInfo>,
+
+    /// CHECK: This claim account is created by the Token Bridge program when it redeems its inbound
+    /// transfer. By checking whether this account exists is a short-circuit way of bailing out
+    /// early if this transfer has already been redeemed (as opposed to letting the Token Bridge
+    /// instruction fail).
+    #[account(mut)]
+    token_bridge_claim: AccountInfo<'info>,
+
+    /// Custody account.
+    #[account(mut)]
+    wrapped_tbtc_token: Box<Account<'info, token::TokenAccount>>,
+
+    /// This mint is owned by the Wormhole Token Bridge program. This PDA address is stored in the
+    /// custodian account.
+    #[account(mut)]
+    wrapped_tbtc_mint: Box<Account<'info, token::Mint>>,
+
+    #[account(mut)]
+    tbtc_mint: Box<Account<'info, token::Mint>>,
+
+    /// Token account for minted tBTC.
+    #[account(
+        mut,
+        token::mint = tbtc_mint,
+        token::authority = recipient,
+    )]
+    recipient_token: Box<Account<'info, token::TokenAccount>>,
+
+    /// CHECK: This account is the authority of the recipient token account, which is encoded in the
+    /// transfer message payload.
+    #[account(address = posted_vaa.data().message().recipient)]
+    recipient: AccountInfo<'info>,
+
+    /// CHECK: This account is needed for the TBTC program.
+    tbtc_config: UncheckedAccount<'info>,
+
+    /// The custodian's minter info, whose remaining allowance caps the amount of tBTC minted.
+    #[account(
+        mut,
+        seeds = [tbtc::MinterInfo::SEED_PREFIX, custodian.key().as_ref()],
+        bump = tbtc_minter_info.bump,
+        seeds::program = tbtc_program,
+    )]
+    tbtc_minter_info: Box<Account<'info, tbtc::MinterInfo>>,
+
+    /// The TBTC program's mint rate limit, whose available amount caps the amount of tBTC minted.
+    #[account(
+        mut,
+        seeds = [tbtc::MintRateLimit::SEED_PREFIX],
+        bump = tbtc_mint_rate_limit.bump,
+        seeds::program = tbtc_program,
+    )]
+    tbtc_mint_rate_limit: Box<Account<'info, tbtc::MintRateLimit>>,
+
+    /// CHECK: This account is needed for the Token Bridge program.
+    token_bridge_config: UncheckedAccount<'info>,
+
+    /// CHECK: This account is needed for the Token Bridge program.
+    token_bridge_registered_emitter: UncheckedAccount<'info>,
+
+    /// CHECK: This account is needed for the Token Bridge program.
+    token_bridge_wrapped_asset: UncheckedAccount<'info>,
+
+    /// CHECK: This account is needed for the Token Bridge program.
+    token_bridge_mint_authority: UncheckedAccount<'info>,
+
+    /// CHECK: This account is needed for the Token Bridge program.
+    rent: UncheckedAccount<'info>,
+
+    tbtc_program: Program<'info, tbtc::Tbtc>,
+    token_bridge_program: Program<'info, TokenBridge>,
+    core_bridge_program: Program<'info, CoreBridge>,
+    token_program: Program<'info, token::Token>,
+    system_program: Program<'info, System>,
+}
+
+impl<'info> ReceiveTbtcWithoutCall<'info> {
+    fn constraints(ctx: &Context<Self>) -> Result<()> {
+        // Only the recipient or the authority can skip calling the target program.
+        let payer = ctx.accounts.payer.key();
+        require!(
+            payer == ctx.accounts.recipient.key() || payer == ctx.accounts.custodian.authority,
+            WormholeGatewayError::IsNotRecipientOrAuthority
+        );
+
+        validate_receive_call(
+            &ctx.accounts.custodian,
+            &ctx.accounts.posted_vaa,
+            &ctx.accounts.gateway_info,
+            &ctx.accounts.token_bridge_claim,
+            &ctx.accounts.recipient,
+            &ctx.accounts.tbtc_minter_info,
+            &ctx.accounts.tbtc_mint_rate_limit,
+        )
+    }
+}
+
+#[access_control(ReceiveTbtcWithoutCall::constraints(&ctx))]
+pub fn receive_tbtc_without_call(
+    ctx: Context<ReceiveTbtcWithoutCall>,
+    _message_hash: [u8; 32],
+) -> Result<()> {
+    let amount = redeem_and_mint(RedeemAndMint {
+        payer: &ctx.accounts.payer,
+        custodian: &mut ctx.accounts.custodian,
+        posted_vaa: &ctx.accounts.posted_vaa,
+        gateway_info: &mut ctx.accounts.gateway_info,
+        token_bridge_claim: &ctx.accounts.token_bridge_claim,
+        wrapped_tbtc_token: &ctx.accounts.wrapped_tbtc_token,
+        wrapped_tbtc_mint: &ctx.accounts.wrapped_tbtc_mint,
+        tbtc_mint: &ctx.accounts.tbtc_mint,
+        recipient_token: &ctx.accounts.recipient_token,
+        tbtc_config: &ctx.accounts.tbtc_config,
+        tbtc_minter_info: &ctx.accounts.tbtc_minter_info,
+        tbtc_mint_rate_limit: &ctx.accounts.tbtc_mint_rate_limit,
+        token_bridge_config: &ctx.accounts.token_bridge_config,
+        token_bridge_registered_emitter: &ctx.accounts.token_bridge_registered_emitter,
+        token_bridge_wrapped_asset: &ctx.accounts.token_bridge_wrapped_asset,
+        token_bridge_mint_authority: &ctx.accounts.token_bridge_mint_authority,
+        rent: &ctx.accounts.rent,
+        tbtc_program: &ctx.accounts.tbtc_program,
+        token_bridge_program: &ctx.accounts.token_bridge_program,
+        core_bridge_program: &ctx.accounts.core_bridge_program,
+        token_program: &ctx.accounts.token_program,
+        system_program: &ctx.accounts.system_program,
+    })?;
+
+    let payload = *ctx.accounts.posted_vaa.data().message();
+
+    emit_event!(
+        ctx,
+        crate::event::WormholeTbtcReceivedWithoutCall {
+            receiver: payload.recipient,
+            amount,
+            target_program: payload.target_program,
+        }
+    );
+
+    Ok(())
+}
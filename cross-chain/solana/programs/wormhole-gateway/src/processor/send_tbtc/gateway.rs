@@ -1,6 +1,8 @@
 use crate::{
     constants::MSG_SEED_PREFIX,
-    state::{Custodian, GatewayInfo},
+    error::WormholeGatewayError,
+    payload::TransferPayload,
+    state::{AllowedChain, Custodian, GatewayInfo},
 };
 use anchor_lang::prelude::*;
 use anchor_spl::token;
@@ -9,11 +11,12 @@ use wormhole_anchor_sdk::{
     wormhole::{self as core_bridge, program::Wormhole as CoreBridge},
 };
 
+#[cfg_attr(feature = "event-cpi", event_cpi)]
 #[derive(Accounts)]
 #[instruction(args: SendTbtcGatewayArgs)]
 pub struct SendTbtcGateway<'info> {
     #[account(
-        mut, 
+        mut,
         seeds = [Custodian::SEED_PREFIX],
         bump = custodian.bump,
         has_one = wrapped_tbtc_token,
@@ -24,11 +27,20 @@ pub struct SendTbtcGateway<'info> {
     custodian: Account<'info, Custodian>,
 
     #[account(
+        mut,
         seeds = [GatewayInfo::SEED_PREFIX, &args.recipient_chain.to_le_bytes()],
         bump = gateway_info.bump,
     )]
     gateway_info: Account<'info, GatewayInfo>,
 
+    /// CHECK: Allowlist entry of the recipient chain, which is validated when sending. It is empty if
+    /// the chain is not allowed.
+    #[account(
+        seeds = [AllowedChain::SEED_PREFIX, &args.recipient_chain.to_le_bytes()],
+        bump,
+    )]
+    allowed_chain: UncheckedAccount<'info>,
+
     /// Custody account.
     #[account(mut)]
     wrapped_tbtc_token: Box<Account<'info, token::TokenAccount>>,
@@ -50,6 +62,10 @@ pub struct SendTbtcGateway<'info> {
     #[account(mut)]
     sender: Signer<'info>,
 
+    /// CHECK: This account is needed for the TBTC program.
+    #[account(mut)]
+    tbtc_config: UncheckedAccount<'info>,
+
     /// CHECK: This account is needed for the Token Bridge program.
     token_bridge_config: UncheckedAccount<'info>,
 
@@ -95,6 +111,7 @@ pub struct SendTbtcGateway<'info> {
     /// CHECK: This account is needed for the Token Bridge program.
     rent: UncheckedAccount<'info>,
 
+    tbtc_program: Program<'info, tbtc::Tbtc>,
     token_bridge_program: Program<'info, TokenBridge>,
     core_bridge_program: Program<'info, CoreBridge>,
     token_program: Program<'info, token::Token>,
@@ -103,8 +120,15 @@ pub struct SendTbtcGateway<'info> {
 
 impl<'info> SendTbtcGateway<'info> {
     fn constraints(ctx: &Context<Self>, args: &SendTbtcGatewayArgs) -> Result<()> {
+        require!(
+            !ctx.accounts.gateway_info.disabled,
+            WormholeGatewayError::GatewayDisabled
+        );
+
         super::validate_send(
+            &ctx.accounts.custodian,
             &ctx.accounts.wrapped_tbtc_token,
+            &ctx.accounts.allowed_chain,
             &args.recipient,
             args.amount,
         )
@@ -120,7 +144,10 @@ pub struct SendTbtcGatewayArgs {
 }
 
 #[access_control(SendTbtcGateway::constraints(&ctx, &args))]
-pub fn send_tbtc_gateway(ctx: Context<SendTbtcGateway>, args: SendTbtcGatewayArgs) -> Result<()> {
+pub fn send_tbtc_gateway<'info>(
+    ctx: Context<'_, '_, '_, 'info, SendTbtcGateway<'info>>,
+    args: SendTbtcGatewayArgs,
+) -> Result<()> {
     let SendTbtcGatewayArgs {
         amount,
         recipient_chain,
@@ -145,19 +172,39 @@ pub fn send_tbtc_gateway(ctx: Context<SendTbtcGateway>, args: SendTbtcGatewayArg
             sender,
             wrapped_tbtc_token,
             token_bridge_transfer_authority,
+            tbtc_config: &ctx.accounts.tbtc_config,
+            #[cfg(feature = "event-cpi")]
+            tbtc_event_authority: super::tbtc_event_authority(ctx.remaining_accounts)?,
+            tbtc_program: &ctx.accounts.tbtc_program,
             token_program,
         },
         amount,
-        recipient_chain,
-        Some(gateway),
-        recipient,
-        None, // arbiter_fee
-        nonce,
     )?;
 
+    // Account for tBTC sent back to the destination chain. No more can be sent there than was
+    // minted for transfers from it, since its gateway only holds that much canonical tBTC.
+    let gateway_info = &mut ctx.accounts.gateway_info;
+    gateway_info.minted_amount = gateway_info
+        .minted_amount
+        .checked_sub(amount)
+        .ok_or(WormholeGatewayError::GatewayMintedAmountUnderflow)?;
+
+    emit_event!(
+        ctx,
+        crate::event::WormholeTbtcSent {
+            amount,
+            recipient_chain,
+            gateway,
+            recipient,
+            arbiter_fee: 0,
+            nonce
+        }
+    );
+
     let custodian = &ctx.accounts.custodian;
 
-    // Finally transfer wrapped tBTC with the recipient encoded as this transfer's message.
+    // Finally transfer wrapped tBTC with the recipient encoded as this transfer's version 0
+    // payload, which the gateways on other chains decode as the recipient address.
     token_bridge::transfer_wrapped_with_payload(
         CpiContext::new_with_signer(
             ctx.accounts.token_bridge_program.to_account_info(),
@@ -198,7 +245,7 @@ pub fn send_tbtc_gateway(ctx: Context<SendTbtcGateway>, args: SendTbtcGatewayArg
         amount,
         gateway,
         recipient_chain,
-        recipient.to_vec(),
+        TransferPayload::v0(recipient).encode(),
         &crate::ID,
     )
 }
@@ -1,25 +1,49 @@
 mod gateway;
 pub use gateway::*;
 
+mod with_payload;
+pub use with_payload::*;
+
 mod wrapped;
 pub use wrapped::*;
 
 use crate::error::WormholeGatewayError;
-use crate::state::Custodian;
+use crate::state::{AllowedChain, Custodian, RecipientFormat};
 use anchor_lang::prelude::*;
 use anchor_spl::token;
 
-pub fn validate_send(
-    wrapped_tbtc_token: &Account<'_, token::TokenAccount>,
+pub fn validate_send<'info>(
+    custodian: &Account<'info, Custodian>,
+    wrapped_tbtc_token: &Account<'info, token::TokenAccount>,
+    allowed_chain: &AccountInfo<'info>,
     recipient: &[u8; 32],
     amount: u64,
 ) -> Result<()> {
+    require!(!custodian.paused, WormholeGatewayError::IsPaused);
     require!(*recipient != [0; 32], WormholeGatewayError::ZeroRecipient);
+
+    // The recipient chain must be allowed, and the recipient must match its address format.
+    require!(
+        !allowed_chain.data_is_empty(),
+        WormholeGatewayError::RecipientChainNotAllowed
+    );
+    let allowed_chain = Account::<AllowedChain>::try_from(allowed_chain)?;
+    match allowed_chain.recipient_format {
+        RecipientFormat::Evm => require!(
+            recipient[..12] == [0; 12],
+            WormholeGatewayError::InvalidEvmRecipient
+        ),
+        RecipientFormat::Bytes32 => (),
+    }
+
     require_gt!(amount, 0, WormholeGatewayError::ZeroAmount);
 
-    // Check that the wrapped tBTC in custody is at least enough to bridge out.
+    // Check that the wrapped tBTC in custody is at least enough to bridge out. Wrapped tBTC owed to
+    // pending mints cannot be bridged out.
     require_gte!(
-        wrapped_tbtc_token.amount,
+        wrapped_tbtc_token
+            .amount
+            .saturating_sub(custodian.pending_amount),
         amount,
         WormholeGatewayError::NotEnoughWrappedTbtc
     );
@@ -27,6 +51,18 @@ pub fn validate_send(
     Ok(())
 }
 
+/// With the `event-cpi` feature, the TBTC program emits events through a self-CPI, which needs its
+/// event authority. It is passed as the first remaining account, so that the account lists are the
+/// same as without the feature.
+#[cfg(feature = "event-cpi")]
+pub fn tbtc_event_authority<'ctx, 'info>(
+    remaining_accounts: &'ctx [AccountInfo<'info>],
+) -> Result<&'ctx AccountInfo<'info>> {
+    Ok(remaining_accounts
+        .first()
+        .ok_or(anchor_lang::error::ErrorCode::AccountNotEnoughKeys)?)
+}
+
 pub struct PrepareTransfer<'ctx, 'info> {
     custodian: &'ctx mut Account<'info, Custodian>,
     tbtc_mint: &'ctx Account<'info, token::Mint>,
@@ -34,18 +70,14 @@ pub struct PrepareTransfer<'ctx, 'info> {
     sender: &'ctx Signer<'info>,
     wrapped_tbtc_token: &'ctx Account<'info, token::TokenAccount>,
     token_bridge_transfer_authority: &'ctx AccountInfo<'info>,
+    tbtc_config: &'ctx AccountInfo<'info>,
+    #[cfg(feature = "event-cpi")]
+    tbtc_event_authority: &'ctx AccountInfo<'info>,
+    tbtc_program: &'ctx Program<'info, tbtc::Tbtc>,
     token_program: &'ctx Program<'info, token::Token>,
 }
 
-pub fn burn_and_prepare_transfer(
-    prepare_transfer: PrepareTransfer,
-    amount: u64,
-    recipient_chain: u16,
-    gateway: Option<[u8; 32]>,
-    recipient: [u8; 32],
-    arbiter_fee: Option<u64>,
-    nonce: u32,
-) -> Result<()> {
+pub fn burn_and_prepare_transfer(prepare_transfer: PrepareTransfer, amount: u64) -> Result<()> {
     let PrepareTransfer {
         custodian,
         tbtc_mint,
@@ -53,6 +85,10 @@ pub fn burn_and_prepare_transfer(
         sender,
         wrapped_tbtc_token,
         token_bridge_transfer_authority,
+        tbtc_config,
+        #[cfg(feature = "event-cpi")]
+        tbtc_event_authority,
+        tbtc_program,
         token_program,
     } = prepare_transfer;
 
@@ -62,28 +98,25 @@ pub fn burn_and_prepare_transfer(
         .checked_sub(amount)
         .ok_or(WormholeGatewayError::MintedAmountUnderflow)?;
 
-    // Burn TBTC mint.
-    token::burn(
+    // Burn tBTC through the TBTC program, so it is counted in its total burned amount.
+    tbtc::cpi::burn(
         CpiContext::new(
-            token_program.to_account_info(),
-            token::Burn {
+            tbtc_program.to_account_info(),
+            tbtc::cpi::accounts::Burn {
                 mint: tbtc_mint.to_account_info(),
-                from: sender_token.to_account_info(),
-                authority: sender.to_account_info(),
+                config: tbtc_config.to_account_info(),
+                owner: sender.to_account_info(),
+                owner_token: sender_token.to_account_info(),
+                token_program: token_program.to_account_info(),
+                #[cfg(feature = "event-cpi")]
+                event_authority: tbtc_event_authority.to_account_info(),
+                #[cfg(feature = "event-cpi")]
+                program: tbtc_program.to_account_info(),
             },
         ),
         amount,
     )?;
 
-    emit!(crate::event::WormholeTbtcSent {
-        amount,
-        recipient_chain,
-        gateway: gateway.unwrap_or_default(),
-        recipient,
-        arbiter_fee: arbiter_fee.unwrap_or_default(),
-        nonce
-    });
-
     // Delegate authority to Token Bridge's transfer authority.
     token::approve(
         CpiContext::new_with_signer(
@@ -0,0 +1,249 @@
+use crate::{
+    constants::MSG_SEED_PREFIX,
+    error::WormholeGatewayError,
+    state::{AllowedChain, Custodian},
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token;
+use wormhole_anchor_sdk::{
+    token_bridge::{self, program::TokenBridge},
+    wormhole::{self as core_bridge, program::Wormhole as CoreBridge},
+};
+
+#[cfg_attr(feature = "event-cpi", event_cpi)]
+#[derive(Accounts)]
+pub struct SendTbtcWithPayload<'info> {
+    #[account(
+        mut,
+        seeds = [Custodian::SEED_PREFIX],
+        bump = custodian.bump,
+        has_one = wrapped_tbtc_token,
+        has_one = wrapped_tbtc_mint,
+        has_one = tbtc_mint,
+        has_one = token_bridge_sender,
+    )]
+    custodian: Account<'info, Custodian>,
+
+    /// CHECK: Allowlist entry of the canonical tBTC chain, which is validated when sending. It is
+    /// empty if the chain is not allowed.
+    #[account(
+        seeds = [
+            AllowedChain::SEED_PREFIX,
+            &custodian.canonical_token.chain.to_le_bytes()
+        ],
+        bump,
+    )]
+    allowed_chain: UncheckedAccount<'info>,
+
+    /// Custody account.
+    #[account(mut)]
+    wrapped_tbtc_token: Box<Account<'info, token::TokenAccount>>,
+
+    /// CHECK: This account is needed for the Token Bridge program.
+    #[account(mut)]
+    wrapped_tbtc_mint: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    tbtc_mint: Box<Account<'info, token::Mint>>,
+
+    #[account(
+        mut,
+        token::mint = tbtc_mint,
+        token::authority = sender
+    )]
+    sender_token: Box<Account<'info, token::TokenAccount>>,
+
+    #[account(mut)]
+    sender: Signer<'info>,
+
+    /// Signer allowed to send tBTC with a payload, which is stored in the custodian.
+    payload_sender: Signer<'info>,
+
+    /// CHECK: This account is needed for the TBTC program.
+    #[account(mut)]
+    tbtc_config: UncheckedAccount<'info>,
+
+    /// CHECK: This account is needed for the Token Bridge program.
+    token_bridge_config: UncheckedAccount<'info>,
+
+    /// CHECK: This account is needed for the Token Bridge program.
+    token_bridge_wrapped_asset: UncheckedAccount<'info>,
+
+    /// CHECK: This account is needed for the Token Bridge program.
+    token_bridge_transfer_authority: UncheckedAccount<'info>,
+
+    /// CHECK: This account is needed for the Token Bridge program.
+    #[account(mut)]
+    core_bridge_data: UncheckedAccount<'info>,
+
+    /// CHECK: This account is needed for the Token Bridge program.
+    #[account(
+        mut,
+        seeds = [
+            MSG_SEED_PREFIX,
+            &core_emitter_sequence.value().to_le_bytes()
+        ],
+        bump,
+    )]
+    core_message: AccountInfo<'info>,
+
+    /// CHECK: This account is needed for the Token Bridge program.
+    token_bridge_core_emitter: UncheckedAccount<'info>,
+
+    /// CHECK: This account is needed for the Token Bridge program.
+    #[account(mut)]
+    core_emitter_sequence: Account<'info, core_bridge::SequenceTracker>,
+
+    /// CHECK: This account is needed for the Token Bridge program.
+    #[account(mut)]
+    core_fee_collector: UncheckedAccount<'info>,
+
+    /// CHECK: This account is needed for the Token Bridge program.
+    clock: UncheckedAccount<'info>,
+
+    /// CHECK: This account is needed for the Token Bridge program. This PDA is specifically used to
+    /// sign for transferring via Token Bridge program with a message.
+    token_bridge_sender: AccountInfo<'info>,
+
+    /// CHECK: This account is needed for the Token Bridge program.
+    rent: UncheckedAccount<'info>,
+
+    tbtc_program: Program<'info, tbtc::Tbtc>,
+    token_bridge_program: Program<'info, TokenBridge>,
+    core_bridge_program: Program<'info, CoreBridge>,
+    token_program: Program<'info, token::Token>,
+    system_program: Program<'info, System>,
+}
+
+impl<'info> SendTbtcWithPayload<'info> {
+    fn constraints(ctx: &Context<Self>, args: &SendTbtcWithPayloadArgs) -> Result<()> {
+        let l1_redeemer = ctx
+            .accounts
+            .custodian
+            .l1_redeemer
+            .ok_or(WormholeGatewayError::L1RedeemerNotSet)?;
+
+        // Only the payload sender can send to the L1 redeemer, so redemption requests cannot skip
+        // its validation.
+        require!(
+            ctx.accounts.custodian.payload_sender == Some(ctx.accounts.payload_sender.key()),
+            WormholeGatewayError::IsNotPayloadSender
+        );
+
+        super::validate_send(
+            &ctx.accounts.custodian,
+            &ctx.accounts.wrapped_tbtc_token,
+            &ctx.accounts.allowed_chain,
+            &l1_redeemer,
+            args.amount,
+        )
+    }
+}
+
+#[derive(Debug, Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct SendTbtcWithPayloadArgs {
+    pub amount: u64,
+    pub nonce: u32,
+    pub payload: Vec<u8>,
+}
+
+#[access_control(SendTbtcWithPayload::constraints(&ctx, &args))]
+pub fn send_tbtc_with_payload<'info>(
+    ctx: Context<'_, '_, '_, 'info, SendTbtcWithPayload<'info>>,
+    args: SendTbtcWithPayloadArgs,
+) -> Result<()> {
+    let SendTbtcWithPayloadArgs {
+        amount,
+        nonce,
+        payload,
+    } = args;
+
+    let sender = &ctx.accounts.sender;
+    let wrapped_tbtc_token = &ctx.accounts.wrapped_tbtc_token;
+    let token_bridge_transfer_authority = &ctx.accounts.token_bridge_transfer_authority;
+    let token_program = &ctx.accounts.token_program;
+
+    // The L1 redeemer was checked in the constraints.
+    let custodian = &ctx.accounts.custodian;
+    let recipient_chain = custodian.canonical_token.chain;
+    let l1_redeemer = custodian.l1_redeemer.unwrap();
+
+    // Prepare for wrapped tBTC transfer. As when sending wrapped tBTC, no gateway's minted amount is
+    // reduced, since the canonical tBTC chain has no gateway.
+    super::burn_and_prepare_transfer(
+        super::PrepareTransfer {
+            custodian: &mut ctx.accounts.custodian,
+            tbtc_mint: &ctx.accounts.tbtc_mint,
+            sender_token: &ctx.accounts.sender_token,
+            sender,
+            wrapped_tbtc_token,
+            token_bridge_transfer_authority,
+            tbtc_config: &ctx.accounts.tbtc_config,
+            #[cfg(feature = "event-cpi")]
+            tbtc_event_authority: super::tbtc_event_authority(ctx.remaining_accounts)?,
+            tbtc_program: &ctx.accounts.tbtc_program,
+            token_program,
+        },
+        amount,
+    )?;
+
+    emit_event!(
+        ctx,
+        crate::event::WormholeTbtcSentWithPayload {
+            sender: sender.key(),
+            amount,
+            recipient_chain,
+            l1_redeemer,
+            nonce,
+            payload: payload.clone()
+        }
+    );
+
+    let custodian = &ctx.accounts.custodian;
+
+    // Finally transfer wrapped tBTC to the L1 redeemer with the caller's payload as this transfer's
+    // message.
+    token_bridge::transfer_wrapped_with_payload(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_bridge_program.to_account_info(),
+            token_bridge::TransferWrappedWithPayload {
+                payer: sender.to_account_info(),
+                config: ctx.accounts.token_bridge_config.to_account_info(),
+                from: wrapped_tbtc_token.to_account_info(),
+                from_owner: custodian.to_account_info(),
+                wrapped_mint: ctx.accounts.wrapped_tbtc_mint.to_account_info(),
+                wrapped_metadata: ctx.accounts.token_bridge_wrapped_asset.to_account_info(),
+                authority_signer: token_bridge_transfer_authority.to_account_info(),
+                wormhole_bridge: ctx.accounts.core_bridge_data.to_account_info(),
+                wormhole_message: ctx.accounts.core_message.to_account_info(),
+                wormhole_emitter: ctx.accounts.token_bridge_core_emitter.to_account_info(),
+                wormhole_sequence: ctx.accounts.core_emitter_sequence.to_account_info(),
+                wormhole_fee_collector: ctx.accounts.core_fee_collector.to_account_info(),
+                clock: ctx.accounts.clock.to_account_info(),
+                sender: ctx.accounts.token_bridge_sender.to_account_info(),
+                rent: ctx.accounts.rent.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+                token_program: token_program.to_account_info(),
+                wormhole_program: ctx.accounts.core_bridge_program.to_account_info(),
+            },
+            &[
+                &[Custodian::SEED_PREFIX, &[custodian.bump]],
+                &[
+                    token_bridge::SEED_PREFIX_SENDER,
+                    &[ctx.accounts.custodian.token_bridge_sender_bump],
+                ],
+                &[
+                    MSG_SEED_PREFIX,
+                    &ctx.accounts.core_emitter_sequence.value().to_le_bytes(),
+                    &[ctx.bumps["core_message"]],
+                ],
+            ],
+        ),
+        nonce,
+        amount,
+        l1_redeemer,
+        recipient_chain,
+        payload,
+        &crate::ID,
+    )
+}
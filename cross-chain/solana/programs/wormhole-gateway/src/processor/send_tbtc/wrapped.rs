@@ -1,4 +1,7 @@
-use crate::{constants::MSG_SEED_PREFIX, state::Custodian};
+use crate::{
+    constants::MSG_SEED_PREFIX,
+    state::{AllowedChain, Custodian},
+};
 use anchor_lang::prelude::*;
 use anchor_spl::token;
 use wormhole_anchor_sdk::{
@@ -6,6 +9,7 @@ use wormhole_anchor_sdk::{
     wormhole::{self as core_bridge, program::Wormhole as CoreBridge},
 };
 
+#[cfg_attr(feature = "event-cpi", event_cpi)]
 #[derive(Accounts)]
 #[instruction(args: SendTbtcWrappedArgs)]
 pub struct SendTbtcWrapped<'info> {
@@ -19,6 +23,14 @@ pub struct SendTbtcWrapped<'info> {
     )]
     custodian: Account<'info, Custodian>,
 
+    /// CHECK: Allowlist entry of the recipient chain, which is validated when sending. It is empty if
+    /// the chain is not allowed.
+    #[account(
+        seeds = [AllowedChain::SEED_PREFIX, &args.recipient_chain.to_le_bytes()],
+        bump,
+    )]
+    allowed_chain: UncheckedAccount<'info>,
+
     /// Custody account.
     #[account(mut)]
     wrapped_tbtc_token: Box<Account<'info, token::TokenAccount>>,
@@ -40,6 +52,10 @@ pub struct SendTbtcWrapped<'info> {
     #[account(mut)]
     sender: Signer<'info>,
 
+    /// CHECK: This account is needed for the TBTC program.
+    #[account(mut)]
+    tbtc_config: UncheckedAccount<'info>,
+
     /// CHECK: This account is needed for the Token Bridge program.
     token_bridge_config: UncheckedAccount<'info>,
 
@@ -81,6 +97,7 @@ pub struct SendTbtcWrapped<'info> {
     /// CHECK: This account is needed for the Token Bridge program.
     rent: UncheckedAccount<'info>,
 
+    tbtc_program: Program<'info, tbtc::Tbtc>,
     token_bridge_program: Program<'info, TokenBridge>,
     core_bridge_program: Program<'info, CoreBridge>,
     token_program: Program<'info, token::Token>,
@@ -90,7 +107,9 @@ pub struct SendTbtcWrapped<'info> {
 impl<'info> SendTbtcWrapped<'info> {
     fn constraints(ctx: &Context<Self>, args: &SendTbtcWrappedArgs) -> Result<()> {
         super::validate_send(
+            &ctx.accounts.custodian,
             &ctx.accounts.wrapped_tbtc_token,
+            &ctx.accounts.allowed_chain,
             &args.recipient,
             args.amount,
         )
@@ -107,7 +126,10 @@ pub struct SendTbtcWrappedArgs {
 }
 
 #[access_control(SendTbtcWrapped::constraints(&ctx, &args))]
-pub fn send_tbtc_wrapped(ctx: Context<SendTbtcWrapped>, args: SendTbtcWrappedArgs) -> Result<()> {
+pub fn send_tbtc_wrapped<'info>(
+    ctx: Context<'_, '_, '_, 'info, SendTbtcWrapped<'info>>,
+    args: SendTbtcWrappedArgs,
+) -> Result<()> {
     let SendTbtcWrappedArgs {
         amount,
         recipient_chain,
@@ -121,7 +143,8 @@ pub fn send_tbtc_wrapped(ctx: Context<SendTbtcWrapped>, args: SendTbtcWrappedArg
     let token_bridge_transfer_authority = &ctx.accounts.token_bridge_transfer_authority;
     let token_program = &ctx.accounts.token_program;
 
-    // Prepare for wrapped tBTC transfer.
+    // Prepare for wrapped tBTC transfer. No gateway's minted amount is reduced, since wrapped tBTC
+    // is not sent to a gateway. Only the custodian's minted amount is.
     super::burn_and_prepare_transfer(
         super::PrepareTransfer {
             custodian: &mut ctx.accounts.custodian,
@@ -130,16 +153,27 @@ pub fn send_tbtc_wrapped(ctx: Context<SendTbtcWrapped>, args: SendTbtcWrappedArg
             sender,
             wrapped_tbtc_token,
             token_bridge_transfer_authority,
+            tbtc_config: &ctx.accounts.tbtc_config,
+            #[cfg(feature = "event-cpi")]
+            tbtc_event_authority: super::tbtc_event_authority(ctx.remaining_accounts)?,
+            tbtc_program: &ctx.accounts.tbtc_program,
             token_program,
         },
         amount,
-        recipient_chain,
-        None, // gateway
-        recipient,
-        Some(arbiter_fee),
-        nonce,
     )?;
 
+    emit_event!(
+        ctx,
+        crate::event::WormholeTbtcSent {
+            amount,
+            recipient_chain,
+            gateway: Default::default(),
+            recipient,
+            arbiter_fee,
+            nonce
+        }
+    );
+
     let custodian = &ctx.accounts.custodian;
 
     // Finally transfer wrapped tBTC to the recipient.
@@ -0,0 +1,22 @@
+use anchor_lang::prelude::*;
+
+/// Format of recipient addresses on a chain tBTC can be sent to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, AnchorSerialize, AnchorDeserialize, InitSpace)]
+pub enum RecipientFormat {
+    /// 20-byte EVM address, left-padded with zeros to 32 bytes.
+    Evm,
+    /// 32-byte address, used by Sui and Aptos.
+    Bytes32,
+}
+
+/// Chain that tBTC can be sent to, along with the format of its recipient addresses.
+#[account]
+#[derive(Debug, InitSpace)]
+pub struct AllowedChain {
+    pub bump: u8,
+    pub recipient_format: RecipientFormat,
+}
+
+impl AllowedChain {
+    pub const SEED_PREFIX: &'static [u8] = b"allowed-chain";
+}
@@ -0,0 +1,13 @@
+use anchor_lang::prelude::*;
+
+/// Program that the gateway is allowed to call after minting tBTC for a receive-and-call transfer.
+#[account]
+#[derive(Debug, InitSpace)]
+pub struct CallTarget {
+    pub bump: u8,
+    pub program: Pubkey,
+}
+
+impl CallTarget {
+    pub const SEED_PREFIX: &'static [u8] = b"call-target";
+}
@@ -1,6 +1,27 @@
 use anchor_lang::prelude::*;
 use wormhole_anchor_sdk::token_bridge;
 
+/// How a received transfer is delivered when it would exceed the minting limit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, AnchorSerialize, AnchorDeserialize, InitSpace)]
+pub enum ReceiveMode {
+    /// Deliver the whole amount as Wormhole tBTC.
+    AllOrNothing,
+    /// Mint tBTC up to the minting limit and deliver the rest as Wormhole tBTC.
+    Split,
+    /// Mint tBTC up to the minting limit and keep the rest in custody, to be claimed as tBTC once
+    /// the minting limit allows it.
+    Queue,
+}
+
+/// Origin chain and address of the canonical tBTC token bridged via the Token Bridge.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, AnchorSerialize, AnchorDeserialize, InitSpace)]
+pub struct CanonicalToken {
+    pub chain: u16,
+    pub address: [u8; 32],
+}
+
+/// NOTE: New fields must be appended, so that custodians created with an older layout can be
+/// migrated with `migrate_custodian`.
 #[account]
 #[derive(Debug, InitSpace)]
 pub struct Custodian {
@@ -15,6 +36,25 @@ pub struct Custodian {
     pub token_bridge_sender_bump: u8,
     pub minting_limit: u64,
     pub minted_amount: u64,
+    pub pending_authority_since: i64,
+    pub authority_change_delay: u32,
+    /// Wrapped tBTC in custody owed to recipients as pending mints.
+    pub pending_amount: u64,
+    pub receive_mode: ReceiveMode,
+    pub paused: bool,
+    pub canonical_token: CanonicalToken,
+    pub pending_canonical_token: Option<CanonicalToken>,
+    pub pending_canonical_token_since: i64,
+    /// Address of the redeemer on the canonical tBTC chain receiving tBTC sent with a payload.
+    pub l1_redeemer: Option<[u8; 32]>,
+    /// Maximum fee paid to whoever relays an inbound transfer, in basis points of its minted amount.
+    pub max_relayer_fee_bps: u16,
+    /// Lower authority change delay, which takes effect once the current delay has elapsed.
+    pub pending_authority_change_delay: Option<u32>,
+    pub pending_authority_change_delay_since: i64,
+    /// Signer allowed to send tBTC with a payload to the L1 redeemer. This is a PDA of the BTC
+    /// Redeemer program, which validates redemption requests before sending them.
+    pub payload_sender: Option<Pubkey>,
 }
 
 impl Custodian {
@@ -5,6 +5,15 @@ use anchor_lang::prelude::*;
 pub struct GatewayInfo {
     pub bump: u8,
     pub address: [u8; 32],
+
+    /// Maximum net amount of tBTC minted for transfers from this chain.
+    pub minting_limit: u64,
+    /// Net amount of tBTC minted (or pending) for transfers from this chain, less the amount sent
+    /// back to it.
+    pub minted_amount: u64,
+
+    /// Whether transfers from and to this chain are blocked.
+    pub disabled: bool,
 }
 
 impl GatewayInfo {
@@ -1,5 +1,17 @@
+mod allowed_chain;
+pub use allowed_chain::*;
+
+mod call_target;
+pub use call_target::*;
+
 mod custodian;
 pub use custodian::*;
 
 mod gateway_info;
 pub use gateway_info::*;
+
+mod pending_mint;
+pub use pending_mint::*;
+
+mod role_member;
+pub use role_member::*;
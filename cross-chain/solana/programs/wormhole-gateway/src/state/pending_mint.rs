@@ -0,0 +1,14 @@
+use anchor_lang::prelude::*;
+
+/// Amount received for a recipient that could not be minted because of the minting limit.
+#[account]
+#[derive(Debug, InitSpace)]
+pub struct PendingMint {
+    pub bump: u8,
+    pub recipient: Pubkey,
+    pub amount: u64,
+}
+
+impl PendingMint {
+    pub const SEED_PREFIX: &'static [u8] = b"pending-mint";
+}
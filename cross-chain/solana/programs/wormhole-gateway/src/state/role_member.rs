@@ -0,0 +1,24 @@
+use anchor_lang::prelude::*;
+
+/// Roles the authority can grant to narrow down who can perform each admin action.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, AnchorSerialize, AnchorDeserialize, InitSpace)]
+pub enum Role {
+    /// Can update the minting limit.
+    LimitManager,
+    /// Can update gateway addresses.
+    GatewayManager,
+    /// Can unpause the gateway.
+    Unpauser,
+}
+
+#[account]
+#[derive(Debug, InitSpace)]
+pub struct RoleMember {
+    pub bump: u8,
+    pub role: Role,
+    pub member: Pubkey,
+}
+
+impl RoleMember {
+    pub const SEED_PREFIX: &'static [u8] = b"role-member";
+}
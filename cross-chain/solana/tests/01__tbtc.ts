@@ -1,5 +1,6 @@
 import * as anchor from "@coral-xyz/anchor";
 import { Program } from "@coral-xyz/anchor";
+import { Metadata } from "@metaplex-foundation/mpl-token-metadata";
 import * as spl from "@solana/spl-token";
 import { assert, expect } from "chai";
 import { Tbtc } from "../target/types/tbtc";
@@ -9,6 +10,7 @@ import {
   expectIxSuccess,
   getOrCreateAta,
   getTokenBalance,
+  getTxEvents,
   sleep,
   transferLamports,
 } from "./helpers/utils";
@@ -32,6 +34,8 @@ describe("tbtc", () => {
   const recipient = anchor.web3.Keypair.generate();
   const txPayer = anchor.web3.Keypair.generate();
 
+  const minterAllowance = BigInt(1500);
+
   it("set up payers", async () => {
     await transferLamports(authority, newAuthority.publicKey, 10000000000);
     await transferLamports(authority, imposter.publicKey, 10000000000);
@@ -52,6 +56,78 @@ describe("tbtc", () => {
     });
   });
 
+  describe("roles", () => {
+    it("cannot grant role without authority", async () => {
+      const cannotGrantIx = await tbtc.grantRoleIx(
+        {
+          authority: imposter.publicKey,
+          member: imposter.publicKey,
+        },
+        "minterAdmin"
+      );
+      await expectIxFail([cannotGrantIx], [imposter], "IsNotAuthority");
+    });
+
+    it("grant roles", async () => {
+      const roles: tbtc.Role[] = [
+        "minterAdmin",
+        "guardianAdmin",
+        "limitManager",
+        "unpauser",
+      ];
+      for (const role of roles) {
+        const grantIx = await tbtc.grantRoleIx(
+          {
+            authority: authority.publicKey,
+            member: authority.publicKey,
+          },
+          role
+        );
+        await expectIxSuccess([grantIx], [authority]);
+
+        const roleMember = await program.account.roleMember.fetch(
+          tbtc.getRoleMemberPDA(role, authority.publicKey)
+        );
+        expect(roleMember.member).to.eql(authority.publicKey);
+        expect(roleMember.role).to.eql({ [role]: {} });
+      }
+    });
+
+    it("revoke role", async () => {
+      const grantIx = await tbtc.grantRoleIx(
+        {
+          authority: authority.publicKey,
+          member: imposter.publicKey,
+        },
+        "unpauser"
+      );
+      await expectIxSuccess([grantIx], [authority]);
+
+      const cannotRevokeIx = await tbtc.revokeRoleIx(
+        {
+          authority: imposter.publicKey,
+          member: imposter.publicKey,
+        },
+        "unpauser"
+      );
+      await expectIxFail([cannotRevokeIx], [imposter], "IsNotAuthority");
+
+      const revokeIx = await tbtc.revokeRoleIx(
+        {
+          authority: authority.publicKey,
+          member: imposter.publicKey,
+        },
+        "unpauser"
+      );
+      await expectIxSuccess([revokeIx], [authority]);
+
+      const mustBeNull = await program.account.roleMember
+        .fetch(tbtc.getRoleMemberPDA("unpauser", imposter.publicKey))
+        .catch((_) => null);
+      assert(mustBeNull === null, "role member found");
+    });
+  });
+
   describe("authority changes", () => {
     it("cannot cancel authority if no pending", async () => {
       const failedCancelIx = await tbtc.cancelAuthorityChangeIx({
@@ -170,15 +246,154 @@ describe("tbtc", () => {
         pendingAuthority: null,
       });
     });
+
+    it("cannot set authority change delay without authority", async () => {
+      const cannotSetIx = await tbtc.setAuthorityChangeDelayIx(
+        {
+          authority: imposter.publicKey,
+        },
+        3600
+      );
+      await expectIxFail([cannotSetIx], [imposter], "IsNotAuthority");
+    });
+
+    it("cannot take authority before delay elapses", async () => {
+      const setDelayIx = await tbtc.setAuthorityChangeDelayIx(
+        {
+          authority: authority.publicKey,
+        },
+        5
+      );
+      await expectIxSuccess([setDelayIx], [authority]);
+      const { authorityChangeDelay } = await tbtc.getConfigData();
+      expect(authorityChangeDelay).to.equal(5);
+
+      const changeIx = await tbtc.changeAuthorityIx({
+        authority: authority.publicKey,
+        newAuthority: newAuthority.publicKey,
+      });
+      await expectIxSuccess([changeIx], [authority]);
+
+      const failedTakeIx = await tbtc.takeAuthorityIx({
+        pendingAuthority: newAuthority.publicKey,
+      });
+      await expectIxFail(
+        [failedTakeIx],
+        [newAuthority],
+        "AuthorityChangeDelayNotElapsed"
+      );
+
+      // Clean up.
+      const cancelIx = await tbtc.cancelAuthorityChangeIx({
+        authority: authority.publicKey,
+      });
+      const txSig = await expectIxSuccess([cancelIx], [authority]);
+      const events = await getTxEvents(program, txSig);
+      expect(events).to.deep.include({
+        name: "AuthorityChangeCancelled",
+        data: {
+          authority: authority.publicKey,
+          pendingAuthority: newAuthority.publicKey,
+        },
+      });
+    });
+
+    it("decrease authority change delay", async () => {
+      // Lowering the delay is only requested at first.
+      const requestIx = await tbtc.setAuthorityChangeDelayIx(
+        {
+          authority: authority.publicKey,
+        },
+        0
+      );
+      await expectIxSuccess([requestIx], [authority]);
+      let configState = await tbtc.getConfigData();
+      expect(configState.authorityChangeDelay).to.equal(5);
+      expect(configState.pendingAuthorityChangeDelay).to.equal(0);
+
+      // The lower delay cannot be set before the current delay elapses.
+      const failedSetIx = await tbtc.setAuthorityChangeDelayIx(
+        {
+          authority: authority.publicKey,
+        },
+        0
+      );
+      await expectIxFail(
+        [failedSetIx],
+        [txPayer, authority],
+        "AuthorityChangeDelayNotElapsed"
+      );
+
+      await sleep(10000);
+
+      const setIx = await tbtc.setAuthorityChangeDelayIx(
+        {
+          authority: authority.publicKey,
+        },
+        0
+      );
+      await expectIxSuccess([setIx], [authority]);
+      configState = await tbtc.getConfigData();
+      expect(configState.authorityChangeDelay).to.equal(0);
+      expect(configState.pendingAuthorityChangeDelay).to.be.null;
+    });
+  });
+
+  describe("migration", () => {
+    it("cannot migrate config (already migrated)", async () => {
+      const cannotMigrateIx = await tbtc.migrateConfigIx({
+        authority: authority.publicKey,
+      });
+      await expectIxFail([cannotMigrateIx], [authority], "AlreadyMigrated");
+    });
+  });
+
+  describe("metadata", () => {
+    it("cannot update metadata without authority", async () => {
+      const cannotUpdateIx = await tbtc.updateMetadataIx(
+        {
+          authority: imposter.publicKey,
+        },
+        { name: "tBTC v2", symbol: "tBTC", uri: "https://imposter.xyz" }
+      );
+      await expectIxFail([cannotUpdateIx], [imposter], "IsNotAuthority");
+    });
+
+    it("update metadata", async () => {
+      const uri = "https://threshold.network/tbtc.json";
+      const updateIx = await tbtc.updateMetadataIx(
+        {
+          authority: authority.publicKey,
+        },
+        { name: "tBTC v2", symbol: "tBTC", uri }
+      );
+      await expectIxSuccess([updateIx], [authority]);
+
+      const metadata = await Metadata.fromAccountAddress(
+        program.provider.connection,
+        tbtc.getTbtcMetadataPDA()
+      );
+      // Metadata strings are padded with null bytes.
+      expect(metadata.data.name.replace(/\0/g, "")).to.equal("tBTC v2");
+      expect(metadata.data.symbol.replace(/\0/g, "")).to.equal("tBTC");
+      expect(metadata.data.uri.replace(/\0/g, "")).to.equal(uri);
+    });
   });
 
   describe("minting", () => {
     it("cannot add minter without authority", async () => {
-      const cannotAddMinterIx = await tbtc.addMinterIx({
-        authority: imposter.publicKey,
-        minter: minter.publicKey,
-      });
-      await expectIxFail([cannotAddMinterIx], [imposter], "IsNotAuthority");
+      const cannotAddMinterIx = await tbtc.addMinterIx(
+        {
+          minterAdmin: imposter.publicKey,
+          minter: minter.publicKey,
+        },
+        minterAllowance
+      );
+      await expectIxFail(
+        [cannotAddMinterIx],
+        [imposter],
+        "AccountNotInitialized"
+      );
     });
 
     it("add minter", async () => {
@@ -187,10 +402,13 @@ describe("tbtc", () => {
         .catch((_) => null);
       assert(mustBeNull === null, "minter info found");
 
-      const addMinterIx = await tbtc.addMinterIx({
-        authority: authority.publicKey,
-        minter: minter.publicKey,
-      });
+      const addMinterIx = await tbtc.addMinterIx(
+        {
+          minterAdmin: authority.publicKey,
+          minter: minter.publicKey,
+        },
+        minterAllowance
+      );
       await expectIxSuccess([addMinterIx], [authority]);
       await tbtc.checkConfig({
         authority: authority.publicKey,
@@ -200,7 +418,10 @@ describe("tbtc", () => {
         paused: false,
         pendingAuthority: null,
       });
-      await tbtc.checkMinterInfo(minter.publicKey);
+      await tbtc.checkMinterInfo(minter.publicKey, {
+        allowance: minterAllowance,
+        minted: BigInt(0),
+      });
     });
 
     it("mint", async () => {
@@ -233,6 +454,183 @@ describe("tbtc", () => {
 
       const recipientAfter = await getTokenBalance(recipientToken);
       expect(recipientAfter).to.equal(amount);
+
+      await tbtc.checkMinterInfo(minter.publicKey, {
+        allowance: minterAllowance,
+        minted: amount,
+      });
+    });
+
+    it("cannot mint more than allowance", async () => {
+      const recipientToken = spl.getAssociatedTokenAddressSync(
+        tbtc.getMintPDA(),
+        recipient.publicKey
+      );
+
+      // Minter has already minted 1000 out of its 1500 allowance.
+      const mintIx = await tbtc.mintIx(
+        {
+          minter: minter.publicKey,
+          recipientToken,
+        },
+        new anchor.BN(501)
+      );
+      await expectIxFail([mintIx], [txPayer, minter], "MintAllowanceExceeded");
+    });
+
+    it("cannot set minter allowance without authority", async () => {
+      const cannotSetIx = await tbtc.setMinterAllowanceIx(
+        {
+          minterAdmin: imposter.publicKey,
+          minter: minter.publicKey,
+        },
+        BigInt(2000)
+      );
+      await expectIxFail([cannotSetIx], [imposter], "AccountNotInitialized");
+    });
+
+    it("set minter allowance", async () => {
+      const newAllowance = BigInt(2000);
+      const setIx = await tbtc.setMinterAllowanceIx(
+        {
+          minterAdmin: authority.publicKey,
+          minter: minter.publicKey,
+        },
+        newAllowance
+      );
+      await expectIxSuccess([setIx], [authority]);
+      await tbtc.checkMinterInfo(minter.publicKey, {
+        allowance: newAllowance,
+        minted: BigInt(1000),
+      });
+
+      // Lower the allowance below what was minted. The minter cannot mint
+      // anymore.
+      const lowerIx = await tbtc.setMinterAllowanceIx(
+        {
+          minterAdmin: authority.publicKey,
+          minter: minter.publicKey,
+        },
+        BigInt(500)
+      );
+      await expectIxSuccess([lowerIx], [authority]);
+
+      const recipientToken = spl.getAssociatedTokenAddressSync(
+        tbtc.getMintPDA(),
+        recipient.publicKey
+      );
+      const mintIx = await tbtc.mintIx(
+        {
+          minter: minter.publicKey,
+          recipientToken,
+        },
+        new anchor.BN(1)
+      );
+      await expectIxFail([mintIx], [txPayer, minter], "MintAllowanceExceeded");
+
+      // Restore the original allowance.
+      const restoreIx = await tbtc.setMinterAllowanceIx(
+        {
+          minterAdmin: authority.publicKey,
+          minter: minter.publicKey,
+        },
+        minterAllowance
+      );
+      await expectIxSuccess([restoreIx], [authority]);
+    });
+
+    it("cannot migrate minter info without minter admin", async () => {
+      const cannotMigrateIx = await tbtc.migrateMinterInfoIx(
+        {
+          minterAdmin: imposter.publicKey,
+          minter: minter.publicKey,
+        },
+        minterAllowance
+      );
+      await expectIxFail(
+        [cannotMigrateIx],
+        [imposter],
+        "AccountNotInitialized"
+      );
+    });
+
+    it("cannot migrate minter info (already migrated)", async () => {
+      const cannotMigrateIx = await tbtc.migrateMinterInfoIx(
+        {
+          minterAdmin: authority.publicKey,
+          minter: minter.publicKey,
+        },
+        minterAllowance
+      );
+      await expectIxFail([cannotMigrateIx], [authority], "AlreadyMigrated");
+    });
+
+    it("cannot migrate mint rate limit without limit manager", async () => {
+      const cannotMigrateIx = await tbtc.migrateMintRateLimitIx({
+        limitManager: imposter.publicKey,
+      });
+      await expectIxFail(
+        [cannotMigrateIx],
+        [imposter],
+        "AccountNotInitialized"
+      );
+    });
+
+    it("cannot migrate mint rate limit (already migrated)", async () => {
+      // The rate limit was created by initialize.
+      const cannotMigrateIx = await tbtc.migrateMintRateLimitIx({
+        limitManager: authority.publicKey,
+      });
+      await expectIxFail([cannotMigrateIx], [authority], "already in use");
+    });
+
+    it("cannot set mint rate limit without authority", async () => {
+      const cannotSetIx = await tbtc.setMintRateLimitIx(
+        {
+          limitManager: imposter.publicKey,
+        },
+        { capacity: BigInt(100), window: 3600 }
+      );
+      await expectIxFail([cannotSetIx], [imposter], "AccountNotInitialized");
+    });
+
+    it("cannot mint more than rate limit", async () => {
+      const setIx = await tbtc.setMintRateLimitIx(
+        {
+          limitManager: authority.publicKey,
+        },
+        { capacity: BigInt(100), window: 3600 }
+      );
+      await expectIxSuccess([setIx], [authority]);
+      await tbtc.checkMintRateLimit({ capacity: BigInt(100), window: 3600 });
+
+      const recipientToken = spl.getAssociatedTokenAddressSync(
+        tbtc.getMintPDA(),
+        recipient.publicKey
+      );
+
+      // Within the minter's allowance, but above the rate limit capacity.
+      const mintIx = await tbtc.mintIx(
+        {
+          minter: minter.publicKey,
+          recipientToken,
+        },
+        new anchor.BN(101)
+      );
+      await expectIxFail([mintIx], [txPayer, minter], "RateLimitExceeded");
+
+      // Lift the rate limit.
+      const liftIx = await tbtc.setMintRateLimitIx(
+        {
+          limitManager: authority.publicKey,
+        },
+        { capacity: BigInt("18446744073709551615"), window: 0 }
+      );
+      await expectIxSuccess([liftIx], [authority]);
+      await tbtc.checkMintRateLimit({
+        capacity: BigInt("18446744073709551615"),
+        window: 0,
+      });
     });
 
     it("cannot mint without minter", async () => {
@@ -278,10 +676,13 @@ describe("tbtc", () => {
         .catch((_) => null);
       assert(mustBeNull === null, "minter info found");
 
-      const addMinterIx = await tbtc.addMinterIx({
-        authority: authority.publicKey,
-        minter: anotherMinter.publicKey,
-      });
+      const addMinterIx = await tbtc.addMinterIx(
+        {
+          minterAdmin: authority.publicKey,
+          minter: anotherMinter.publicKey,
+        },
+        minterAllowance
+      );
       await expectIxSuccess([addMinterIx], [authority]);
       await tbtc.checkConfig({
         authority: authority.publicKey,
@@ -297,7 +698,7 @@ describe("tbtc", () => {
     it("cannot remove minter with wrong key", async () => {
       const minterInfo = tbtc.getMinterInfoPDA(minter.publicKey);
       const cannotRemoveIx = await tbtc.removeMinterIx({
-        authority: authority.publicKey,
+        minterAdmin: authority.publicKey,
         minterInfo,
         minter: anotherMinter.publicKey,
       });
@@ -337,15 +738,15 @@ describe("tbtc", () => {
 
     it("cannot remove minter without authority", async () => {
       const cannotRemoveIx = await tbtc.removeMinterIx({
-        authority: imposter.publicKey,
+        minterAdmin: imposter.publicKey,
         minter: anotherMinter.publicKey,
       });
-      await expectIxFail([cannotRemoveIx], [imposter], "IsNotAuthority");
+      await expectIxFail([cannotRemoveIx], [imposter], "AccountNotInitialized");
     });
 
     it("remove minter", async () => {
       const removeIx = await tbtc.removeMinterIx({
-        authority: authority.publicKey,
+        minterAdmin: authority.publicKey,
         minter: anotherMinter.publicKey,
       });
       await expectIxSuccess([removeIx], [authority]);
@@ -365,7 +766,7 @@ describe("tbtc", () => {
 
     it("cannot remove same minter again", async () => {
       const cannotRemoveIx = await tbtc.removeMinterIx({
-        authority: authority.publicKey,
+        minterAdmin: authority.publicKey,
         minter: anotherMinter.publicKey,
       });
       await expectIxFail(
@@ -377,7 +778,7 @@ describe("tbtc", () => {
 
     it("remove last minter", async () => {
       const removeIx = await tbtc.removeMinterIx({
-        authority: authority.publicKey,
+        minterAdmin: authority.publicKey,
         minter: minter.publicKey,
       });
       await expectIxSuccess([removeIx], [authority]);
@@ -399,10 +800,10 @@ describe("tbtc", () => {
   describe("guardians", () => {
     it("cannot add guardian without authority", async () => {
       const cannotAddIx = await tbtc.addGuardianIx({
-        authority: imposter.publicKey,
+        guardianAdmin: imposter.publicKey,
         guardian: guardian.publicKey,
       });
-      await expectIxFail([cannotAddIx], [imposter], "IsNotAuthority");
+      await expectIxFail([cannotAddIx], [imposter], "AccountNotInitialized");
     });
 
     it("add guardian", async () => {
@@ -412,7 +813,7 @@ describe("tbtc", () => {
       assert(mustBeNull === null, "guardian info found");
 
       const addIx = await tbtc.addGuardianIx({
-        authority: authority.publicKey,
+        guardianAdmin: authority.publicKey,
         guardian: guardian.publicKey,
       });
       await expectIxSuccess([addIx], [authority]);
@@ -452,10 +853,13 @@ describe("tbtc", () => {
     });
 
     it("add minter and mint", async () => {
-      const addMinterIx = await tbtc.addMinterIx({
-        authority: authority.publicKey,
-        minter: minter.publicKey,
-      });
+      const addMinterIx = await tbtc.addMinterIx(
+        {
+          minterAdmin: authority.publicKey,
+          minter: minter.publicKey,
+        },
+        minterAllowance
+      );
       await expectIxSuccess([addMinterIx], [authority]);
       await tbtc.checkConfig({
         authority: authority.publicKey,
@@ -496,11 +900,93 @@ describe("tbtc", () => {
       expect(recipientAfter).to.equal(recipientBefore + amount);
     });
 
+    it("cannot freeze account without guardian", async () => {
+      const recipientToken = spl.getAssociatedTokenAddressSync(
+        tbtc.getMintPDA(),
+        recipient.publicKey
+      );
+
+      const cannotFreezeIx = await tbtc.freezeAccountIx({
+        guardian: imposter.publicKey,
+        tokenAccount: recipientToken,
+      });
+      await expectIxFail(
+        [cannotFreezeIx],
+        [txPayer, imposter],
+        "AccountNotInitialized"
+      );
+    });
+
+    it("freeze account", async () => {
+      const recipientToken = spl.getAssociatedTokenAddressSync(
+        tbtc.getMintPDA(),
+        recipient.publicKey
+      );
+
+      const freezeIx = await tbtc.freezeAccountIx({
+        guardian: guardian.publicKey,
+        tokenAccount: recipientToken,
+      });
+      await expectIxSuccess([freezeIx], [txPayer, guardian]);
+
+      const recipientState = await spl.getAccount(
+        program.provider.connection,
+        recipientToken
+      );
+      expect(recipientState.isFrozen).to.be.true;
+
+      // Frozen balance cannot move.
+      const burnIx = await tbtc.burnIx(
+        {
+          owner: recipient.publicKey,
+          ownerToken: recipientToken,
+        },
+        new anchor.BN(100)
+      );
+      await expectIxFail([burnIx], [txPayer, recipient], "Account is frozen");
+    });
+
+    it("cannot thaw account without authority", async () => {
+      const recipientToken = spl.getAssociatedTokenAddressSync(
+        tbtc.getMintPDA(),
+        recipient.publicKey
+      );
+
+      const cannotThawIx = await tbtc.thawAccountIx({
+        authority: guardian.publicKey,
+        tokenAccount: recipientToken,
+      });
+      await expectIxFail(
+        [cannotThawIx],
+        [txPayer, guardian],
+        "IsNotAuthority"
+      );
+    });
+
+    it("thaw account", async () => {
+      const recipientToken = spl.getAssociatedTokenAddressSync(
+        tbtc.getMintPDA(),
+        recipient.publicKey
+      );
+
+      const thawIx = await tbtc.thawAccountIx({
+        authority: authority.publicKey,
+        tokenAccount: recipientToken,
+      });
+      await expectIxSuccess([thawIx], [authority]);
+
+      const recipientState = await spl.getAccount(
+        program.provider.connection,
+        recipientToken
+      );
+      expect(recipientState.isFrozen).to.be.false;
+    });
+
     it("pause", async () => {
       const pauseIx = await tbtc.pauseIx({
         guardian: guardian.publicKey,
       });
-      await expectIxSuccess([pauseIx], [txPayer, guardian]);
+      const txSig = await expectIxSuccess([pauseIx], [txPayer, guardian]);
       await tbtc.checkConfig({
         authority: authority.publicKey,
         numMinters: 1,
@@ -509,6 +995,12 @@ describe("tbtc", () => {
         paused: true,
         pendingAuthority: null,
       });
+
+      const events = await getTxEvents(program, txSig);
+      expect(events).to.deep.include({
+        name: "Paused",
+        data: { guardian: guardian.publicKey },
+      });
     });
 
     it("cannot mint while paused", async () => {
@@ -527,6 +1019,31 @@ describe("tbtc", () => {
       await expectIxFail([mintIx], [txPayer, minter], "IsPaused");
     });
 
+    it("cannot burn while paused", async () => {
+      const recipientToken = spl.getAssociatedTokenAddressSync(
+        tbtc.getMintPDA(),
+        recipient.publicKey
+      );
+
+      const burnIx = await tbtc.burnIx(
+        {
+          owner: recipient.publicKey,
+          ownerToken: recipientToken,
+        },
+        new anchor.BN(100)
+      );
+      await expectIxFail([burnIx], [txPayer, recipient], "IsPaused");
+
+      const burnFromIx = await tbtc.burnFromIx(
+        {
+          minter: minter.publicKey,
+          ownerToken: recipientToken,
+        },
+        new anchor.BN(100)
+      );
+      await expectIxFail([burnFromIx], [txPayer, minter], "IsPaused");
+    });
+
     it("add another guardian", async () => {
       const mustBeNull = await tbtc
         .checkGuardianInfo(anotherGuardian.publicKey)
@@ -534,7 +1051,7 @@ describe("tbtc", () => {
       assert(mustBeNull === null, "guardian info found");
 
       const addIx = await tbtc.addGuardianIx({
-        authority: authority.publicKey,
+        guardianAdmin: authority.publicKey,
         guardian: anotherGuardian.publicKey,
       });
       await expectIxSuccess([addIx], [authority]);
@@ -562,9 +1079,9 @@ describe("tbtc", () => {
 
     it("unpause", async () => {
       const unpauseIx = await tbtc.unpauseIx({
-        authority: authority.publicKey,
+        unpauser: authority.publicKey,
       });
-      await expectIxSuccess([unpauseIx], [authority]);
+      const txSig = await expectIxSuccess([unpauseIx], [authority]);
       await tbtc.checkConfig({
         authority: authority.publicKey,
         numMinters: 1,
@@ -573,11 +1090,17 @@ describe("tbtc", () => {
         paused: false,
         pendingAuthority: null,
       });
+
+      const events = await getTxEvents(program, txSig);
+      expect(events).to.deep.include({
+        name: "Unpaused",
+        data: { unpauser: authority.publicKey },
+      });
     });
 
     it("cannot unpause again", async () => {
       const cannotUnpauseIx = await tbtc.unpauseIx({
-        authority: authority.publicKey,
+        unpauser: authority.publicKey,
       });
       await expectIxFail(
         [cannotUnpauseIx],
@@ -650,16 +1173,16 @@ describe("tbtc", () => {
 
     it("cannot remove guardian without authority", async () => {
       const cannotRemoveIx = await tbtc.removeGuardianIx({
-        authority: imposter.publicKey,
+        guardianAdmin: imposter.publicKey,
         guardian: anotherGuardian.publicKey,
       });
-      await expectIxFail([cannotRemoveIx], [imposter], "IsNotAuthority");
+      await expectIxFail([cannotRemoveIx], [imposter], "AccountNotInitialized");
     });
 
     it("cannot remove guardian with mismatched info", async () => {
       const guardianInfo = tbtc.getGuardianInfoPDA(anotherGuardian.publicKey);
       const cannotRemoveIx = await tbtc.removeGuardianIx({
-        authority: authority.publicKey,
+        guardianAdmin: authority.publicKey,
         guardianInfo,
         guardian: guardian.publicKey,
       });
@@ -668,7 +1191,7 @@ describe("tbtc", () => {
 
     it("remove guardian", async () => {
       const removeIx = await tbtc.removeGuardianIx({
-        authority: authority.publicKey,
+        guardianAdmin: authority.publicKey,
         guardian: anotherGuardian.publicKey,
       });
       await expectIxSuccess([removeIx], [authority]);
@@ -688,7 +1211,7 @@ describe("tbtc", () => {
 
     it("unpause", async () => {
       const unpauseIx = await tbtc.unpauseIx({
-        authority: authority.publicKey,
+        unpauser: authority.publicKey,
       });
       await expectIxSuccess([unpauseIx], [authority]);
       await tbtc.checkConfig({
@@ -727,7 +1250,7 @@ describe("tbtc", () => {
       });
 
       const removeIx = await tbtc.removeGuardianIx({
-        authority: authority.publicKey,
+        guardianAdmin: authority.publicKey,
         guardian: guardian.publicKey,
       });
       await expectIxSuccess([removeIx], [authority]);
@@ -763,7 +1286,7 @@ describe("tbtc", () => {
 
     it("unpause without any guardians then mint", async () => {
       const unpauseIx = await tbtc.unpauseIx({
-        authority: authority.publicKey,
+        unpauser: authority.publicKey,
       });
       await expectIxSuccess([unpauseIx], [authority]);
       await tbtc.checkConfig({
@@ -806,7 +1329,259 @@ describe("tbtc", () => {
 
     it("remove minter", async () => {
       const removeIx = await tbtc.removeMinterIx({
+        minterAdmin: authority.publicKey,
+        minter: minter.publicKey,
+      });
+      await expectIxSuccess([removeIx], [authority]);
+      await tbtc.checkConfig({
+        authority: authority.publicKey,
+        numMinters: 0,
+        numGuardians: 0,
+        supply: BigInt(2000),
+        paused: false,
+        pendingAuthority: null,
+      });
+    });
+  });
+
+  describe("recover tokens", () => {
+    const strayMintKeys = anchor.web3.Keypair.generate();
+    const strayTokenKeys = anchor.web3.Keypair.generate();
+
+    it("set up stray tokens", async () => {
+      const connection = program.provider.connection;
+      await spl.createMint(
+        connection,
+        authority,
+        authority.publicKey,
+        null,
+        8,
+        strayMintKeys
+      );
+
+      // Token account owned by the config PDA.
+      const strayToken = await spl.createAccount(
+        connection,
+        authority,
+        strayMintKeys.publicKey,
+        tbtc.getConfigPDA(),
+        strayTokenKeys
+      );
+      await spl.mintTo(
+        connection,
+        authority,
+        strayMintKeys.publicKey,
+        strayToken,
+        authority,
+        1000
+      );
+    });
+
+    it("cannot recover tokens without authority", async () => {
+      const recipientToken = await getOrCreateAta(
+        authority,
+        strayMintKeys.publicKey,
+        imposter.publicKey
+      );
+
+      const cannotRecoverIx = await tbtc.recoverTokensIx(
+        {
+          authority: imposter.publicKey,
+          sourceToken: strayTokenKeys.publicKey,
+          recipientToken,
+        },
+        BigInt(1000)
+      );
+      await expectIxFail([cannotRecoverIx], [imposter], "IsNotAuthority");
+    });
+
+    it("recover tokens", async () => {
+      const recipientToken = await getOrCreateAta(
+        authority,
+        strayMintKeys.publicKey,
+        authority.publicKey
+      );
+
+      const recoverIx = await tbtc.recoverTokensIx(
+        {
+          authority: authority.publicKey,
+          sourceToken: strayTokenKeys.publicKey,
+          recipientToken,
+        },
+        BigInt(1000)
+      );
+      await expectIxSuccess([recoverIx], [authority]);
+
+      const strayAfter = await getTokenBalance(strayTokenKeys.publicKey);
+      expect(strayAfter).to.equal(BigInt(0));
+
+      const recipientAfter = await getTokenBalance(recipientToken);
+      expect(recipientAfter).to.equal(BigInt(1000));
+    });
+  });
+
+  describe("burning", () => {
+    it("add minter and mint", async () => {
+      const addIx = await tbtc.addMinterIx(
+        {
+          minterAdmin: authority.publicKey,
+          minter: minter.publicKey,
+        },
+        minterAllowance
+      );
+      await expectIxSuccess([addIx], [authority]);
+
+      const recipientToken = spl.getAssociatedTokenAddressSync(
+        tbtc.getMintPDA(),
+        recipient.publicKey
+      );
+      const mintIx = await tbtc.mintIx(
+        {
+          minter: minter.publicKey,
+          recipientToken,
+        },
+        new anchor.BN(300)
+      );
+      await expectIxSuccess([mintIx], [txPayer, minter]);
+      await tbtc.checkConfig({
+        authority: authority.publicKey,
+        numMinters: 1,
+        numGuardians: 0,
+        supply: BigInt(2300),
+        paused: false,
+        pendingAuthority: null,
+      });
+    });
+
+    it("cannot burn from another owner's account", async () => {
+      const recipientToken = spl.getAssociatedTokenAddressSync(
+        tbtc.getMintPDA(),
+        recipient.publicKey
+      );
+
+      const burnIx = await tbtc.burnIx(
+        {
+          owner: imposter.publicKey,
+          ownerToken: recipientToken,
+        },
+        new anchor.BN(100)
+      );
+      await expectIxFail([burnIx], [txPayer, imposter], "ConstraintTokenOwner");
+    });
+
+    it("burn", async () => {
+      const amount = BigInt(100);
+
+      const recipientToken = spl.getAssociatedTokenAddressSync(
+        tbtc.getMintPDA(),
+        recipient.publicKey
+      );
+      const recipientBefore = await getTokenBalance(recipientToken);
+
+      const burnIx = await tbtc.burnIx(
+        {
+          owner: recipient.publicKey,
+          ownerToken: recipientToken,
+        },
+        new anchor.BN(amount.toString())
+      );
+      await expectIxSuccess([burnIx], [txPayer, recipient]);
+      await tbtc.checkConfig({
+        authority: authority.publicKey,
+        numMinters: 1,
+        numGuardians: 0,
+        supply: BigInt(2200),
+        paused: false,
+        pendingAuthority: null,
+      });
+
+      const recipientAfter = await getTokenBalance(recipientToken);
+      expect(recipientAfter).to.equal(recipientBefore - amount);
+
+      const { totalBurned } = await tbtc.getConfigData();
+      expect(BigInt(totalBurned.toString())).to.equal(amount);
+    });
+
+    it("cannot burn from without minter", async () => {
+      const recipientToken = spl.getAssociatedTokenAddressSync(
+        tbtc.getMintPDA(),
+        recipient.publicKey
+      );
+
+      const burnFromIx = await tbtc.burnFromIx(
+        {
+          minter: imposter.publicKey,
+          ownerToken: recipientToken,
+        },
+        new anchor.BN(100)
+      );
+      await expectIxFail(
+        [burnFromIx],
+        [txPayer, imposter],
+        "AccountNotInitialized"
+      );
+    });
+
+    it("burn from", async () => {
+      const amount = BigInt(200);
+
+      const recipientToken = spl.getAssociatedTokenAddressSync(
+        tbtc.getMintPDA(),
+        recipient.publicKey
+      );
+      const recipientBefore = await getTokenBalance(recipientToken);
+
+      // Recipient approves the minter to burn on its behalf.
+      const approveIx = spl.createApproveInstruction(
+        recipientToken,
+        minter.publicKey,
+        recipient.publicKey,
+        amount
+      );
+      const burnFromIx = await tbtc.burnFromIx(
+        {
+          minter: minter.publicKey,
+          ownerToken: recipientToken,
+        },
+        new anchor.BN(amount.toString())
+      );
+      await expectIxSuccess(
+        [approveIx, burnFromIx],
+        [txPayer, recipient, minter]
+      );
+      await tbtc.checkConfig({
         authority: authority.publicKey,
+        numMinters: 1,
+        numGuardians: 0,
+        supply: BigInt(2000),
+        paused: false,
+        pendingAuthority: null,
+      });
+
+      const recipientAfter = await getTokenBalance(recipientToken);
+      expect(recipientAfter).to.equal(recipientBefore - amount);
+
+      const { totalBurned } = await tbtc.getConfigData();
+      expect(BigInt(totalBurned.toString())).to.equal(BigInt(300));
+
+      // The delegation is used up, so the minter cannot burn again.
+      const cannotBurnFromIx = await tbtc.burnFromIx(
+        {
+          minter: minter.publicKey,
+          ownerToken: recipientToken,
+        },
+        new anchor.BN(1)
+      );
+      await expectIxFail(
+        [cannotBurnFromIx],
+        [txPayer, minter],
+        "owner does not match"
+      );
+    });
+
+    it("remove minter", async () => {
+      const removeIx = await tbtc.removeMinterIx({
+        minterAdmin: authority.publicKey,
         minter: minter.publicKey,
       });
       await expectIxSuccess([removeIx], [authority]);
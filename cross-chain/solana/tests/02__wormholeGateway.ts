@@ -1,20 +1,30 @@
 import { MockEthereumTokenBridge } from "@certusone/wormhole-sdk/lib/cjs/mock";
 import * as anchor from "@coral-xyz/anchor";
 import { Program } from "@coral-xyz/anchor";
-import { getAccount, getAssociatedTokenAddressSync } from "@solana/spl-token";
+import {
+  createTransferInstruction,
+  getAccount,
+  getAssociatedTokenAddressSync,
+} from "@solana/spl-token";
 import { PublicKey } from "@solana/web3.js";
 import { expect } from "chai";
 import { WormholeGateway } from "../target/types/wormhole_gateway";
 import {
+  BTC_REDEEMER_PROGRAM_ID,
   ETHEREUM_TOKEN_BRIDGE_ADDRESS,
+  TBTC_PROGRAM_ID,
   WORMHOLE_GATEWAY_PROGRAM_ID,
   WRAPPED_TBTC_MINT,
   ethereumGatewaySendTbtc,
+  ethereumGatewaySendTbtcWithPayload,
   expectIxFail,
   expectIxSuccess,
   generatePayer,
   getOrCreateAta,
+  getTokenBalance,
+  getTxEvents,
   preloadWrappedTbtc,
+  sleep,
   transferLamports,
 } from "./helpers";
 import * as tbtc from "./helpers/tbtc";
@@ -31,7 +41,10 @@ async function setup(
   const tokenBridgeSender = wormholeGateway.getTokenBridgeSenderPDA();
 
   await program.methods
-    .initialize(new anchor.BN(mintingLimit.toString()))
+    .initialize({
+      mintingLimit: new anchor.BN(mintingLimit.toString()),
+      canonicalToken: wormholeGateway.ETHEREUM_TBTC_TOKEN,
+    })
     .accounts({
       authority: authority.publicKey,
       custodian,
@@ -98,6 +111,8 @@ describe("wormhole-gateway", () => {
         mintingLimit,
         pendingAuthority: null,
       });
+      const { canonicalToken } = await wormholeGateway.getCustodianData();
+      expect(canonicalToken).to.eql(wormholeGateway.ETHEREUM_TBTC_TOKEN);
       await tbtc.checkConfig({
         authority: authority.publicKey,
         numMinters: 0,
@@ -117,6 +132,53 @@ describe("wormhole-gateway", () => {
       // Give the impostor some lamports.
       await transferLamports(authority, imposter.publicKey, 100000000000);
     });
+
+    it("cannot grant role (not authority)", async () => {
+      const failingIx = await wormholeGateway.grantRoleIx(
+        {
+          authority: imposter.publicKey,
+          member: imposter.publicKey,
+        },
+        "limitManager"
+      );
+      await expectIxFail([failingIx], [imposter], "IsNotAuthority");
+    });
+
+    it("grant roles", async () => {
+      const roles: wormholeGateway.Role[] = [
+        "limitManager",
+        "gatewayManager",
+        "unpauser",
+      ];
+      for (const role of roles) {
+        const grantIx = await wormholeGateway.grantRoleIx(
+          {
+            authority: authority.publicKey,
+            member: authority.publicKey,
+          },
+          role
+        );
+        await expectIxSuccess([grantIx], [authority]);
+
+        const roleMember = await program.account.roleMember.fetch(
+          wormholeGateway.getRoleMemberPDA(role, authority.publicKey)
+        );
+        expect(roleMember.member).to.eql(authority.publicKey);
+        expect(roleMember.role).to.eql({ [role]: {} });
+      }
+    });
+  });
+
+  describe("migration", () => {
+    it("cannot migrate custodian (already migrated)", async () => {
+      const failingIx = await wormholeGateway.migrateCustodianIx(
+        {
+          authority: authority.publicKey,
+        },
+        wormholeGateway.ETHEREUM_TBTC_TOKEN
+      );
+      await expectIxFail([failingIx], [authority], "AlreadyMigrated");
+    });
   });
 
   describe("authority changes", () => {
@@ -226,6 +288,89 @@ describe("wormhole-gateway", () => {
         pendingAuthority: null,
       });
     });
+
+    it("cannot set authority change delay without authority", async () => {
+      const cannotSetIx = await wormholeGateway.setAuthorityChangeDelayIx(
+        {
+          authority: imposter.publicKey,
+        },
+        3600
+      );
+      await expectIxFail([cannotSetIx], [imposter], "IsNotAuthority");
+    });
+
+    it("cannot take authority before delay elapses", async () => {
+      const setDelayIx = await wormholeGateway.setAuthorityChangeDelayIx(
+        {
+          authority: authority.publicKey,
+        },
+        5
+      );
+      await expectIxSuccess([setDelayIx], [authority]);
+      const { authorityChangeDelay } = await wormholeGateway.getCustodianData();
+      expect(authorityChangeDelay).to.equal(5);
+
+      const changeIx = await wormholeGateway.changeAuthorityIx({
+        authority: authority.publicKey,
+        newAuthority: newAuthority.publicKey,
+      });
+      await expectIxSuccess([changeIx], [authority]);
+
+      const failedTakeIx = await wormholeGateway.takeAuthorityIx({
+        pendingAuthority: newAuthority.publicKey,
+      });
+      await expectIxFail(
+        [failedTakeIx],
+        [newAuthority],
+        "AuthorityChangeDelayNotElapsed"
+      );
+
+      // Clean up.
+      const cancelIx = await wormholeGateway.cancelAuthorityChangeIx({
+        authority: authority.publicKey,
+      });
+      await expectIxSuccess([cancelIx], [authority]);
+    });
+
+    it("decrease authority change delay", async () => {
+      // Lowering the delay is only requested at first.
+      const requestIx = await wormholeGateway.setAuthorityChangeDelayIx(
+        {
+          authority: authority.publicKey,
+        },
+        0
+      );
+      await expectIxSuccess([requestIx], [authority]);
+      let custodianState = await wormholeGateway.getCustodianData();
+      expect(custodianState.authorityChangeDelay).to.equal(5);
+      expect(custodianState.pendingAuthorityChangeDelay).to.equal(0);
+
+      // The lower delay cannot be set before the current delay elapses.
+      const failedSetIx = await wormholeGateway.setAuthorityChangeDelayIx(
+        {
+          authority: authority.publicKey,
+        },
+        0
+      );
+      await expectIxFail(
+        [failedSetIx],
+        [txPayer, authority],
+        "AuthorityChangeDelayNotElapsed"
+      );
+
+      await sleep(10000);
+
+      const setIx = await wormholeGateway.setAuthorityChangeDelayIx(
+        {
+          authority: authority.publicKey,
+        },
+        0
+      );
+      await expectIxSuccess([setIx], [authority]);
+      custodianState = await wormholeGateway.getCustodianData();
+      expect(custodianState.authorityChangeDelay).to.equal(0);
+      expect(custodianState.pendingAuthorityChangeDelay).to.be.null;
+    });
   });
 
   describe("minting limit", () => {
@@ -234,7 +379,7 @@ describe("wormhole-gateway", () => {
       const newLimit = BigInt(20000);
       const ix = await wormholeGateway.updateMintingLimitIx(
         {
-          authority: authority.publicKey,
+          limitManager: authority.publicKey,
         },
         newLimit
       );
@@ -251,11 +396,11 @@ describe("wormhole-gateway", () => {
       const newLimit = BigInt(69000);
       const failingIx = await wormholeGateway.updateMintingLimitIx(
         {
-          authority: imposter.publicKey,
+          limitManager: imposter.publicKey,
         },
         newLimit
       );
-      await expectIxFail([failingIx], [imposter], "IsNotAuthority");
+      await expectIxFail([failingIx], [imposter], "AccountNotInitialized");
     });
   });
 
@@ -275,7 +420,7 @@ describe("wormhole-gateway", () => {
       const firstAddress = Array.from(Buffer.alloc(32, "deadbeef", "hex"));
       const firstIx = await wormholeGateway.updateGatewayAddress(
         {
-          authority: authority.publicKey,
+          gatewayManager: authority.publicKey,
         },
         { chain, address: firstAddress }
       );
@@ -288,7 +433,7 @@ describe("wormhole-gateway", () => {
       const goodAddress = Array.from(ethereumTokenBridge.address);
       const secondIx = await wormholeGateway.updateGatewayAddress(
         {
-          authority: authority.publicKey,
+          gatewayManager: authority.publicKey,
         },
         { chain, address: goodAddress }
       );
@@ -301,12 +446,215 @@ describe("wormhole-gateway", () => {
       const goodAddress = Array.from(ethereumTokenBridge.address);
       const failingIx = await wormholeGateway.updateGatewayAddress(
         {
-          authority: imposter.publicKey,
+          gatewayManager: imposter.publicKey,
+        },
+        { chain, address: goodAddress }
+      );
+      await expectIxFail([failingIx], [imposter], "AccountNotInitialized");
+    });
+
+    it("cannot update gateway minting limit (not limit manager)", async () => {
+      const failingIx = await wormholeGateway.updateGatewayMintingLimitIx(
+        {
+          limitManager: imposter.publicKey,
+        },
+        { chain, mintingLimit: BigInt(69) }
+      );
+      await expectIxFail([failingIx], [imposter], "AccountNotInitialized");
+    });
+
+    it("update gateway minting limit", async () => {
+      // New gateways cannot mint until their minting limit is raised.
+      const gatewayInfoBefore = await wormholeGateway.getGatewayInfo(chain);
+      expect(gatewayInfoBefore.mintingLimit.toString()).to.equal("0");
+
+      const mintingLimit = BigInt("18446744073709551615"); // Max u64
+      const ix = await wormholeGateway.updateGatewayMintingLimitIx(
+        {
+          limitManager: authority.publicKey,
+        },
+        { chain, mintingLimit }
+      );
+      await expectIxSuccess([ix], [authority]);
+
+      // Updating the gateway address keeps the minting limit.
+      const goodAddress = Array.from(ethereumTokenBridge.address);
+      const updateAddressIx = await wormholeGateway.updateGatewayAddress(
+        {
+          gatewayManager: authority.publicKey,
         },
         { chain, address: goodAddress }
       );
+      await expectIxSuccess([updateAddressIx], [authority]);
+
+      const gatewayInfoAfter = await wormholeGateway.getGatewayInfo(chain);
+      expect(gatewayInfoAfter.mintingLimit.toString()).to.equal(
+        mintingLimit.toString()
+      );
+      expect(gatewayInfoAfter.mintedAmount.toString()).to.equal("0");
+    });
+
+    it("cannot migrate gateway info (not authority)", async () => {
+      const failingIx = await wormholeGateway.migrateGatewayInfoIx(
+        {
+          authority: imposter.publicKey,
+        },
+        { chain, mintingLimit: BigInt(69) }
+      );
       await expectIxFail([failingIx], [imposter], "IsNotAuthority");
     });
+
+    it("cannot migrate gateway info (already migrated)", async () => {
+      const failingIx = await wormholeGateway.migrateGatewayInfoIx(
+        {
+          authority: authority.publicKey,
+        },
+        { chain, mintingLimit: BigInt(69) }
+      );
+      await expectIxFail([failingIx], [authority], "AlreadyMigrated");
+    });
+
+    it("cannot remove gateway address (not gateway manager)", async () => {
+      const failingIx = await wormholeGateway.removeGatewayAddressIx(
+        {
+          gatewayManager: imposter.publicKey,
+        },
+        { chain }
+      );
+      await expectIxFail([failingIx], [imposter], "AccountNotInitialized");
+    });
+
+    it("remove gateway address", async () => {
+      // Add a gateway for another chain, which is removed again.
+      const otherChain = 3;
+      const address = Array.from(Buffer.alloc(32, "deadbeef", "hex"));
+      const updateIx = await wormholeGateway.updateGatewayAddress(
+        {
+          gatewayManager: authority.publicKey,
+        },
+        { chain: otherChain, address }
+      );
+      await expectIxSuccess([updateIx], [authority]);
+      await wormholeGateway.checkGateway(otherChain, address);
+
+      const ix = await wormholeGateway.removeGatewayAddressIx(
+        {
+          gatewayManager: authority.publicKey,
+        },
+        { chain: otherChain }
+      );
+      await expectIxSuccess([ix], [authority]);
+
+      const gatewayInfo = await connection.getAccountInfo(
+        wormholeGateway.getGatewayInfoPDA(otherChain)
+      );
+      expect(gatewayInfo).is.null;
+    });
+
+    it("cannot disable gateway (not gateway manager)", async () => {
+      const failingIx = await wormholeGateway.updateGatewayDisabledIx(
+        {
+          gatewayManager: imposter.publicKey,
+        },
+        { chain, disabled: true }
+      );
+      await expectIxFail([failingIx], [imposter], "AccountNotInitialized");
+    });
+
+    it("disable and enable gateway", async () => {
+      const disableIx = await wormholeGateway.updateGatewayDisabledIx(
+        {
+          gatewayManager: authority.publicKey,
+        },
+        { chain, disabled: true }
+      );
+      await expectIxSuccess([disableIx], [authority]);
+
+      const gatewayInfoDisabled = await wormholeGateway.getGatewayInfo(chain);
+      expect(gatewayInfoDisabled.disabled).to.be.true;
+
+      const enableIx = await wormholeGateway.updateGatewayDisabledIx(
+        {
+          gatewayManager: authority.publicKey,
+        },
+        { chain, disabled: false }
+      );
+      await expectIxSuccess([enableIx], [authority]);
+
+      const gatewayInfoEnabled = await wormholeGateway.getGatewayInfo(chain);
+      expect(gatewayInfoEnabled.disabled).to.be.false;
+    });
+  });
+
+  describe("allowed chains", () => {
+    it("cannot update allowed chain (not gateway manager)", async () => {
+      const failingIx = await wormholeGateway.updateAllowedChainIx(
+        {
+          gatewayManager: imposter.publicKey,
+        },
+        { chain: 2, recipientFormat: "evm" }
+      );
+      await expectIxFail([failingIx], [imposter], "AccountNotInitialized");
+    });
+
+    it("update allowed chains", async () => {
+      // Ethereum takes EVM addresses. Chain 69 stands in for a chain with
+      // 32-byte addresses, like Sui or Aptos.
+      const evmIx = await wormholeGateway.updateAllowedChainIx(
+        {
+          gatewayManager: authority.publicKey,
+        },
+        { chain: 2, recipientFormat: "evm" }
+      );
+      const bytes32Ix = await wormholeGateway.updateAllowedChainIx(
+        {
+          gatewayManager: authority.publicKey,
+        },
+        { chain: 69, recipientFormat: "bytes32" }
+      );
+      await expectIxSuccess([evmIx, bytes32Ix], [authority]);
+
+      const [evmChain, bytes32Chain] = await Promise.all([
+        wormholeGateway.getAllowedChain(2),
+        wormholeGateway.getAllowedChain(69),
+      ]);
+      expect(evmChain.recipientFormat).to.eql({ evm: {} });
+      expect(bytes32Chain.recipientFormat).to.eql({ bytes32: {} });
+    });
+
+    it("cannot remove allowed chain (not gateway manager)", async () => {
+      const failingIx = await wormholeGateway.removeAllowedChainIx(
+        {
+          gatewayManager: imposter.publicKey,
+        },
+        { chain: 2 }
+      );
+      await expectIxFail([failingIx], [imposter], "AccountNotInitialized");
+    });
+
+    it("remove allowed chain", async () => {
+      const otherChain = 3;
+      const updateIx = await wormholeGateway.updateAllowedChainIx(
+        {
+          gatewayManager: authority.publicKey,
+        },
+        { chain: otherChain, recipientFormat: "bytes32" }
+      );
+      await expectIxSuccess([updateIx], [authority]);
+
+      const ix = await wormholeGateway.removeAllowedChainIx(
+        {
+          gatewayManager: authority.publicKey,
+        },
+        { chain: otherChain }
+      );
+      await expectIxSuccess([ix], [authority]);
+
+      const allowedChain = await connection.getAccountInfo(
+        wormholeGateway.getAllowedChainPDA(otherChain)
+      );
+      expect(allowedChain).is.null;
+    });
   });
 
   describe("deposit wrapped tbtc", () => {
@@ -374,10 +722,13 @@ describe("wormhole-gateway", () => {
       );
 
       // Add custodian as minter.
-      const addMinterIx = await tbtc.addMinterIx({
-        authority: authority.publicKey,
-        minter: custodian,
-      });
+      const addMinterIx = await tbtc.addMinterIx(
+        {
+          minterAdmin: authority.publicKey,
+          minter: custodian,
+        },
+        BigInt("18446744073709551615") // Max u64
+      );
       await expectIxSuccess([addMinterIx], [authority]);
       await tbtc.checkConfig({
         authority: authority.publicKey,
@@ -492,7 +843,7 @@ describe("wormhole-gateway", () => {
       const newLimit = BigInt(70000);
       const updateLimitIx = await wormholeGateway.updateMintingLimitIx(
         {
-          authority: authority.publicKey,
+          limitManager: authority.publicKey,
         },
         newLimit
       );
@@ -510,6 +861,109 @@ describe("wormhole-gateway", () => {
     });
   });
 
+  describe("recover tokens", () => {
+    it("cannot recover tokens (not authority)", async () => {
+      const recipientWrappedToken = await getOrCreateAta(
+        authority,
+        WRAPPED_TBTC_MINT,
+        imposter.publicKey
+      );
+
+      const failingIx = await wormholeGateway.recoverTokensIx(
+        {
+          authority: imposter.publicKey,
+          sourceToken: gatewayWrappedTbtcToken,
+          recipientToken: recipientWrappedToken,
+        },
+        BigInt(1)
+      );
+      await expectIxFail([failingIx], [imposter], "IsNotAuthority");
+    });
+
+    it("cannot recover wrapped tbtc backing minted tbtc", async () => {
+      const recipientWrappedToken = await getOrCreateAta(
+        authority,
+        WRAPPED_TBTC_MINT,
+        authority.publicKey
+      );
+
+      // Custody only holds what has been minted.
+      const custodyBalance = await getTokenBalance(gatewayWrappedTbtcToken);
+      const mintedAmount = await wormholeGateway.getMintedAmount();
+      expect(custodyBalance).to.equal(mintedAmount);
+
+      const failingIx = await wormholeGateway.recoverTokensIx(
+        {
+          authority: authority.publicKey,
+          sourceToken: gatewayWrappedTbtcToken,
+          recipientToken: recipientWrappedToken,
+        },
+        BigInt(1)
+      );
+      await expectIxFail(
+        [failingIx],
+        [authority],
+        "RecoverAmountExceedsExcess"
+      );
+    });
+
+    it("recover excess wrapped tbtc", async () => {
+      // Set up new wallet
+      const payer = await generatePayer(authority);
+
+      const payerWrappedToken = await preloadWrappedTbtc(
+        payer,
+        ethereumTokenBridge,
+        BigInt("100000000000"),
+        payer.publicKey
+      );
+
+      // Send wrapped tBTC straight to custody by mistake.
+      const strayAmount = BigInt(1000);
+      const transferIx = createTransferInstruction(
+        payerWrappedToken,
+        gatewayWrappedTbtcToken,
+        payer.publicKey,
+        strayAmount
+      );
+      await expectIxSuccess([transferIx], [payer]);
+
+      const payerBefore = await getTokenBalance(payerWrappedToken);
+
+      // Cannot recover more than the excess.
+      const failingIx = await wormholeGateway.recoverTokensIx(
+        {
+          authority: authority.publicKey,
+          sourceToken: gatewayWrappedTbtcToken,
+          recipientToken: payerWrappedToken,
+        },
+        strayAmount + BigInt(1)
+      );
+      await expectIxFail(
+        [failingIx],
+        [authority],
+        "RecoverAmountExceedsExcess"
+      );
+
+      const recoverIx = await wormholeGateway.recoverTokensIx(
+        {
+          authority: authority.publicKey,
+          sourceToken: gatewayWrappedTbtcToken,
+          recipientToken: payerWrappedToken,
+        },
+        strayAmount
+      );
+      await expectIxSuccess([recoverIx], [authority]);
+
+      const payerAfter = await getTokenBalance(payerWrappedToken);
+      expect(payerAfter).to.equal(payerBefore + strayAmount);
+
+      const custodyBalance = await getTokenBalance(gatewayWrappedTbtcToken);
+      const mintedAmount = await wormholeGateway.getMintedAmount();
+      expect(custodyBalance).to.equal(mintedAmount);
+    });
+  });
+
   describe("receive tbtc", () => {
     let replayVaa;
 
@@ -598,38 +1052,23 @@ describe("wormhole-gateway", () => {
       await expectIxFail([ix], [payer], "TransferAlreadyRedeemed");
     });
 
-    it("receive wrapped tbtc (ata doesn't exist)", async () => {
+    it("receive tbtc (recipient ata doesn't exist)", async () => {
       // Set up new wallet
       const payer = await generatePayer(authority);
 
-      // Use common token account.
-      const recipient = commonTokenOwner.publicKey;
+      // Use a new recipient without a token account.
+      const recipient = anchor.web3.Keypair.generate().publicKey;
       const recipientToken = getAssociatedTokenAddressSync(
         tbtc.getMintPDA(),
         recipient
       );
-      const recipientWrappedToken = getAssociatedTokenAddressSync(
-        WRAPPED_TBTC_MINT,
-        recipient
-      );
-
-      // Get minted amount before.
-      const mintedAmountBefore = await wormholeGateway.getMintedAmount();
-
-      // Verify that the wrapped token account doesn't exist yet.
-      try {
-        await getAccount(connection, recipientWrappedToken);
-      } catch (e: any) {
-        expect(e.toString()).to.equal("TokenAccountNotFoundError");
-      }
 
       // Get foreign gateway.
       const fromGateway = await wormholeGateway
         .getGatewayInfo(2)
         .then((info) => info.address);
 
-      // Create transfer VAA.
-      const sentAmount = BigInt(5000);
+      const sentAmount = BigInt(500);
       const signedVaa = await ethereumGatewaySendTbtc(
         payer,
         ethereumTokenBridge,
@@ -639,28 +1078,88 @@ describe("wormhole-gateway", () => {
         recipient
       );
 
-      // Set the mint limit to a value smaller than sentAmount.
-      const newLimit = sentAmount - BigInt(69);
-      const updateLimitIx = await wormholeGateway.updateMintingLimitIx(
+      const ix = await wormholeGateway.receiveTbtcIx(
         {
-          authority: authority.publicKey,
+          payer: payer.publicKey,
+          recipient,
         },
-        newLimit
+        signedVaa
       );
-      await expectIxSuccess([updateLimitIx], [authority]);
-      await wormholeGateway.checkCustodian({
-        authority: authority.publicKey,
-        mintingLimit: newLimit,
-        pendingAuthority: null,
-      });
+      await expectIxSuccess([ix], [payer]);
 
-      // Balance check before receiving wrapped tbtc. We can't
-      // check the balance of the recipient's wrapped tbtc yet,
-      // since the contract will create the ATA.
-      const [tbtcBefore, gatewayBefore] = await Promise.all([
-        getAccount(connection, recipientToken),
-        getAccount(connection, gatewayWrappedTbtcToken),
-      ]);
+      // The gateway created the recipient's token account.
+      const tbtcAfter = await getAccount(connection, recipientToken);
+      expect(tbtcAfter.owner).to.eql(recipient);
+      expect(tbtcAfter.amount).to.equal(sentAmount);
+    });
+
+    it("cannot receive tbtc (recipient token not ata)", async () => {
+      // Set up new wallet
+      const payer = await generatePayer(authority);
+
+      // Use common token account, but pass another token account.
+      const recipient = commonTokenOwner.publicKey;
+      const recipientToken = await getOrCreateAta(
+        payer,
+        tbtc.getMintPDA(),
+        payer.publicKey
+      );
+
+      // Get foreign gateway.
+      const fromGateway = await wormholeGateway
+        .getGatewayInfo(2)
+        .then((info) => info.address);
+
+      const signedVaa = await ethereumGatewaySendTbtc(
+        payer,
+        ethereumTokenBridge,
+        BigInt(500),
+        fromGateway,
+        WORMHOLE_GATEWAY_PROGRAM_ID,
+        recipient
+      );
+
+      const failingIx = await wormholeGateway.receiveTbtcIx(
+        {
+          payer: payer.publicKey,
+          recipientToken,
+          recipient,
+        },
+        signedVaa
+      );
+      await expectIxFail([failingIx], [payer], "ConstraintAddress");
+    });
+
+    it("receive tbtc (versioned payload)", async () => {
+      // Set up new wallet
+      const payer = await generatePayer(authority);
+
+      // Use common token account.
+      const recipient = commonTokenOwner.publicKey;
+      const recipientToken = getAssociatedTokenAddressSync(
+        tbtc.getMintPDA(),
+        recipient
+      );
+
+      // Get foreign gateway.
+      const fromGateway = await wormholeGateway
+        .getGatewayInfo(2)
+        .then((info) => info.address);
+
+      const sentAmount = BigInt(500);
+      const signedVaa = await ethereumGatewaySendTbtcWithPayload(
+        payer,
+        ethereumTokenBridge,
+        sentAmount,
+        fromGateway,
+        WORMHOLE_GATEWAY_PROGRAM_ID,
+        wormholeGateway.encodeTransferPayload({
+          recipient,
+          memo: Buffer.from("hello from ethereum"),
+        })
+      );
+
+      const tbtcBefore = await getTokenBalance(recipientToken);
 
       const ix = await wormholeGateway.receiveTbtcIx(
         {
@@ -672,27 +1171,51 @@ describe("wormhole-gateway", () => {
       );
       await expectIxSuccess([ix], [payer]);
 
-      // Check token accounts after receiving wrapped tbtc. We should
-      // be able to fetch the recipient's wrapped tbtc now.
-      const [tbtcAfter, wrappedTbtcAfter, gatewayAfter] = await Promise.all([
-        getAccount(connection, recipientToken),
-        getAccount(connection, recipientWrappedToken),
-        getAccount(connection, gatewayWrappedTbtcToken),
-      ]);
+      const tbtcAfter = await getTokenBalance(recipientToken);
+      expect(tbtcAfter).to.equal(tbtcBefore + sentAmount);
+    });
 
-      // Check minted amount.
-      const mintedAmountAfter = await wormholeGateway.getMintedAmount();
-      expect(mintedAmountAfter).to.equal(mintedAmountBefore);
+    it("cannot update max relayer fee (not gateway manager)", async () => {
+      const failingIx = await wormholeGateway.updateMaxRelayerFeeIx(
+        {
+          gatewayManager: imposter.publicKey,
+        },
+        100
+      );
+      await expectIxFail([failingIx], [imposter], "AccountNotInitialized");
+    });
 
-      // Check balance change.
-      expect(tbtcAfter.amount).to.equal(tbtcBefore.amount);
-      expect(gatewayAfter.amount).to.equal(gatewayBefore.amount);
-      expect(wrappedTbtcAfter.amount).to.equal(sentAmount);
+    it("cannot update max relayer fee (too high)", async () => {
+      const failingIx = await wormholeGateway.updateMaxRelayerFeeIx(
+        {
+          gatewayManager: authority.publicKey,
+        },
+        10001
+      );
+      await expectIxFail([failingIx], [authority], "MaxRelayerFeeTooHigh");
     });
 
-    it("receive wrapped tbtc (ata exists)", async () => {
-      // Set up new wallet
+    it("update max relayer fee", async () => {
+      const ix = await wormholeGateway.updateMaxRelayerFeeIx(
+        {
+          gatewayManager: authority.publicKey,
+        },
+        100
+      );
+      await expectIxSuccess([ix], [authority]);
+
+      const custodianState = await wormholeGateway.getCustodianData();
+      expect(custodianState.maxRelayerFeeBps).to.equal(100);
+    });
+
+    it("receive tbtc with relayer fee", async () => {
+      // Set up new wallet, which relays the transfer.
       const payer = await generatePayer(authority);
+      const payerToken = await getOrCreateAta(
+        payer,
+        tbtc.getMintPDA(),
+        payer.publicKey
+      );
 
       // Use common token account.
       const recipient = commonTokenOwner.publicKey;
@@ -700,11 +1223,6 @@ describe("wormhole-gateway", () => {
         tbtc.getMintPDA(),
         recipient
       );
-      const recipientWrappedToken = await getOrCreateAta(
-        payer,
-        WRAPPED_TBTC_MINT,
-        recipient
-      );
 
       // Get foreign gateway.
       const fromGateway = await wormholeGateway
@@ -714,73 +1232,47 @@ describe("wormhole-gateway", () => {
       // Get minted amount before.
       const mintedAmountBefore = await wormholeGateway.getMintedAmount();
 
-      // Create transfer VAA.
-      const sentAmount = BigInt(5000);
-      const signedVaa = await ethereumGatewaySendTbtc(
+      const sentAmount = BigInt(1000);
+      const relayerFee = BigInt(5);
+      const signedVaa = await ethereumGatewaySendTbtcWithPayload(
         payer,
         ethereumTokenBridge,
         sentAmount,
         fromGateway,
         WORMHOLE_GATEWAY_PROGRAM_ID,
-        recipient
-      );
-
-      // Set the mint limit to a value smaller than sentAmount.
-      const newLimit = sentAmount - BigInt(69);
-      const updateLimitIx = await wormholeGateway.updateMintingLimitIx(
-        {
-          authority: authority.publicKey,
-        },
-        newLimit
+        wormholeGateway.encodeTransferPayload({ recipient, relayerFee })
       );
-      await expectIxSuccess([updateLimitIx], [authority]);
-      await wormholeGateway.checkCustodian({
-        authority: authority.publicKey,
-        mintingLimit: newLimit,
-        pendingAuthority: null,
-      });
 
-      // Balance check before receiving wrapped tbtc. If this
-      // line successfully executes, then the recipient's
-      // wrapped tbtc account already exists.
-      const [tbtcBefore, wrappedTbtcBefore, gatewayBefore] = await Promise.all([
-        getAccount(connection, recipientToken),
-        getAccount(connection, recipientWrappedToken),
-        getAccount(connection, gatewayWrappedTbtcToken),
-      ]);
+      const tbtcBefore = await getTokenBalance(recipientToken);
 
       const ix = await wormholeGateway.receiveTbtcIx(
         {
           payer: payer.publicKey,
           recipientToken,
           recipient,
+          payerToken,
         },
         signedVaa
       );
       await expectIxSuccess([ix], [payer]);
 
-      // Check token accounts after receiving wrapped tbtc.
-      const [tbtcAfter, wrappedTbtcAfter, gatewayAfter] = await Promise.all([
-        getAccount(connection, recipientToken),
-        getAccount(connection, recipientWrappedToken),
-        getAccount(connection, gatewayWrappedTbtcToken),
-      ]);
+      // The fee is carved out of the minted amount.
+      const tbtcAfter = await getTokenBalance(recipientToken);
+      expect(tbtcAfter).to.equal(tbtcBefore + sentAmount - relayerFee);
+      expect(await getTokenBalance(payerToken)).to.equal(relayerFee);
 
-      // Check minted amount.
       const mintedAmountAfter = await wormholeGateway.getMintedAmount();
-      expect(mintedAmountAfter).to.equal(mintedAmountBefore);
-
-      // Check balance change.
-      expect(tbtcAfter.amount).to.equal(tbtcBefore.amount);
-      expect(gatewayAfter.amount).to.equal(gatewayBefore.amount);
-      expect(wrappedTbtcAfter.amount).to.equal(
-        wrappedTbtcBefore.amount + sentAmount
-      );
+      expect(mintedAmountAfter).to.equal(mintedAmountBefore + sentAmount);
     });
 
-    it("cannot receive non-tbtc transfers", async () => {
-      // Set up new wallet
+    it("receive tbtc with relayer fee (capped at max fee)", async () => {
+      // Set up new wallet, which relays the transfer.
       const payer = await generatePayer(authority);
+      const payerToken = await getOrCreateAta(
+        payer,
+        tbtc.getMintPDA(),
+        payer.publicKey
+      );
 
       // Use common token account.
       const recipient = commonTokenOwner.publicKey;
@@ -794,31 +1286,41 @@ describe("wormhole-gateway", () => {
         .getGatewayInfo(2)
         .then((info) => info.address);
 
-      const sentAmount = BigInt(5000);
-      const signedVaa = await ethereumGatewaySendTbtc(
+      // The fee exceeds 100 basis points of the amount.
+      const sentAmount = BigInt(1000);
+      const signedVaa = await ethereumGatewaySendTbtcWithPayload(
         payer,
         ethereumTokenBridge,
         sentAmount,
         fromGateway,
         WORMHOLE_GATEWAY_PROGRAM_ID,
-        recipient,
-        "0xC02aaA39b223FE8D0A0e5C4F27eAD9083C756Cc2", // WETH address
-        69 // hehe
+        wormholeGateway.encodeTransferPayload({
+          recipient,
+          relayerFee: BigInt(500),
+        })
       );
 
-      const failingIx = await wormholeGateway.receiveTbtcIx(
+      const tbtcBefore = await getTokenBalance(recipientToken);
+
+      const ix = await wormholeGateway.receiveTbtcIx(
         {
           payer: payer.publicKey,
           recipientToken,
           recipient,
+          payerToken,
         },
         signedVaa
       );
-      await expectIxFail([failingIx], [payer], "InvalidEthereumTbtc");
+      await expectIxSuccess([ix], [payer]);
+
+      const maxRelayerFee = BigInt(10);
+      const tbtcAfter = await getTokenBalance(recipientToken);
+      expect(tbtcAfter).to.equal(tbtcBefore + sentAmount - maxRelayerFee);
+      expect(await getTokenBalance(payerToken)).to.equal(maxRelayerFee);
     });
 
-    it("cannot receive zero-amount tbtc transfers", async () => {
-      // Set up new wallet
+    it("receive tbtc with relayer fee (fee waived)", async () => {
+      // Set up new wallet, which does not provide a token account for the fee.
       const payer = await generatePayer(authority);
 
       // Use common token account.
@@ -833,17 +1335,22 @@ describe("wormhole-gateway", () => {
         .getGatewayInfo(2)
         .then((info) => info.address);
 
-      const sentAmount = BigInt(0);
-      const signedVaa = await ethereumGatewaySendTbtc(
+      const sentAmount = BigInt(1000);
+      const signedVaa = await ethereumGatewaySendTbtcWithPayload(
         payer,
         ethereumTokenBridge,
         sentAmount,
         fromGateway,
         WORMHOLE_GATEWAY_PROGRAM_ID,
-        recipient
+        wormholeGateway.encodeTransferPayload({
+          recipient,
+          relayerFee: BigInt(5),
+        })
       );
 
-      const failingIx = await wormholeGateway.receiveTbtcIx(
+      const tbtcBefore = await getTokenBalance(recipientToken);
+
+      const ix = await wormholeGateway.receiveTbtcIx(
         {
           payer: payer.publicKey,
           recipientToken,
@@ -851,17 +1358,19 @@ describe("wormhole-gateway", () => {
         },
         signedVaa
       );
-      await expectIxFail([failingIx], [payer], "NoTbtcTransferred");
+      await expectIxSuccess([ix], [payer]);
+
+      const tbtcAfter = await getTokenBalance(recipientToken);
+      expect(tbtcAfter).to.equal(tbtcBefore + sentAmount);
     });
 
-    it("cannot receive tbtc transfer with zero address as recipient", async () => {
+    it("receive tbtc (version 0 payload with trailing data)", async () => {
       // Set up new wallet
       const payer = await generatePayer(authority);
 
-      // Use common token account. Set the recipient to the zero address.
-      const recipient = PublicKey.default;
-      const defaultTokenAccount = await getOrCreateAta(
-        payer,
+      // Use common token account.
+      const recipient = commonTokenOwner.publicKey;
+      const recipientToken = getAssociatedTokenAddressSync(
         tbtc.getMintPDA(),
         recipient
       );
@@ -871,362 +1380,2530 @@ describe("wormhole-gateway", () => {
         .getGatewayInfo(2)
         .then((info) => info.address);
 
-      const sentAmount = BigInt(100);
-      const signedVaa = await ethereumGatewaySendTbtc(
+      // The recipient is read from the leading 32 bytes.
+      const sentAmount = BigInt(500);
+      const signedVaa = await ethereumGatewaySendTbtcWithPayload(
         payer,
         ethereumTokenBridge,
         sentAmount,
         fromGateway,
         WORMHOLE_GATEWAY_PROGRAM_ID,
-        recipient
+        Buffer.concat([recipient.toBuffer(), Buffer.alloc(32, "ef", "hex")])
       );
 
-      const failingIx = await wormholeGateway.receiveTbtcIx(
+      const tbtcBefore = await getTokenBalance(recipientToken);
+
+      const ix = await wormholeGateway.receiveTbtcIx(
         {
           payer: payer.publicKey,
-          recipientToken: defaultTokenAccount,
+          recipientToken,
           recipient,
         },
         signedVaa
       );
-      await expectIxFail([failingIx], [payer], "RecipientZeroAddress");
+      await expectIxSuccess([ix], [payer]);
+
+      const tbtcAfter = await getTokenBalance(recipientToken);
+      expect(tbtcAfter).to.equal(tbtcBefore + sentAmount);
     });
-  });
 
-  describe("send tbtc", () => {
-    it("send tbtc to gateway", async () => {
-      // Use common token account.
-      const sender = commonTokenOwner.publicKey;
-      const senderToken = getAssociatedTokenAddressSync(
+    it("receive tbtc (version 0 payload with leading version byte)", async () => {
+      // Set up new wallet
+      const payer = await generatePayer(authority);
+
+      // A legacy recipient may start with a version byte.
+      const recipientBytes = Buffer.alloc(32, "beef", "hex");
+      recipientBytes[0] = 1;
+      const recipient = new PublicKey(recipientBytes);
+      const recipientToken = getAssociatedTokenAddressSync(
         tbtc.getMintPDA(),
-        sender
+        recipient,
+        true
       );
 
-      // Check token accounts.
-      const [senderTbtcBefore, gatewayBefore] = await Promise.all([
-        getAccount(connection, senderToken),
-        getAccount(connection, gatewayWrappedTbtcToken),
-      ]);
-
-      // Check minted amount before.
-      const mintedAmountBefore = await wormholeGateway.getMintedAmount();
-
-      // Get destination gateway.
-      const recipientChain = 2;
-      const recipient = Array.from(Buffer.alloc(32, "deadbeef", "hex"));
-      const nonce = 420;
+      // Get foreign gateway.
+      const fromGateway = await wormholeGateway
+        .getGatewayInfo(2)
+        .then((info) => info.address);
 
-      // This should work.
-      const sendAmount = BigInt(2000);
-      const ix = await wormholeGateway.sendTbtcGatewayIx(
+      // Make room for minting the received tBTC.
+      const sentAmount = BigInt(500);
+      const mintedAmount = await wormholeGateway.getMintedAmount();
+      const updateLimitIx = await wormholeGateway.updateMintingLimitIx(
         {
-          senderToken,
-          sender,
+          limitManager: authority.publicKey,
         },
-        {
-          amount: new anchor.BN(sendAmount.toString()),
-          recipientChain,
-          recipient,
-          nonce,
-        }
+        mintedAmount + sentAmount
       );
-      await expectIxSuccess([ix], [commonTokenOwner]);
+      await expectIxSuccess([updateLimitIx], [authority]);
 
-      // Check token accounts after sending tbtc.
-      const [senderTbtcAfter, gatewayAfter] = await Promise.all([
-        getAccount(connection, senderToken),
+      // The payload does not have the version 1 layout, so the recipient is
+      // read from the leading 32 bytes.
+      const signedVaa = await ethereumGatewaySendTbtcWithPayload(
+        payer,
+        ethereumTokenBridge,
+        sentAmount,
+        fromGateway,
+        WORMHOLE_GATEWAY_PROGRAM_ID,
+        Buffer.concat([recipientBytes, Buffer.alloc(32, "ef", "hex")])
+      );
+
+      const ix = await wormholeGateway.receiveTbtcIx(
+        {
+          payer: payer.publicKey,
+          recipientToken,
+          recipient,
+        },
+        signedVaa
+      );
+      await expectIxSuccess([ix], [payer]);
+
+      const tbtcAfter = await getTokenBalance(recipientToken);
+      expect(tbtcAfter).to.equal(sentAmount);
+    });
+
+    it("receive wrapped tbtc (ata doesn't exist)", async () => {
+      // Set up new wallet
+      const payer = await generatePayer(authority);
+
+      // Use common token account.
+      const recipient = commonTokenOwner.publicKey;
+      const recipientToken = getAssociatedTokenAddressSync(
+        tbtc.getMintPDA(),
+        recipient
+      );
+      const recipientWrappedToken = getAssociatedTokenAddressSync(
+        WRAPPED_TBTC_MINT,
+        recipient
+      );
+
+      // Get minted amount before.
+      const mintedAmountBefore = await wormholeGateway.getMintedAmount();
+
+      // Verify that the wrapped token account doesn't exist yet.
+      try {
+        await getAccount(connection, recipientWrappedToken);
+      } catch (e: any) {
+        expect(e.toString()).to.equal("TokenAccountNotFoundError");
+      }
+
+      // Get foreign gateway.
+      const fromGateway = await wormholeGateway
+        .getGatewayInfo(2)
+        .then((info) => info.address);
+
+      // Create transfer VAA.
+      const sentAmount = BigInt(5000);
+      const signedVaa = await ethereumGatewaySendTbtc(
+        payer,
+        ethereumTokenBridge,
+        sentAmount,
+        fromGateway,
+        WORMHOLE_GATEWAY_PROGRAM_ID,
+        recipient
+      );
+
+      // Set the mint limit to a value smaller than sentAmount.
+      const newLimit = sentAmount - BigInt(69);
+      const updateLimitIx = await wormholeGateway.updateMintingLimitIx(
+        {
+          limitManager: authority.publicKey,
+        },
+        newLimit
+      );
+      await expectIxSuccess([updateLimitIx], [authority]);
+      await wormholeGateway.checkCustodian({
+        authority: authority.publicKey,
+        mintingLimit: newLimit,
+        pendingAuthority: null,
+      });
+
+      // Balance check before receiving wrapped tbtc. We can't
+      // check the balance of the recipient's wrapped tbtc yet,
+      // since the contract will create the ATA.
+      const [tbtcBefore, gatewayBefore] = await Promise.all([
+        getAccount(connection, recipientToken),
+        getAccount(connection, gatewayWrappedTbtcToken),
+      ]);
+
+      const ix = await wormholeGateway.receiveTbtcIx(
+        {
+          payer: payer.publicKey,
+          recipientToken,
+          recipient,
+        },
+        signedVaa
+      );
+      await expectIxSuccess([ix], [payer]);
+
+      // Check token accounts after receiving wrapped tbtc. We should
+      // be able to fetch the recipient's wrapped tbtc now.
+      const [tbtcAfter, wrappedTbtcAfter, gatewayAfter] = await Promise.all([
+        getAccount(connection, recipientToken),
+        getAccount(connection, recipientWrappedToken),
         getAccount(connection, gatewayWrappedTbtcToken),
       ]);
 
       // Check minted amount.
       const mintedAmountAfter = await wormholeGateway.getMintedAmount();
-      expect(mintedAmountAfter).to.equal(mintedAmountBefore - sendAmount);
+      expect(mintedAmountAfter).to.equal(mintedAmountBefore);
 
       // Check balance change.
-      expect(senderTbtcAfter.amount).to.equal(
-        senderTbtcBefore.amount - sendAmount
-      );
-      expect(gatewayAfter.amount).to.equal(gatewayBefore.amount - sendAmount);
+      expect(tbtcAfter.amount).to.equal(tbtcBefore.amount);
+      expect(gatewayAfter.amount).to.equal(gatewayBefore.amount);
+      expect(wrappedTbtcAfter.amount).to.equal(sentAmount);
     });
 
-    it("cannot send tbtc to gateway (insufficient wrapped balance)", async () => {
+    it("receive wrapped tbtc (ata exists)", async () => {
+      // Set up new wallet
+      const payer = await generatePayer(authority);
+
       // Use common token account.
-      const sender = commonTokenOwner.publicKey;
-      const senderToken = getAssociatedTokenAddressSync(
+      const recipient = commonTokenOwner.publicKey;
+      const recipientToken = getAssociatedTokenAddressSync(
         tbtc.getMintPDA(),
-        sender
+        recipient
+      );
+      const recipientWrappedToken = await getOrCreateAta(
+        payer,
+        WRAPPED_TBTC_MINT,
+        recipient
       );
 
-      // Get destination gateway.
-      const recipientChain = 2;
-      const recipient = Array.from(Buffer.alloc(32, "deadbeef", "hex"));
-      const nonce = 420;
+      // Get foreign gateway.
+      const fromGateway = await wormholeGateway
+        .getGatewayInfo(2)
+        .then((info) => info.address);
 
-      // Check token accounts.
-      const gatewayWrappedBalance = await getAccount(
-        connection,
-        gatewayWrappedTbtcToken
+      // Get minted amount before.
+      const mintedAmountBefore = await wormholeGateway.getMintedAmount();
+
+      // Create transfer VAA.
+      const sentAmount = BigInt(5000);
+      const signedVaa = await ethereumGatewaySendTbtc(
+        payer,
+        ethereumTokenBridge,
+        sentAmount,
+        fromGateway,
+        WORMHOLE_GATEWAY_PROGRAM_ID,
+        recipient
       );
 
-      // Try an amount that won't work.
-      const sendAmount = gatewayWrappedBalance.amount + BigInt(69);
-      const ix = await wormholeGateway.sendTbtcGatewayIx(
+      // Set the mint limit to a value smaller than sentAmount.
+      const newLimit = sentAmount - BigInt(69);
+      const updateLimitIx = await wormholeGateway.updateMintingLimitIx(
         {
-          senderToken,
-          sender,
+          limitManager: authority.publicKey,
         },
+        newLimit
+      );
+      await expectIxSuccess([updateLimitIx], [authority]);
+      await wormholeGateway.checkCustodian({
+        authority: authority.publicKey,
+        mintingLimit: newLimit,
+        pendingAuthority: null,
+      });
+
+      // Balance check before receiving wrapped tbtc. If this
+      // line successfully executes, then the recipient's
+      // wrapped tbtc account already exists.
+      const [tbtcBefore, wrappedTbtcBefore, gatewayBefore] = await Promise.all([
+        getAccount(connection, recipientToken),
+        getAccount(connection, recipientWrappedToken),
+        getAccount(connection, gatewayWrappedTbtcToken),
+      ]);
+
+      const ix = await wormholeGateway.receiveTbtcIx(
         {
-          amount: new anchor.BN(sendAmount.toString()),
-          recipientChain,
+          payer: payer.publicKey,
+          recipientToken,
           recipient,
-          nonce,
-        }
+        },
+        signedVaa
       );
-      await expectIxFail([ix], [commonTokenOwner], "NotEnoughWrappedTbtc");
+      await expectIxSuccess([ix], [payer]);
+
+      // Check token accounts after receiving wrapped tbtc.
+      const [tbtcAfter, wrappedTbtcAfter, gatewayAfter] = await Promise.all([
+        getAccount(connection, recipientToken),
+        getAccount(connection, recipientWrappedToken),
+        getAccount(connection, gatewayWrappedTbtcToken),
+      ]);
+
+      // Check minted amount.
+      const mintedAmountAfter = await wormholeGateway.getMintedAmount();
+      expect(mintedAmountAfter).to.equal(mintedAmountBefore);
+
+      // Check balance change.
+      expect(tbtcAfter.amount).to.equal(tbtcBefore.amount);
+      expect(gatewayAfter.amount).to.equal(gatewayBefore.amount);
+      expect(wrappedTbtcAfter.amount).to.equal(
+        wrappedTbtcBefore.amount + sentAmount
+      );
+    });
+
+    it("cannot update receive mode (not limit manager)", async () => {
+      const failingIx = await wormholeGateway.updateReceiveModeIx(
+        {
+          limitManager: imposter.publicKey,
+        },
+        "split"
+      );
+      await expectIxFail([failingIx], [imposter], "AccountNotInitialized");
+    });
+
+    it("receive split tbtc", async () => {
+      // Set up new wallet
+      const payer = await generatePayer(authority);
+
+      // Use common token account.
+      const recipient = commonTokenOwner.publicKey;
+      const recipientToken = getAssociatedTokenAddressSync(
+        tbtc.getMintPDA(),
+        recipient
+      );
+      const recipientWrappedToken = getAssociatedTokenAddressSync(
+        WRAPPED_TBTC_MINT,
+        recipient
+      );
+
+      // Get foreign gateway.
+      const fromGateway = await wormholeGateway
+        .getGatewayInfo(2)
+        .then((info) => info.address);
+
+      // Get minted amount before.
+      const mintedAmountBefore = await wormholeGateway.getMintedAmount();
+
+      // Create transfer VAA.
+      const sentAmount = BigInt(5000);
+      const signedVaa = await ethereumGatewaySendTbtc(
+        payer,
+        ethereumTokenBridge,
+        sentAmount,
+        fromGateway,
+        WORMHOLE_GATEWAY_PROGRAM_ID,
+        recipient
+      );
+
+      // Only leave room to mint part of sentAmount.
+      const mintableAmount = BigInt(69);
+      const newLimit = mintedAmountBefore + mintableAmount;
+      const updateLimitIx = await wormholeGateway.updateMintingLimitIx(
+        {
+          limitManager: authority.publicKey,
+        },
+        newLimit
+      );
+      const splitIx = await wormholeGateway.updateReceiveModeIx(
+        {
+          limitManager: authority.publicKey,
+        },
+        "split"
+      );
+      await expectIxSuccess([updateLimitIx, splitIx], [authority]);
+
+      const custodianState = await wormholeGateway.getCustodianData();
+      expect(custodianState.receiveMode).to.eql({ split: {} });
+
+      const [tbtcBefore, wrappedTbtcBefore, gatewayBefore] = await Promise.all([
+        getAccount(connection, recipientToken),
+        getAccount(connection, recipientWrappedToken),
+        getAccount(connection, gatewayWrappedTbtcToken),
+      ]);
+
+      const ix = await wormholeGateway.receiveTbtcIx(
+        {
+          payer: payer.publicKey,
+          recipientToken,
+          recipient,
+        },
+        signedVaa
+      );
+      await expectIxSuccess([ix], [payer]);
+
+      const [tbtcAfter, wrappedTbtcAfter, gatewayAfter] = await Promise.all([
+        getAccount(connection, recipientToken),
+        getAccount(connection, recipientWrappedToken),
+        getAccount(connection, gatewayWrappedTbtcToken),
+      ]);
+
+      // Check minted amount.
+      const mintedAmountAfter = await wormholeGateway.getMintedAmount();
+      expect(mintedAmountAfter).to.equal(newLimit);
+
+      // Check balance change.
+      expect(tbtcAfter.amount).to.equal(tbtcBefore.amount + mintableAmount);
+      expect(gatewayAfter.amount).to.equal(
+        gatewayBefore.amount + mintableAmount
+      );
+      expect(wrappedTbtcAfter.amount).to.equal(
+        wrappedTbtcBefore.amount + sentAmount - mintableAmount
+      );
+
+      // Go back to the default receive mode.
+      const allOrNothingIx = await wormholeGateway.updateReceiveModeIx(
+        {
+          limitManager: authority.publicKey,
+        },
+        "allOrNothing"
+      );
+      await expectIxSuccess([allOrNothingIx], [authority]);
+    });
+
+    it("receive split tbtc (minter allowance exceeded)", async () => {
+      // Set up new wallet
+      const payer = await generatePayer(authority);
+
+      // Use common token account.
+      const recipient = commonTokenOwner.publicKey;
+      const recipientToken = getAssociatedTokenAddressSync(
+        tbtc.getMintPDA(),
+        recipient
+      );
+      const recipientWrappedToken = getAssociatedTokenAddressSync(
+        WRAPPED_TBTC_MINT,
+        recipient
+      );
+
+      // Get foreign gateway.
+      const fromGateway = await wormholeGateway
+        .getGatewayInfo(2)
+        .then((info) => info.address);
+
+      // Create transfer VAA.
+      const sentAmount = BigInt(5000);
+      const signedVaa = await ethereumGatewaySendTbtc(
+        payer,
+        ethereumTokenBridge,
+        sentAmount,
+        fromGateway,
+        WORMHOLE_GATEWAY_PROGRAM_ID,
+        recipient
+      );
+
+      // Leave room under the minting limit, but only allow the custodian to
+      // mint part of sentAmount.
+      const mintedAmountBefore = await wormholeGateway.getMintedAmount();
+      const mintableAmount = BigInt(42);
+      const minted = await tbtc
+        .getMinterInfo(custodian)
+        .then((info) => BigInt(info.minted.toString()));
+      const updateLimitIx = await wormholeGateway.updateMintingLimitIx(
+        {
+          limitManager: authority.publicKey,
+        },
+        mintedAmountBefore + sentAmount
+      );
+      const splitIx = await wormholeGateway.updateReceiveModeIx(
+        {
+          limitManager: authority.publicKey,
+        },
+        "split"
+      );
+      const setAllowanceIx = await tbtc.setMinterAllowanceIx(
+        {
+          minterAdmin: authority.publicKey,
+          minter: custodian,
+        },
+        minted + mintableAmount
+      );
+      await expectIxSuccess(
+        [updateLimitIx, splitIx, setAllowanceIx],
+        [authority]
+      );
+
+      const [tbtcBefore, wrappedTbtcBefore] = await Promise.all([
+        getAccount(connection, recipientToken),
+        getAccount(connection, recipientWrappedToken),
+      ]);
+
+      const ix = await wormholeGateway.receiveTbtcIx(
+        {
+          payer: payer.publicKey,
+          recipientToken,
+          recipient,
+        },
+        signedVaa
+      );
+      await expectIxSuccess([ix], [payer]);
+
+      const [tbtcAfter, wrappedTbtcAfter] = await Promise.all([
+        getAccount(connection, recipientToken),
+        getAccount(connection, recipientWrappedToken),
+      ]);
+
+      // Check minted amount.
+      const mintedAmountAfter = await wormholeGateway.getMintedAmount();
+      expect(mintedAmountAfter).to.equal(mintedAmountBefore + mintableAmount);
+
+      // Check balance change.
+      expect(tbtcAfter.amount).to.equal(tbtcBefore.amount + mintableAmount);
+      expect(wrappedTbtcAfter.amount).to.equal(
+        wrappedTbtcBefore.amount + sentAmount - mintableAmount
+      );
+
+      // Restore the allowance and go back to the default receive mode.
+      const restoreAllowanceIx = await tbtc.setMinterAllowanceIx(
+        {
+          minterAdmin: authority.publicKey,
+          minter: custodian,
+        },
+        BigInt("18446744073709551615") // Max u64
+      );
+      const allOrNothingIx = await wormholeGateway.updateReceiveModeIx(
+        {
+          limitManager: authority.publicKey,
+        },
+        "allOrNothing"
+      );
+      await expectIxSuccess([restoreAllowanceIx, allOrNothingIx], [authority]);
+    });
+
+    it("receive split tbtc with relayer fee (capped at max fee)", async () => {
+      // Set up new wallet, which relays the transfer.
+      const payer = await generatePayer(authority);
+      const payerToken = await getOrCreateAta(
+        payer,
+        tbtc.getMintPDA(),
+        payer.publicKey
+      );
+
+      // Use common token account.
+      const recipient = commonTokenOwner.publicKey;
+      const recipientToken = getAssociatedTokenAddressSync(
+        tbtc.getMintPDA(),
+        recipient
+      );
+      const recipientWrappedToken = getAssociatedTokenAddressSync(
+        WRAPPED_TBTC_MINT,
+        recipient
+      );
+
+      // Get foreign gateway.
+      const fromGateway = await wormholeGateway
+        .getGatewayInfo(2)
+        .then((info) => info.address);
+
+      // Create transfer VAA. The fee exceeds 100 basis points of the amount.
+      const sentAmount = BigInt(5000);
+      const signedVaa = await ethereumGatewaySendTbtcWithPayload(
+        payer,
+        ethereumTokenBridge,
+        sentAmount,
+        fromGateway,
+        WORMHOLE_GATEWAY_PROGRAM_ID,
+        wormholeGateway.encodeTransferPayload({
+          recipient,
+          relayerFee: BigInt(500),
+        })
+      );
+
+      // Only leave room to mint part of sentAmount.
+      const mintedAmountBefore = await wormholeGateway.getMintedAmount();
+      const mintableAmount = BigInt(200);
+      const updateLimitIx = await wormholeGateway.updateMintingLimitIx(
+        {
+          limitManager: authority.publicKey,
+        },
+        mintedAmountBefore + mintableAmount
+      );
+      const splitIx = await wormholeGateway.updateReceiveModeIx(
+        {
+          limitManager: authority.publicKey,
+        },
+        "split"
+      );
+      await expectIxSuccess([updateLimitIx, splitIx], [authority]);
+
+      const [tbtcBefore, wrappedTbtcBefore] = await Promise.all([
+        getAccount(connection, recipientToken),
+        getAccount(connection, recipientWrappedToken),
+      ]);
+
+      const ix = await wormholeGateway.receiveTbtcIx(
+        {
+          payer: payer.publicKey,
+          recipientToken,
+          recipient,
+          payerToken,
+        },
+        signedVaa
+      );
+      await expectIxSuccess([ix], [payer]);
+
+      const [tbtcAfter, wrappedTbtcAfter] = await Promise.all([
+        getAccount(connection, recipientToken),
+        getAccount(connection, recipientWrappedToken),
+      ]);
+
+      // The fee is capped at 100 basis points of the minted amount, not of the
+      // sent amount.
+      const maxRelayerFee = BigInt(2);
+      expect(tbtcAfter.amount).to.equal(
+        tbtcBefore.amount + mintableAmount - maxRelayerFee
+      );
+      expect(await getTokenBalance(payerToken)).to.equal(maxRelayerFee);
+      expect(wrappedTbtcAfter.amount).to.equal(
+        wrappedTbtcBefore.amount + sentAmount - mintableAmount
+      );
+
+      // Go back to the default receive mode.
+      const allOrNothingIx = await wormholeGateway.updateReceiveModeIx(
+        {
+          limitManager: authority.publicKey,
+        },
+        "allOrNothing"
+      );
+      await expectIxSuccess([allOrNothingIx], [authority]);
+    });
+
+    it("receive queued tbtc", async () => {
+      // Set up new wallet
+      const payer = await generatePayer(authority);
+
+      // Use common token account.
+      const recipient = commonTokenOwner.publicKey;
+      const recipientToken = getAssociatedTokenAddressSync(
+        tbtc.getMintPDA(),
+        recipient
+      );
+      const recipientWrappedToken = getAssociatedTokenAddressSync(
+        WRAPPED_TBTC_MINT,
+        recipient
+      );
+
+      // Get foreign gateway.
+      const fromGateway = await wormholeGateway
+        .getGatewayInfo(2)
+        .then((info) => info.address);
+
+      // Get minted amount before.
+      const mintedAmountBefore = await wormholeGateway.getMintedAmount();
+
+      // Create transfer VAA.
+      const sentAmount = BigInt(5000);
+      const signedVaa = await ethereumGatewaySendTbtc(
+        payer,
+        ethereumTokenBridge,
+        sentAmount,
+        fromGateway,
+        WORMHOLE_GATEWAY_PROGRAM_ID,
+        recipient
+      );
+
+      // Only leave room to mint part of sentAmount.
+      const mintableAmount = BigInt(69);
+      const newLimit = mintedAmountBefore + mintableAmount;
+      const updateLimitIx = await wormholeGateway.updateMintingLimitIx(
+        {
+          limitManager: authority.publicKey,
+        },
+        newLimit
+      );
+      const queueIx = await wormholeGateway.updateReceiveModeIx(
+        {
+          limitManager: authority.publicKey,
+        },
+        "queue"
+      );
+      await expectIxSuccess([updateLimitIx, queueIx], [authority]);
+
+      const [tbtcBefore, wrappedTbtcBefore, gatewayBefore] = await Promise.all([
+        getAccount(connection, recipientToken),
+        getAccount(connection, recipientWrappedToken),
+        getAccount(connection, gatewayWrappedTbtcToken),
+      ]);
+
+      const ix = await wormholeGateway.receiveTbtcIx(
+        {
+          payer: payer.publicKey,
+          recipientToken,
+          recipient,
+        },
+        signedVaa
+      );
+      const txSig = await expectIxSuccess([ix], [payer]);
+
+      const [tbtcAfter, wrappedTbtcAfter, gatewayAfter] = await Promise.all([
+        getAccount(connection, recipientToken),
+        getAccount(connection, recipientWrappedToken),
+        getAccount(connection, gatewayWrappedTbtcToken),
+      ]);
+
+      // Check minted amount.
+      const mintedAmountAfter = await wormholeGateway.getMintedAmount();
+      expect(mintedAmountAfter).to.equal(newLimit);
+
+      // Check balance change. The excess stays in custody.
+      expect(tbtcAfter.amount).to.equal(tbtcBefore.amount + mintableAmount);
+      expect(gatewayAfter.amount).to.equal(gatewayBefore.amount + sentAmount);
+      expect(wrappedTbtcAfter.amount).to.equal(wrappedTbtcBefore.amount);
+
+      // Check pending mint.
+      const pendingAmount = sentAmount - mintableAmount;
+      const pendingMint = await wormholeGateway.getPendingMint(recipient);
+      expect(pendingMint.recipient).to.eql(recipient);
+      expect(pendingMint.amount.toString()).to.equal(pendingAmount.toString());
+
+      const custodianState = await wormholeGateway.getCustodianData();
+      expect(custodianState.pendingAmount.toString()).to.equal(
+        pendingAmount.toString()
+      );
+
+      // The split between minted and queued tBTC is emitted.
+      const [event] = await getTxEvents(program, txSig).then((events) =>
+        events.filter((e) => e.name === "WormholeTbtcReceivedSplit")
+      );
+      expect(event.data.receiver).to.eql(recipient);
+      expect(event.data.mintedAmount.toString()).to.equal(
+        mintableAmount.toString()
+      );
+      expect(event.data.wrappedAmount.toString()).to.equal("0");
+      expect(event.data.queuedAmount.toString()).to.equal(
+        pendingAmount.toString()
+      );
+    });
+
+    it("cannot claim pending mint (minting limit exceeded)", async () => {
+      const recipient = commonTokenOwner.publicKey;
+      const recipientToken = getAssociatedTokenAddressSync(
+        tbtc.getMintPDA(),
+        recipient
+      );
+
+      const failingIx = await wormholeGateway.claimPendingMintIx({
+        recipientToken,
+        recipient,
+      });
+      await expectIxFail([failingIx], [txPayer], "MintingLimitExceeded");
+    });
+
+    it("claim pending mint", async () => {
+      // Use common token account.
+      const recipient = commonTokenOwner.publicKey;
+      const recipientToken = getAssociatedTokenAddressSync(
+        tbtc.getMintPDA(),
+        recipient
+      );
+
+      // Raise the minting limit so the pending mint can be claimed.
+      const mintedAmountBefore = await wormholeGateway.getMintedAmount();
+      const updateLimitIx = await wormholeGateway.updateMintingLimitIx(
+        {
+          limitManager: authority.publicKey,
+        },
+        mintedAmountBefore + BigInt(10000)
+      );
+      await expectIxSuccess([updateLimitIx], [authority]);
+
+      const pendingAmount = await wormholeGateway
+        .getPendingMint(recipient)
+        .then((info) => BigInt(info.amount.toString()));
+      const [tbtcBefore, gatewayBefore, lamportsBefore] = await Promise.all([
+        getAccount(connection, recipientToken),
+        getAccount(connection, gatewayWrappedTbtcToken),
+        connection.getBalance(recipient),
+      ]);
+      const pendingMintLamports = await connection.getBalance(
+        wormholeGateway.getPendingMintPDA(recipient)
+      );
+
+      // Anyone can claim on behalf of the recipient.
+      const ix = await wormholeGateway.claimPendingMintIx({
+        recipientToken,
+        recipient,
+      });
+      await expectIxSuccess([ix], [txPayer]);
+
+      const [tbtcAfter, gatewayAfter] = await Promise.all([
+        getAccount(connection, recipientToken),
+        getAccount(connection, gatewayWrappedTbtcToken),
+      ]);
+
+      // Check minted amount.
+      const mintedAmountAfter = await wormholeGateway.getMintedAmount();
+      expect(mintedAmountAfter).to.equal(mintedAmountBefore + pendingAmount);
+
+      // Check balance change.
+      expect(tbtcAfter.amount).to.equal(tbtcBefore.amount + pendingAmount);
+      expect(gatewayAfter.amount).to.equal(gatewayBefore.amount);
+
+      // Check that the fully claimed pending mint is closed and its lamports
+      // are returned to the recipient.
+      const pendingMintInfo = await connection.getAccountInfo(
+        wormholeGateway.getPendingMintPDA(recipient)
+      );
+      expect(pendingMintInfo).to.be.null;
+
+      const lamportsAfter = await connection.getBalance(recipient);
+      expect(lamportsAfter).to.equal(lamportsBefore + pendingMintLamports);
+
+      const custodianState = await wormholeGateway.getCustodianData();
+      expect(custodianState.pendingAmount.toString()).to.equal("0");
+    });
+
+    it("cannot claim pending mint (nothing pending)", async () => {
+      const recipient = commonTokenOwner.publicKey;
+      const recipientToken = getAssociatedTokenAddressSync(
+        tbtc.getMintPDA(),
+        recipient
+      );
+
+      const failingIx = await wormholeGateway.claimPendingMintIx({
+        recipientToken,
+        recipient,
+      });
+      await expectIxFail([failingIx], [txPayer], "AccountNotInitialized");
+
+      // Go back to the default receive mode.
+      const allOrNothingIx = await wormholeGateway.updateReceiveModeIx(
+        {
+          limitManager: authority.publicKey,
+        },
+        "allOrNothing"
+      );
+      await expectIxSuccess([allOrNothingIx], [authority]);
+    });
+
+    it("receive wrapped tbtc (gateway minting limit exceeded)", async () => {
+      // Set up new wallet
+      const payer = await generatePayer(authority);
+
+      // Use common token account.
+      const recipient = commonTokenOwner.publicKey;
+      const recipientToken = getAssociatedTokenAddressSync(
+        tbtc.getMintPDA(),
+        recipient
+      );
+      const recipientWrappedToken = getAssociatedTokenAddressSync(
+        WRAPPED_TBTC_MINT,
+        recipient
+      );
+
+      // Get foreign gateway.
+      const chain = 2;
+      const fromGateway = await wormholeGateway
+        .getGatewayInfo(chain)
+        .then((info) => info.address);
+
+      // Create transfer VAA.
+      const sentAmount = BigInt(5000);
+      const signedVaa = await ethereumGatewaySendTbtc(
+        payer,
+        ethereumTokenBridge,
+        sentAmount,
+        fromGateway,
+        WORMHOLE_GATEWAY_PROGRAM_ID,
+        recipient
+      );
+
+      // The minting limit allows minting, but the gateway's does not.
+      const mintedAmountBefore = await wormholeGateway.getMintedAmount();
+      const gatewayMintedAmountBefore =
+        await wormholeGateway.getGatewayMintedAmount(chain);
+      const updateLimitIx = await wormholeGateway.updateMintingLimitIx(
+        {
+          limitManager: authority.publicKey,
+        },
+        mintedAmountBefore + sentAmount
+      );
+      const updateGatewayLimitIx =
+        await wormholeGateway.updateGatewayMintingLimitIx(
+          {
+            limitManager: authority.publicKey,
+          },
+          { chain, mintingLimit: gatewayMintedAmountBefore }
+        );
+      await expectIxSuccess(
+        [updateLimitIx, updateGatewayLimitIx],
+        [authority]
+      );
+
+      const [tbtcBefore, wrappedTbtcBefore, gatewayBefore] = await Promise.all([
+        getAccount(connection, recipientToken),
+        getAccount(connection, recipientWrappedToken),
+        getAccount(connection, gatewayWrappedTbtcToken),
+      ]);
+
+      const ix = await wormholeGateway.receiveTbtcIx(
+        {
+          payer: payer.publicKey,
+          recipientToken,
+          recipient,
+        },
+        signedVaa
+      );
+      await expectIxSuccess([ix], [payer]);
+
+      const [tbtcAfter, wrappedTbtcAfter, gatewayAfter] = await Promise.all([
+        getAccount(connection, recipientToken),
+        getAccount(connection, recipientWrappedToken),
+        getAccount(connection, gatewayWrappedTbtcToken),
+      ]);
+
+      // Check minted amounts.
+      const mintedAmountAfter = await wormholeGateway.getMintedAmount();
+      expect(mintedAmountAfter).to.equal(mintedAmountBefore);
+      const gatewayMintedAmountAfter =
+        await wormholeGateway.getGatewayMintedAmount(chain);
+      expect(gatewayMintedAmountAfter).to.equal(gatewayMintedAmountBefore);
+
+      // Check balance change.
+      expect(tbtcAfter.amount).to.equal(tbtcBefore.amount);
+      expect(gatewayAfter.amount).to.equal(gatewayBefore.amount);
+      expect(wrappedTbtcAfter.amount).to.equal(
+        wrappedTbtcBefore.amount + sentAmount
+      );
+
+      // Remove the gateway's minting limit again.
+      const resetGatewayLimitIx =
+        await wormholeGateway.updateGatewayMintingLimitIx(
+          {
+            limitManager: authority.publicKey,
+          },
+          { chain, mintingLimit: BigInt("18446744073709551615") }
+        );
+      await expectIxSuccess([resetGatewayLimitIx], [authority]);
+    });
+
+    it("cannot receive non-tbtc transfers", async () => {
+      // Set up new wallet
+      const payer = await generatePayer(authority);
+
+      // Use common token account.
+      const recipient = commonTokenOwner.publicKey;
+      const recipientToken = getAssociatedTokenAddressSync(
+        tbtc.getMintPDA(),
+        recipient
+      );
+
+      // Get foreign gateway.
+      const fromGateway = await wormholeGateway
+        .getGatewayInfo(2)
+        .then((info) => info.address);
+
+      const sentAmount = BigInt(5000);
+      const signedVaa = await ethereumGatewaySendTbtc(
+        payer,
+        ethereumTokenBridge,
+        sentAmount,
+        fromGateway,
+        WORMHOLE_GATEWAY_PROGRAM_ID,
+        recipient,
+        "0xC02aaA39b223FE8D0A0e5C4F27eAD9083C756Cc2", // WETH address
+        69 // hehe
+      );
+
+      const failingIx = await wormholeGateway.receiveTbtcIx(
+        {
+          payer: payer.publicKey,
+          recipientToken,
+          recipient,
+        },
+        signedVaa
+      );
+      await expectIxFail([failingIx], [payer], "InvalidCanonicalToken");
+    });
+
+    it("cannot receive zero-amount tbtc transfers", async () => {
+      // Set up new wallet
+      const payer = await generatePayer(authority);
+
+      // Use common token account.
+      const recipient = commonTokenOwner.publicKey;
+      const recipientToken = getAssociatedTokenAddressSync(
+        tbtc.getMintPDA(),
+        recipient
+      );
+
+      // Get foreign gateway.
+      const fromGateway = await wormholeGateway
+        .getGatewayInfo(2)
+        .then((info) => info.address);
+
+      const sentAmount = BigInt(0);
+      const signedVaa = await ethereumGatewaySendTbtc(
+        payer,
+        ethereumTokenBridge,
+        sentAmount,
+        fromGateway,
+        WORMHOLE_GATEWAY_PROGRAM_ID,
+        recipient
+      );
+
+      const failingIx = await wormholeGateway.receiveTbtcIx(
+        {
+          payer: payer.publicKey,
+          recipientToken,
+          recipient,
+        },
+        signedVaa
+      );
+      await expectIxFail([failingIx], [payer], "NoTbtcTransferred");
+    });
+
+    it("cannot receive tbtc (unknown source gateway)", async () => {
+      // Set up new wallet
+      const payer = await generatePayer(authority);
+
+      // Use common token account.
+      const recipient = commonTokenOwner.publicKey;
+      const recipientToken = getAssociatedTokenAddressSync(
+        tbtc.getMintPDA(),
+        recipient
+      );
+
+      // Send from a contract that is not the registered gateway.
+      const fromGateway = Array.from(Buffer.alloc(32, "deadc0de", "hex"));
+
+      const sentAmount = BigInt(100);
+      const signedVaa = await ethereumGatewaySendTbtc(
+        payer,
+        ethereumTokenBridge,
+        sentAmount,
+        fromGateway,
+        WORMHOLE_GATEWAY_PROGRAM_ID,
+        recipient
+      );
+
+      const failingIx = await wormholeGateway.receiveTbtcIx(
+        {
+          payer: payer.publicKey,
+          recipientToken,
+          recipient,
+        },
+        signedVaa
+      );
+      await expectIxFail([failingIx], [payer], "UnknownSourceGateway");
+    });
+
+    it("cannot receive tbtc (gateway disabled)", async () => {
+      // Set up new wallet
+      const payer = await generatePayer(authority);
+
+      // Use common token account.
+      const recipient = commonTokenOwner.publicKey;
+      const recipientToken = getAssociatedTokenAddressSync(
+        tbtc.getMintPDA(),
+        recipient
+      );
+
+      // Get foreign gateway.
+      const chain = 2;
+      const fromGateway = await wormholeGateway
+        .getGatewayInfo(chain)
+        .then((info) => info.address);
+
+      const sentAmount = BigInt(100);
+      const signedVaa = await ethereumGatewaySendTbtc(
+        payer,
+        ethereumTokenBridge,
+        sentAmount,
+        fromGateway,
+        WORMHOLE_GATEWAY_PROGRAM_ID,
+        recipient
+      );
+
+      const disableIx = await wormholeGateway.updateGatewayDisabledIx(
+        {
+          gatewayManager: authority.publicKey,
+        },
+        { chain, disabled: true }
+      );
+      await expectIxSuccess([disableIx], [authority]);
+
+      const failingIx = await wormholeGateway.receiveTbtcIx(
+        {
+          payer: payer.publicKey,
+          recipientToken,
+          recipient,
+        },
+        signedVaa
+      );
+      await expectIxFail([failingIx], [payer], "GatewayDisabled");
+
+      const enableIx = await wormholeGateway.updateGatewayDisabledIx(
+        {
+          gatewayManager: authority.publicKey,
+        },
+        { chain, disabled: false }
+      );
+      await expectIxSuccess([enableIx], [authority]);
+    });
+
+    it("cannot receive tbtc transfer with zero address as recipient", async () => {
+      // Set up new wallet
+      const payer = await generatePayer(authority);
+
+      // Use common token account. Set the recipient to the zero address.
+      const recipient = PublicKey.default;
+      const defaultTokenAccount = await getOrCreateAta(
+        payer,
+        tbtc.getMintPDA(),
+        recipient
+      );
+
+      // Get foreign gateway.
+      const fromGateway = await wormholeGateway
+        .getGatewayInfo(2)
+        .then((info) => info.address);
+
+      const sentAmount = BigInt(100);
+      const signedVaa = await ethereumGatewaySendTbtc(
+        payer,
+        ethereumTokenBridge,
+        sentAmount,
+        fromGateway,
+        WORMHOLE_GATEWAY_PROGRAM_ID,
+        recipient
+      );
+
+      const failingIx = await wormholeGateway.receiveTbtcIx(
+        {
+          payer: payer.publicKey,
+          recipientToken: defaultTokenAccount,
+          recipient,
+        },
+        signedVaa
+      );
+      await expectIxFail([failingIx], [payer], "RecipientZeroAddress");
+    });
+
+    it("cannot remove gateway address (minted amount not zero)", async () => {
+      const { mintedAmount } = await wormholeGateway.getGatewayInfo(2);
+      expect(mintedAmount.toString()).to.not.equal("0");
+
+      const failingIx = await wormholeGateway.removeGatewayAddressIx(
+        {
+          gatewayManager: authority.publicKey,
+        },
+        { chain: 2 }
+      );
+      await expectIxFail(
+        [failingIx],
+        [authority],
+        "GatewayMintedAmountNotZero"
+      );
+    });
+  });
+
+  describe("send tbtc", () => {
+    it("send tbtc to gateway", async () => {
+      // Use common token account.
+      const sender = commonTokenOwner.publicKey;
+      const senderToken = getAssociatedTokenAddressSync(
+        tbtc.getMintPDA(),
+        sender
+      );
+
+      // Check token accounts.
+      const [senderTbtcBefore, gatewayBefore] = await Promise.all([
+        getAccount(connection, senderToken),
+        getAccount(connection, gatewayWrappedTbtcToken),
+      ]);
+
+      // Check minted amounts before.
+      const mintedAmountBefore = await wormholeGateway.getMintedAmount();
+      const gatewayMintedAmountBefore =
+        await wormholeGateway.getGatewayMintedAmount(2);
+      const totalBurnedBefore = await tbtc
+        .getConfigData()
+        .then((config) => BigInt(config.totalBurned.toString()));
+
+      // Get destination gateway.
+      const recipientChain = 2;
+      // EVM address, left-padded to 32 bytes.
+      const recipient = Array.from(
+        Buffer.alloc(32, "deadbeef", "hex").fill(0, 0, 12)
+      );
+      const nonce = 420;
+
+      // This should work.
+      const sendAmount = BigInt(2000);
+      const ix = await wormholeGateway.sendTbtcGatewayIx(
+        {
+          senderToken,
+          sender,
+        },
+        {
+          amount: new anchor.BN(sendAmount.toString()),
+          recipientChain,
+          recipient,
+          nonce,
+        }
+      );
+      await expectIxSuccess([ix], [commonTokenOwner]);
+
+      // Check token accounts after sending tbtc.
+      const [senderTbtcAfter, gatewayAfter] = await Promise.all([
+        getAccount(connection, senderToken),
+        getAccount(connection, gatewayWrappedTbtcToken),
+      ]);
+
+      // Check minted amounts.
+      const mintedAmountAfter = await wormholeGateway.getMintedAmount();
+      expect(mintedAmountAfter).to.equal(mintedAmountBefore - sendAmount);
+      const gatewayMintedAmountAfter =
+        await wormholeGateway.getGatewayMintedAmount(recipientChain);
+      expect(gatewayMintedAmountAfter).to.equal(
+        gatewayMintedAmountBefore - sendAmount
+      );
+
+      // The tBTC is burned through the TBTC program.
+      const { totalBurned } = await tbtc.getConfigData();
+      expect(BigInt(totalBurned.toString())).to.equal(
+        totalBurnedBefore + sendAmount
+      );
+
+      // Check balance change.
+      expect(senderTbtcAfter.amount).to.equal(
+        senderTbtcBefore.amount - sendAmount
+      );
+      expect(gatewayAfter.amount).to.equal(gatewayBefore.amount - sendAmount);
+    });
+
+    it("cannot send tbtc to gateway (insufficient wrapped balance)", async () => {
+      // Use common token account.
+      const sender = commonTokenOwner.publicKey;
+      const senderToken = getAssociatedTokenAddressSync(
+        tbtc.getMintPDA(),
+        sender
+      );
+
+      // Get destination gateway.
+      const recipientChain = 2;
+      // EVM address, left-padded to 32 bytes.
+      const recipient = Array.from(
+        Buffer.alloc(32, "deadbeef", "hex").fill(0, 0, 12)
+      );
+      const nonce = 420;
+
+      // Check token accounts.
+      const gatewayWrappedBalance = await getAccount(
+        connection,
+        gatewayWrappedTbtcToken
+      );
+
+      // Try an amount that won't work.
+      const sendAmount = gatewayWrappedBalance.amount + BigInt(69);
+      const ix = await wormholeGateway.sendTbtcGatewayIx(
+        {
+          senderToken,
+          sender,
+        },
+        {
+          amount: new anchor.BN(sendAmount.toString()),
+          recipientChain,
+          recipient,
+          nonce,
+        }
+      );
+      await expectIxFail([ix], [commonTokenOwner], "NotEnoughWrappedTbtc");
+    });
+
+    it("cannot send tbtc to gateway (gateway disabled)", async () => {
+      // Use common token account.
+      const sender = commonTokenOwner.publicKey;
+      const senderToken = getAssociatedTokenAddressSync(
+        tbtc.getMintPDA(),
+        sender
+      );
+
+      // Get destination gateway.
+      const recipientChain = 2;
+      // EVM address, left-padded to 32 bytes.
+      const recipient = Array.from(
+        Buffer.alloc(32, "deadbeef", "hex").fill(0, 0, 12)
+      );
+      const nonce = 420;
+
+      const disableIx = await wormholeGateway.updateGatewayDisabledIx(
+        {
+          gatewayManager: authority.publicKey,
+        },
+        { chain: recipientChain, disabled: true }
+      );
+      await expectIxSuccess([disableIx], [authority]);
+
+      const sendAmount = BigInt(2000);
+      const ix = await wormholeGateway.sendTbtcGatewayIx(
+        {
+          senderToken,
+          sender,
+        },
+        {
+          amount: new anchor.BN(sendAmount.toString()),
+          recipientChain,
+          recipient,
+          nonce,
+        }
+      );
+      await expectIxFail([ix], [commonTokenOwner], "GatewayDisabled");
+
+      const enableIx = await wormholeGateway.updateGatewayDisabledIx(
+        {
+          gatewayManager: authority.publicKey,
+        },
+        { chain: recipientChain, disabled: false }
+      );
+      await expectIxSuccess([enableIx], [authority]);
+    });
+
+    it("cannot send tbtc to gateway (zero amount)", async () => {
+      // Use common token account.
+      const sender = commonTokenOwner.publicKey;
+      const senderToken = getAssociatedTokenAddressSync(
+        tbtc.getMintPDA(),
+        sender
+      );
+
+      // Get destination gateway.
+      const recipientChain = 2;
+      // EVM address, left-padded to 32 bytes.
+      const recipient = Array.from(
+        Buffer.alloc(32, "deadbeef", "hex").fill(0, 0, 12)
+      );
+      const nonce = 420;
+
+      // Try an amount that won't work.
+      const sendAmount = BigInt(0);
+      const ix = await wormholeGateway.sendTbtcGatewayIx(
+        {
+          senderToken,
+          sender,
+        },
+        {
+          amount: new anchor.BN(sendAmount.toString()),
+          recipientChain,
+          recipient,
+          nonce,
+        }
+      );
+      await expectIxFail([ix], [commonTokenOwner], "ZeroAmount");
+    });
+
+    it("cannot send tbtc to gateway (recipient is zero address)", async () => {
+      // Use common token account.
+      const sender = commonTokenOwner.publicKey;
+      const senderToken = getAssociatedTokenAddressSync(
+        tbtc.getMintPDA(),
+        sender
+      );
+
+      // Get destination gateway.
+      const recipientChain = 2;
+      const recipient = Array.from(Buffer.alloc(32)); // empty buffer
+      const nonce = 420;
+
+      const sendAmount = BigInt(69);
+      const ix = await wormholeGateway.sendTbtcGatewayIx(
+        {
+          senderToken,
+          sender,
+        },
+        {
+          amount: new anchor.BN(sendAmount.toString()),
+          recipientChain,
+          recipient,
+          nonce,
+        }
+      );
+      await expectIxFail([ix], [commonTokenOwner], "ZeroRecipient");
+    });
+
+    it("cannot send tbtc to gateway (invalid target gateway)", async () => {
+      // Use common token account.
+      const sender = commonTokenOwner.publicKey;
+      const senderToken = getAssociatedTokenAddressSync(
+        tbtc.getMintPDA(),
+        sender
+      );
+
+      // Get destination gateway.
+      const recipientChain = 69; // bad gateway
+      const recipient = Array.from(Buffer.alloc(32)); // empty buffer
+      const nonce = 420;
+
+      const sendAmount = BigInt(69);
+      const ix = await wormholeGateway.sendTbtcGatewayIx(
+        {
+          senderToken,
+          sender,
+        },
+        {
+          amount: new anchor.BN(sendAmount.toString()),
+          recipientChain,
+          recipient,
+          nonce,
+        }
+      );
+      await expectIxFail([ix], [commonTokenOwner], "AccountNotInitialized");
+    });
+
+    it("cannot send tbtc to gateway (exceeds gateway minted amount)", async () => {
+      // Use common token account.
+      const sender = commonTokenOwner.publicKey;
+      const senderToken = getAssociatedTokenAddressSync(
+        tbtc.getMintPDA(),
+        sender
+      );
+
+      // Nothing has been minted for transfers from a new gateway's chain.
+      const recipientChain = 69;
+      const updateIx = await wormholeGateway.updateGatewayAddress(
+        {
+          gatewayManager: authority.publicKey,
+        },
+        {
+          chain: recipientChain,
+          address: Array.from(Buffer.alloc(32, "deadbeef", "hex")),
+        }
+      );
+      await expectIxSuccess([updateIx], [authority]);
+      expect(
+        await wormholeGateway.getGatewayMintedAmount(recipientChain)
+      ).to.equal(BigInt(0));
+
+      const recipient = Array.from(Buffer.alloc(32, "deadbeef", "hex"));
+      const nonce = 420;
+
+      const sendAmount = BigInt(69);
+      const ix = await wormholeGateway.sendTbtcGatewayIx(
+        {
+          senderToken,
+          sender,
+        },
+        {
+          amount: new anchor.BN(sendAmount.toString()),
+          recipientChain,
+          recipient,
+          nonce,
+        }
+      );
+      await expectIxFail(
+        [ix],
+        [commonTokenOwner],
+        "GatewayMintedAmountUnderflow"
+      );
+    });
+  });
+
+  describe("send wrapped tbtc", () => {
+    it("send wrapped tbtc", async () => {
+      // Use common token account.
+      const sender = commonTokenOwner.publicKey;
+      const senderToken = getAssociatedTokenAddressSync(
+        tbtc.getMintPDA(),
+        sender
+      );
+
+      // Check token accounts.
+      const [senderTbtcBefore, gatewayBefore] = await Promise.all([
+        getAccount(connection, senderToken),
+        getAccount(connection, gatewayWrappedTbtcToken),
+      ]);
+
+      // Check minted amount before.
+      const mintedAmountBefore = await wormholeGateway.getMintedAmount();
+
+      // Get destination gateway.
+      const recipientChain = 69;
+      const recipient = Array.from(Buffer.alloc(32, "deadbeef", "hex"));
+      const nonce = 420;
+
+      // This should work.
+      const sendAmount = BigInt(2000);
+      const ix = await wormholeGateway.sendTbtcWrappedIx(
+        {
+          senderToken,
+          sender,
+        },
+        {
+          amount: new anchor.BN(sendAmount.toString()),
+          recipientChain,
+          recipient,
+          arbiterFee: new anchor.BN(0),
+          nonce,
+        }
+      );
+      await expectIxSuccess([ix], [commonTokenOwner]);
+
+      // Check token accounts after sending tbtc.
+      const [senderTbtcAfter, gatewayAfter] = await Promise.all([
+        getAccount(connection, senderToken),
+        getAccount(connection, gatewayWrappedTbtcToken),
+      ]);
+
+      // Check minted amount.
+      const mintedAmountAfter = await wormholeGateway.getMintedAmount();
+      expect(mintedAmountAfter).to.equal(mintedAmountBefore - sendAmount);
+
+      // Check balance change.
+      expect(senderTbtcAfter.amount).to.equal(
+        senderTbtcBefore.amount - sendAmount
+      );
+      expect(gatewayAfter.amount).to.equal(gatewayBefore.amount - sendAmount);
+    });
+
+    it("cannot send wrapped tbtc (insufficient wrapped balance)", async () => {
+      // Use common token account.
+      const sender = commonTokenOwner.publicKey;
+      const senderToken = getAssociatedTokenAddressSync(
+        tbtc.getMintPDA(),
+        sender
+      );
+
+      // Get destination gateway.
+      const recipientChain = 2;
+      // EVM address, left-padded to 32 bytes.
+      const recipient = Array.from(
+        Buffer.alloc(32, "deadbeef", "hex").fill(0, 0, 12)
+      );
+      const nonce = 420;
+
+      // Check token accounts.
+      const gatewayWrappedBalance = await getAccount(
+        connection,
+        gatewayWrappedTbtcToken
+      );
+
+      // Try an amount that won't work.
+      const sendAmount = gatewayWrappedBalance.amount + BigInt(69);
+      const ix = await wormholeGateway.sendTbtcWrappedIx(
+        {
+          senderToken,
+          sender,
+        },
+        {
+          amount: new anchor.BN(sendAmount.toString()),
+          recipientChain,
+          recipient,
+          arbiterFee: new anchor.BN(0),
+          nonce,
+        }
+      );
+      await expectIxFail([ix], [commonTokenOwner], "NotEnoughWrappedTbtc");
+    });
+
+    it("cannot send wrapped tbtc(zero amount)", async () => {
+      // Use common token account.
+      const sender = commonTokenOwner.publicKey;
+      const senderToken = getAssociatedTokenAddressSync(
+        tbtc.getMintPDA(),
+        sender
+      );
+
+      // Get destination gateway.
+      const recipientChain = 2;
+      // EVM address, left-padded to 32 bytes.
+      const recipient = Array.from(
+        Buffer.alloc(32, "deadbeef", "hex").fill(0, 0, 12)
+      );
+      const nonce = 420;
+
+      // Try an amount that won't work.
+      const sendAmount = BigInt(0);
+      const ix = await wormholeGateway.sendTbtcWrappedIx(
+        {
+          senderToken,
+          sender,
+        },
+        {
+          amount: new anchor.BN(sendAmount.toString()),
+          recipientChain,
+          recipient,
+          arbiterFee: new anchor.BN(0),
+          nonce,
+        }
+      );
+      await expectIxFail([ix], [commonTokenOwner], "ZeroAmount");
+    });
+
+    it("cannot send wrapped tbtc (recipient is zero address)", async () => {
+      // Use common token account.
+      const sender = commonTokenOwner.publicKey;
+      const senderToken = getAssociatedTokenAddressSync(
+        tbtc.getMintPDA(),
+        sender
+      );
+
+      // Get destination gateway.
+      const recipientChain = 2;
+      const recipient = Array.from(Buffer.alloc(32)); // empty buffer
+      const nonce = 420;
+
+      const sendAmount = BigInt(69);
+      const ix = await wormholeGateway.sendTbtcWrappedIx(
+        {
+          senderToken,
+          sender,
+        },
+        {
+          amount: new anchor.BN(sendAmount.toString()),
+          recipientChain,
+          recipient,
+          arbiterFee: new anchor.BN(0),
+          nonce,
+        }
+      );
+      await expectIxFail([ix], [commonTokenOwner], "ZeroRecipient");
+    });
+
+    it("cannot send wrapped tbtc (chain not allowed)", async () => {
+      // Use common token account.
+      const sender = commonTokenOwner.publicKey;
+      const senderToken = getAssociatedTokenAddressSync(
+        tbtc.getMintPDA(),
+        sender
+      );
+
+      // Send to a chain without an allowlist entry.
+      const recipientChain = 70;
+      const recipient = Array.from(Buffer.alloc(32, "deadbeef", "hex"));
+      const nonce = 420;
+
+      const sendAmount = BigInt(69);
+      const ix = await wormholeGateway.sendTbtcWrappedIx(
+        {
+          senderToken,
+          sender,
+        },
+        {
+          amount: new anchor.BN(sendAmount.toString()),
+          recipientChain,
+          recipient,
+          arbiterFee: new anchor.BN(0),
+          nonce,
+        }
+      );
+      await expectIxFail([ix], [commonTokenOwner], "RecipientChainNotAllowed");
+    });
+
+    it("cannot send wrapped tbtc (invalid evm recipient)", async () => {
+      // Use common token account.
+      const sender = commonTokenOwner.publicKey;
+      const senderToken = getAssociatedTokenAddressSync(
+        tbtc.getMintPDA(),
+        sender
+      );
+
+      // Recipient is not a left-padded 20-byte address.
+      const recipientChain = 2;
+      const recipient = Array.from(Buffer.alloc(32, "deadbeef", "hex"));
+      const nonce = 420;
+
+      const sendAmount = BigInt(69);
+      const ix = await wormholeGateway.sendTbtcWrappedIx(
+        {
+          senderToken,
+          sender,
+        },
+        {
+          amount: new anchor.BN(sendAmount.toString()),
+          recipientChain,
+          recipient,
+          arbiterFee: new anchor.BN(0),
+          nonce,
+        }
+      );
+      await expectIxFail([ix], [commonTokenOwner], "InvalidEvmRecipient");
+    });
+  });
+
+  describe("send tbtc with payload", () => {
+    // EVM address, left-padded to 32 bytes.
+    const l1Redeemer = Array.from(
+      Buffer.alloc(32, "deadbeef", "hex").fill(0, 0, 12)
+    );
+    const payloadSender = anchor.web3.Keypair.generate();
+
+    it("cannot send tbtc with payload (l1 redeemer not set)", async () => {
+      // Use common token account.
+      const sender = commonTokenOwner.publicKey;
+      const senderToken = getAssociatedTokenAddressSync(
+        tbtc.getMintPDA(),
+        sender
+      );
+
+      const ix = await wormholeGateway.sendTbtcWithPayloadIx(
+        {
+          senderToken,
+          sender,
+          payloadSender: payloadSender.publicKey,
+        },
+        {
+          amount: new anchor.BN(1000),
+          nonce: 420,
+          payload: Buffer.from("deadbeef", "hex"),
+        }
+      );
+      await expectIxFail(
+        [ix],
+        [commonTokenOwner, payloadSender],
+        "L1RedeemerNotSet"
+      );
+    });
+
+    it("cannot update l1 redeemer (not gateway manager)", async () => {
+      const failingIx = await wormholeGateway.updateL1RedeemerIx(
+        {
+          gatewayManager: imposter.publicKey,
+        },
+        l1Redeemer
+      );
+      await expectIxFail([failingIx], [imposter], "AccountNotInitialized");
+    });
+
+    it("update l1 redeemer", async () => {
+      const ix = await wormholeGateway.updateL1RedeemerIx(
+        {
+          gatewayManager: authority.publicKey,
+        },
+        l1Redeemer
+      );
+      await expectIxSuccess([ix], [authority]);
+
+      const custodianState = await wormholeGateway.getCustodianData();
+      expect(custodianState.l1Redeemer).to.eql(l1Redeemer);
+    });
+
+    it("cannot send tbtc with payload (not payload sender)", async () => {
+      // Use common token account.
+      const sender = commonTokenOwner.publicKey;
+      const senderToken = getAssociatedTokenAddressSync(
+        tbtc.getMintPDA(),
+        sender
+      );
+
+      // Only the BTC Redeemer's payload sender can send to the L1 redeemer.
+      const ix = await wormholeGateway.sendTbtcWithPayloadIx(
+        {
+          senderToken,
+          sender,
+          payloadSender: sender,
+        },
+        {
+          amount: new anchor.BN(1000),
+          nonce: 420,
+          payload: Buffer.from("deadbeef", "hex"),
+        }
+      );
+      await expectIxFail([ix], [commonTokenOwner], "IsNotPayloadSender");
+    });
+
+    it("cannot update payload sender (not gateway manager)", async () => {
+      const failingIx = await wormholeGateway.updatePayloadSenderIx(
+        {
+          gatewayManager: imposter.publicKey,
+        },
+        payloadSender.publicKey
+      );
+      await expectIxFail([failingIx], [imposter], "AccountNotInitialized");
+    });
+
+    it("update payload sender", async () => {
+      const ix = await wormholeGateway.updatePayloadSenderIx(
+        {
+          gatewayManager: authority.publicKey,
+        },
+        payloadSender.publicKey
+      );
+      await expectIxSuccess([ix], [authority]);
+
+      const custodianState = await wormholeGateway.getCustodianData();
+      expect(custodianState.payloadSender).to.eql(payloadSender.publicKey);
+    });
+
+    it("send tbtc with payload", async () => {
+      // Use common token account.
+      const sender = commonTokenOwner.publicKey;
+      const senderToken = getAssociatedTokenAddressSync(
+        tbtc.getMintPDA(),
+        sender
+      );
+
+      // Check token accounts.
+      const [senderTbtcBefore, gatewayBefore] = await Promise.all([
+        getAccount(connection, senderToken),
+        getAccount(connection, gatewayWrappedTbtcToken),
+      ]);
+
+      // Check minted amount before.
+      const mintedAmountBefore = await wormholeGateway.getMintedAmount();
+
+      // Redeem to a Bitcoin output script.
+      const payload = Buffer.from(
+        "0014deadbeefdeadbeefdeadbeefdeadbeefdeadbeef",
+        "hex"
+      );
+      const sendAmount = BigInt(1000);
+      const ix = await wormholeGateway.sendTbtcWithPayloadIx(
+        {
+          senderToken,
+          sender,
+          payloadSender: payloadSender.publicKey,
+        },
+        {
+          amount: new anchor.BN(sendAmount.toString()),
+          nonce: 420,
+          payload,
+        }
+      );
+      await expectIxSuccess([ix], [commonTokenOwner, payloadSender]);
+
+      // Check token accounts after sending tbtc.
+      const [senderTbtcAfter, gatewayAfter] = await Promise.all([
+        getAccount(connection, senderToken),
+        getAccount(connection, gatewayWrappedTbtcToken),
+      ]);
+
+      // Check minted amount.
+      const mintedAmountAfter = await wormholeGateway.getMintedAmount();
+      expect(mintedAmountAfter).to.equal(mintedAmountBefore - sendAmount);
+
+      // Check balance change.
+      expect(senderTbtcAfter.amount).to.equal(
+        senderTbtcBefore.amount - sendAmount
+      );
+      expect(gatewayAfter.amount).to.equal(gatewayBefore.amount - sendAmount);
+    });
+
+    it("cannot send tbtc with payload (zero amount)", async () => {
+      // Use common token account.
+      const sender = commonTokenOwner.publicKey;
+      const senderToken = getAssociatedTokenAddressSync(
+        tbtc.getMintPDA(),
+        sender
+      );
+
+      const ix = await wormholeGateway.sendTbtcWithPayloadIx(
+        {
+          senderToken,
+          sender,
+          payloadSender: payloadSender.publicKey,
+        },
+        {
+          amount: new anchor.BN(0),
+          nonce: 420,
+          payload: Buffer.from("deadbeef", "hex"),
+        }
+      );
+      await expectIxFail([ix], [commonTokenOwner, payloadSender], "ZeroAmount");
+    });
+  });
+
+  describe("receive tbtc and call", () => {
+    const sender = Buffer.alloc(32, "deadbeef", "hex").fill(0, 0, 12);
+    const message = Buffer.from("deadbeef", "hex");
+
+    it("cannot add call target (not authority)", async () => {
+      const failingIx = await wormholeGateway.addCallTargetIx({
+        program: TBTC_PROGRAM_ID,
+        authority: imposter.publicKey,
+      });
+      await expectIxFail([failingIx], [imposter], "IsNotAuthority");
+    });
+
+    it("add call target", async () => {
+      const ix = await wormholeGateway.addCallTargetIx({
+        program: TBTC_PROGRAM_ID,
+        authority: authority.publicKey,
+      });
+      await expectIxSuccess([ix], [authority]);
+
+      const callTarget = await program.account.callTarget.fetch(
+        wormholeGateway.getCallTargetPDA(TBTC_PROGRAM_ID)
+      );
+      expect(callTarget.program).to.eql(TBTC_PROGRAM_ID);
+    });
+
+    it("remove call target", async () => {
+      const addIx = await wormholeGateway.addCallTargetIx({
+        program: BTC_REDEEMER_PROGRAM_ID,
+        authority: authority.publicKey,
+      });
+      await expectIxSuccess([addIx], [authority]);
+
+      const failingIx = await wormholeGateway.removeCallTargetIx({
+        program: BTC_REDEEMER_PROGRAM_ID,
+        authority: imposter.publicKey,
+      });
+      await expectIxFail([failingIx], [imposter], "IsNotAuthority");
+
+      const ix = await wormholeGateway.removeCallTargetIx({
+        program: BTC_REDEEMER_PROGRAM_ID,
+        authority: authority.publicKey,
+      });
+      await expectIxSuccess([ix], [authority]);
+
+      const callTarget = await program.account.callTarget.fetchNullable(
+        wormholeGateway.getCallTargetPDA(BTC_REDEEMER_PROGRAM_ID)
+      );
+      expect(callTarget).to.be.null;
+    });
+
+    it("cannot receive tbtc and call (target not allowed)", async () => {
+      // Set up new wallet
+      const payer = await generatePayer(authority);
+
+      // Use common token account.
+      const recipient = commonTokenOwner.publicKey;
+      const recipientToken = getAssociatedTokenAddressSync(
+        tbtc.getMintPDA(),
+        recipient
+      );
+
+      // Get foreign gateway.
+      const fromGateway = await wormholeGateway
+        .getGatewayInfo(2)
+        .then((info) => info.address);
+
+      const signedVaa = await ethereumGatewaySendTbtcWithPayload(
+        payer,
+        ethereumTokenBridge,
+        BigInt(1000),
+        fromGateway,
+        WORMHOLE_GATEWAY_PROGRAM_ID,
+        wormholeGateway.encodeReceiveAndCallPayload({
+          targetProgram: BTC_REDEEMER_PROGRAM_ID,
+          recipient,
+          sender,
+          message,
+        })
+      );
+
+      const failingIx = await wormholeGateway.receiveTbtcAndCallIx(
+        {
+          payer: payer.publicKey,
+          recipientToken,
+          recipient,
+          targetProgram: BTC_REDEEMER_PROGRAM_ID,
+        },
+        signedVaa
+      );
+      await expectIxFail([failingIx], [payer], "AccountNotInitialized");
+    });
+
+    it("cannot receive tbtc and call (unsupported payload version)", async () => {
+      // Set up new wallet
+      const payer = await generatePayer(authority);
+
+      // Use common token account.
+      const recipient = commonTokenOwner.publicKey;
+      const recipientToken = getAssociatedTokenAddressSync(
+        tbtc.getMintPDA(),
+        recipient
+      );
+
+      // Get foreign gateway.
+      const fromGateway = await wormholeGateway
+        .getGatewayInfo(2)
+        .then((info) => info.address);
+
+      const signedVaa = await ethereumGatewaySendTbtcWithPayload(
+        payer,
+        ethereumTokenBridge,
+        BigInt(1000),
+        fromGateway,
+        WORMHOLE_GATEWAY_PROGRAM_ID,
+        wormholeGateway.encodeReceiveAndCallPayload(
+          {
+            targetProgram: TBTC_PROGRAM_ID,
+            recipient,
+            sender,
+            message,
+          },
+          1
+        )
+      );
+
+      const failingIx = await wormholeGateway.receiveTbtcAndCallIx(
+        {
+          payer: payer.publicKey,
+          recipientToken,
+          recipient,
+          targetProgram: TBTC_PROGRAM_ID,
+        },
+        signedVaa
+      );
+      await expectIxFail([failingIx], [payer], "UnsupportedPayloadVersion");
+    });
+
+    it("cannot receive tbtc and call (minting limit exceeded)", async () => {
+      // Set up new wallet
+      const payer = await generatePayer(authority);
+
+      // Use common token account.
+      const recipient = commonTokenOwner.publicKey;
+      const recipientToken = getAssociatedTokenAddressSync(
+        tbtc.getMintPDA(),
+        recipient
+      );
+
+      // Get foreign gateway.
+      const fromGateway = await wormholeGateway
+        .getGatewayInfo(2)
+        .then((info) => info.address);
+
+      const signedVaa = await ethereumGatewaySendTbtcWithPayload(
+        payer,
+        ethereumTokenBridge,
+        BigInt(1000),
+        fromGateway,
+        WORMHOLE_GATEWAY_PROGRAM_ID,
+        wormholeGateway.encodeReceiveAndCallPayload({
+          targetProgram: TBTC_PROGRAM_ID,
+          recipient,
+          sender,
+          message,
+        })
+      );
+
+      // Leave less room than the sent amount.
+      const mintedAmount = await wormholeGateway.getMintedAmount();
+      const updateLimitIx = await wormholeGateway.updateMintingLimitIx(
+        {
+          limitManager: authority.publicKey,
+        },
+        mintedAmount + BigInt(999)
+      );
+      await expectIxSuccess([updateLimitIx], [authority]);
+
+      const failingIx = await wormholeGateway.receiveTbtcAndCallIx(
+        {
+          payer: payer.publicKey,
+          recipientToken,
+          recipient,
+          targetProgram: TBTC_PROGRAM_ID,
+        },
+        signedVaa
+      );
+      await expectIxFail([failingIx], [payer], "MintingLimitExceeded");
+    });
+
+    it("receive tbtc and call (target rejects call)", async () => {
+      // Set up new wallet
+      const payer = await generatePayer(authority);
+
+      // Use common token account.
+      const recipient = commonTokenOwner.publicKey;
+      const recipientToken = getAssociatedTokenAddressSync(
+        tbtc.getMintPDA(),
+        recipient
+      );
+
+      // Get foreign gateway.
+      const fromGateway = await wormholeGateway
+        .getGatewayInfo(2)
+        .then((info) => info.address);
+
+      const sentAmount = BigInt(1000);
+      const signedVaa = await ethereumGatewaySendTbtcWithPayload(
+        payer,
+        ethereumTokenBridge,
+        sentAmount,
+        fromGateway,
+        WORMHOLE_GATEWAY_PROGRAM_ID,
+        wormholeGateway.encodeReceiveAndCallPayload({
+          targetProgram: TBTC_PROGRAM_ID,
+          recipient,
+          sender,
+          message,
+        })
+      );
+
+      // Make room for minting the received tBTC.
+      const mintedAmount = await wormholeGateway.getMintedAmount();
+      const updateLimitIx = await wormholeGateway.updateMintingLimitIx(
+        {
+          limitManager: authority.publicKey,
+        },
+        mintedAmount + sentAmount
+      );
+      await expectIxSuccess([updateLimitIx], [authority]);
+
+      // The tBTC program does not implement `on_tbtc_received`, so the whole
+      // transfer is reverted once the gateway calls it after minting.
+      const ix = await wormholeGateway.receiveTbtcAndCallIx(
+        {
+          payer: payer.publicKey,
+          recipientToken,
+          recipient,
+          targetProgram: TBTC_PROGRAM_ID,
+        },
+        signedVaa
+      );
+      await expectIxFail([ix], [payer], "InstructionFallbackNotFound");
+    });
+
+    it("cannot receive tbtc without call (not recipient or authority)", async () => {
+      // Use common token account.
+      const recipient = commonTokenOwner.publicKey;
+      const recipientToken = getAssociatedTokenAddressSync(
+        tbtc.getMintPDA(),
+        recipient
+      );
+
+      // Get foreign gateway.
+      const fromGateway = await wormholeGateway
+        .getGatewayInfo(2)
+        .then((info) => info.address);
+
+      const signedVaa = await ethereumGatewaySendTbtcWithPayload(
+        imposter,
+        ethereumTokenBridge,
+        BigInt(1000),
+        fromGateway,
+        WORMHOLE_GATEWAY_PROGRAM_ID,
+        wormholeGateway.encodeReceiveAndCallPayload({
+          targetProgram: TBTC_PROGRAM_ID,
+          recipient,
+          sender,
+          message,
+        })
+      );
+
+      const failingIx = await wormholeGateway.receiveTbtcWithoutCallIx(
+        {
+          payer: imposter.publicKey,
+          recipientToken,
+          recipient,
+        },
+        signedVaa
+      );
+      await expectIxFail(
+        [failingIx],
+        [imposter],
+        "IsNotRecipientOrAuthority"
+      );
+    });
+
+    it("receive tbtc without call (as recipient)", async () => {
+      // Use common token account.
+      const recipient = commonTokenOwner.publicKey;
+      const recipientToken = getAssociatedTokenAddressSync(
+        tbtc.getMintPDA(),
+        recipient
+      );
+
+      // Get foreign gateway.
+      const fromGateway = await wormholeGateway
+        .getGatewayInfo(2)
+        .then((info) => info.address);
+
+      const sentAmount = BigInt(1000);
+      const signedVaa = await ethereumGatewaySendTbtcWithPayload(
+        commonTokenOwner,
+        ethereumTokenBridge,
+        sentAmount,
+        fromGateway,
+        WORMHOLE_GATEWAY_PROGRAM_ID,
+        wormholeGateway.encodeReceiveAndCallPayload({
+          targetProgram: TBTC_PROGRAM_ID,
+          recipient,
+          sender,
+          message,
+        })
+      );
+
+      // Make room for minting the received tBTC.
+      const mintedAmount = await wormholeGateway.getMintedAmount();
+      const updateLimitIx = await wormholeGateway.updateMintingLimitIx(
+        {
+          limitManager: authority.publicKey,
+        },
+        mintedAmount + sentAmount
+      );
+      await expectIxSuccess([updateLimitIx], [authority]);
+
+      const tbtcBefore = await getTokenBalance(recipientToken);
+
+      // The tBTC program rejects the call, so the recipient takes the tBTC
+      // without it.
+      const ix = await wormholeGateway.receiveTbtcWithoutCallIx(
+        {
+          payer: recipient,
+          recipientToken,
+          recipient,
+        },
+        signedVaa
+      );
+      await expectIxSuccess([ix], [commonTokenOwner]);
+
+      const tbtcAfter = await getTokenBalance(recipientToken);
+      expect(tbtcAfter).to.equal(tbtcBefore + sentAmount);
+    });
+
+    it("receive tbtc without call (target not allowed)", async () => {
+      // Use common token account.
+      const recipient = commonTokenOwner.publicKey;
+      const recipientToken = getAssociatedTokenAddressSync(
+        tbtc.getMintPDA(),
+        recipient
+      );
+
+      // Get foreign gateway.
+      const fromGateway = await wormholeGateway
+        .getGatewayInfo(2)
+        .then((info) => info.address);
+
+      const sentAmount = BigInt(1000);
+      const signedVaa = await ethereumGatewaySendTbtcWithPayload(
+        authority,
+        ethereumTokenBridge,
+        sentAmount,
+        fromGateway,
+        WORMHOLE_GATEWAY_PROGRAM_ID,
+        wormholeGateway.encodeReceiveAndCallPayload({
+          targetProgram: BTC_REDEEMER_PROGRAM_ID,
+          recipient,
+          sender,
+          message,
+        })
+      );
+
+      // Make room for minting the received tBTC.
+      const mintedAmount = await wormholeGateway.getMintedAmount();
+      const updateLimitIx = await wormholeGateway.updateMintingLimitIx(
+        {
+          limitManager: authority.publicKey,
+        },
+        mintedAmount + sentAmount
+      );
+      await expectIxSuccess([updateLimitIx], [authority]);
+
+      const tbtcBefore = await getTokenBalance(recipientToken);
+
+      // The target was removed, so the authority redeems the transfer.
+      const ix = await wormholeGateway.receiveTbtcWithoutCallIx(
+        {
+          payer: authority.publicKey,
+          recipientToken,
+          recipient,
+        },
+        signedVaa
+      );
+      const txSig = await expectIxSuccess([ix], [authority]);
+
+      const tbtcAfter = await getTokenBalance(recipientToken);
+      expect(tbtcAfter).to.equal(tbtcBefore + sentAmount);
+
+      const [event] = await getTxEvents(program, txSig).then((events) =>
+        events.filter((e) => e.name === "WormholeTbtcReceivedWithoutCall")
+      );
+      expect(event.data.receiver).to.eql(recipient);
+      expect(event.data.amount.toString()).to.equal(sentAmount.toString());
+      expect(event.data.targetProgram).to.eql(BTC_REDEEMER_PROGRAM_ID);
+    });
+
+    it("receive tbtc without call (pda recipient)", async () => {
+      // The recipient is a PDA of the target program, which cannot sign.
+      const [recipient] = PublicKey.findProgramAddressSync(
+        [Buffer.from("recipient")],
+        BTC_REDEEMER_PROGRAM_ID
+      );
+      const recipientToken = await getOrCreateAta(
+        authority,
+        tbtc.getMintPDA(),
+        recipient,
+        true
+      );
+
+      // Get foreign gateway.
+      const fromGateway = await wormholeGateway
+        .getGatewayInfo(2)
+        .then((info) => info.address);
+
+      const sentAmount = BigInt(1000);
+      const signedVaa = await ethereumGatewaySendTbtcWithPayload(
+        authority,
+        ethereumTokenBridge,
+        sentAmount,
+        fromGateway,
+        WORMHOLE_GATEWAY_PROGRAM_ID,
+        wormholeGateway.encodeReceiveAndCallPayload({
+          targetProgram: BTC_REDEEMER_PROGRAM_ID,
+          recipient,
+          sender,
+          message,
+        })
+      );
+
+      // Make room for minting the received tBTC.
+      const mintedAmount = await wormholeGateway.getMintedAmount();
+      const updateLimitIx = await wormholeGateway.updateMintingLimitIx(
+        {
+          limitManager: authority.publicKey,
+        },
+        mintedAmount + sentAmount
+      );
+      await expectIxSuccess([updateLimitIx], [authority]);
+
+      // Anyone else but the authority is rejected.
+      const failingIx = await wormholeGateway.receiveTbtcWithoutCallIx(
+        {
+          payer: imposter.publicKey,
+          recipientToken,
+          recipient,
+        },
+        signedVaa
+      );
+      await expectIxFail(
+        [failingIx],
+        [imposter],
+        "IsNotRecipientOrAuthority"
+      );
+
+      const tbtcBefore = await getTokenBalance(recipientToken);
+
+      // Only the authority can recover the transfer.
+      const ix = await wormholeGateway.receiveTbtcWithoutCallIx(
+        {
+          payer: authority.publicKey,
+          recipientToken,
+          recipient,
+        },
+        signedVaa
+      );
+      await expectIxSuccess([ix], [authority]);
+
+      const tbtcAfter = await getTokenBalance(recipientToken);
+      expect(tbtcAfter).to.equal(tbtcBefore + sentAmount);
+    });
+  });
+
+  describe("pause", () => {
+    it("cannot pause (not a guardian)", async () => {
+      const failingIx = await wormholeGateway.pauseIx({
+        guardian: imposter.publicKey,
+      });
+      await expectIxFail([failingIx], [imposter], "AccountNotInitialized");
     });
 
-    it("cannot send tbtc to gateway (zero amount)", async () => {
-      // Use common token account.
-      const sender = commonTokenOwner.publicKey;
-      const senderToken = getAssociatedTokenAddressSync(
-        tbtc.getMintPDA(),
-        sender
+    it("pause as tbtc guardian", async () => {
+      const addGuardianIx = await tbtc.addGuardianIx({
+        guardianAdmin: authority.publicKey,
+        guardian: guardianKeys.publicKey,
+      });
+      await expectIxSuccess([addGuardianIx], [authority]);
+
+      const ix = await wormholeGateway.pauseIx({
+        guardian: guardianKeys.publicKey,
+      });
+      await expectIxSuccess([ix], [txPayer, guardianKeys]);
+
+      const custodianState = await wormholeGateway.getCustodianData();
+      expect(custodianState.paused).to.be.true;
+    });
+
+    it("cannot pause (already paused)", async () => {
+      const failingIx = await wormholeGateway.pauseIx({
+        guardian: guardianKeys.publicKey,
+      });
+      await expectIxFail([failingIx], [txPayer, guardianKeys], "IsPaused");
+    });
+
+    it("cannot deposit wrapped tbtc (paused)", async () => {
+      const payer = await generatePayer(authority);
+
+      const recipientWrappedToken = await preloadWrappedTbtc(
+        payer,
+        ethereumTokenBridge,
+        BigInt("100000000000"),
+        payer.publicKey
       );
 
-      // Get destination gateway.
-      const recipientChain = 2;
-      const recipient = Array.from(Buffer.alloc(32, "deadbeef", "hex"));
-      const nonce = 420;
+      const recipientToken = await getOrCreateAta(
+        payer,
+        tbtcMint,
+        payer.publicKey
+      );
 
-      // Try an amount that won't work.
-      const sendAmount = BigInt(0);
-      const ix = await wormholeGateway.sendTbtcGatewayIx(
+      const ix = await wormholeGateway.depositWormholeTbtcIx(
         {
-          senderToken,
-          sender,
+          recipientWrappedToken,
+          recipientToken,
+          recipient: payer.publicKey,
         },
-        {
-          amount: new anchor.BN(sendAmount.toString()),
-          recipientChain,
-          recipient,
-          nonce,
-        }
+        BigInt(500)
       );
-      await expectIxFail([ix], [commonTokenOwner], "ZeroAmount");
+      await expectIxFail([ix], [payer], "IsPaused");
     });
 
-    it("cannot send tbtc to gateway (recipient is zero address)", async () => {
-      // Use common token account.
-      const sender = commonTokenOwner.publicKey;
-      const senderToken = getAssociatedTokenAddressSync(
+    it("cannot receive tbtc (paused)", async () => {
+      const payer = await generatePayer(authority);
+
+      const recipient = commonTokenOwner.publicKey;
+      const recipientToken = getAssociatedTokenAddressSync(
         tbtc.getMintPDA(),
-        sender
+        recipient
       );
 
-      // Get destination gateway.
-      const recipientChain = 2;
-      const recipient = Array.from(Buffer.alloc(32)); // empty buffer
-      const nonce = 420;
+      const fromGateway = await wormholeGateway
+        .getGatewayInfo(2)
+        .then((info) => info.address);
 
-      const sendAmount = BigInt(69);
-      const ix = await wormholeGateway.sendTbtcGatewayIx(
-        {
-          senderToken,
-          sender,
-        },
+      const signedVaa = await ethereumGatewaySendTbtc(
+        payer,
+        ethereumTokenBridge,
+        BigInt(5000),
+        fromGateway,
+        WORMHOLE_GATEWAY_PROGRAM_ID,
+        recipient
+      );
+
+      const ix = await wormholeGateway.receiveTbtcIx(
         {
-          amount: new anchor.BN(sendAmount.toString()),
-          recipientChain,
+          payer: payer.publicKey,
+          recipientToken,
           recipient,
-          nonce,
-        }
+        },
+        signedVaa
       );
-      await expectIxFail([ix], [commonTokenOwner], "ZeroRecipient");
+      await expectIxFail([ix], [payer], "IsPaused");
     });
 
-    it("cannot send tbtc to gateway (invalid target gateway)", async () => {
-      // Use common token account.
+    it("cannot send tbtc (paused)", async () => {
       const sender = commonTokenOwner.publicKey;
       const senderToken = getAssociatedTokenAddressSync(
         tbtc.getMintPDA(),
         sender
       );
 
-      // Get destination gateway.
-      const recipientChain = 69; // bad gateway
-      const recipient = Array.from(Buffer.alloc(32)); // empty buffer
-      const nonce = 420;
+      const recipient = Array.from(Buffer.alloc(32, "deadbeef", "hex"));
+      const amount = new anchor.BN(69);
 
-      const sendAmount = BigInt(69);
-      const ix = await wormholeGateway.sendTbtcGatewayIx(
+      const gatewayIx = await wormholeGateway.sendTbtcGatewayIx(
         {
           senderToken,
           sender,
         },
         {
-          amount: new anchor.BN(sendAmount.toString()),
-          recipientChain,
+          amount,
+          recipientChain: 2,
           recipient,
-          nonce,
+          nonce: 0,
         }
       );
-      await expectIxFail([ix], [commonTokenOwner], "AccountNotInitialized");
-    });
-  });
-
-  describe("send wrapped tbtc", () => {
-    it("send wrapped tbtc", async () => {
-      // Use common token account.
-      const sender = commonTokenOwner.publicKey;
-      const senderToken = getAssociatedTokenAddressSync(
-        tbtc.getMintPDA(),
-        sender
-      );
-
-      // Check token accounts.
-      const [senderTbtcBefore, gatewayBefore] = await Promise.all([
-        getAccount(connection, senderToken),
-        getAccount(connection, gatewayWrappedTbtcToken),
-      ]);
-
-      // Check minted amount before.
-      const mintedAmountBefore = await wormholeGateway.getMintedAmount();
-
-      // Get destination gateway.
-      const recipientChain = 69;
-      const recipient = Array.from(Buffer.alloc(32, "deadbeef", "hex"));
-      const nonce = 420;
+      await expectIxFail([gatewayIx], [commonTokenOwner], "IsPaused");
 
-      // This should work.
-      const sendAmount = BigInt(2000);
-      const ix = await wormholeGateway.sendTbtcWrappedIx(
+      const wrappedIx = await wormholeGateway.sendTbtcWrappedIx(
         {
           senderToken,
           sender,
         },
         {
-          amount: new anchor.BN(sendAmount.toString()),
-          recipientChain,
+          amount,
+          recipientChain: 69,
           recipient,
           arbiterFee: new anchor.BN(0),
-          nonce,
+          nonce: 0,
         }
       );
-      await expectIxSuccess([ix], [commonTokenOwner]);
+      await expectIxFail([wrappedIx], [commonTokenOwner], "IsPaused");
+    });
 
-      // Check token accounts after sending tbtc.
-      const [senderTbtcAfter, gatewayAfter] = await Promise.all([
-        getAccount(connection, senderToken),
-        getAccount(connection, gatewayWrappedTbtcToken),
-      ]);
+    it("cannot unpause (not unpauser)", async () => {
+      const failingIx = await wormholeGateway.unpauseIx({
+        unpauser: imposter.publicKey,
+      });
+      await expectIxFail([failingIx], [imposter], "AccountNotInitialized");
+    });
 
-      // Check minted amount.
-      const mintedAmountAfter = await wormholeGateway.getMintedAmount();
-      expect(mintedAmountAfter).to.equal(mintedAmountBefore - sendAmount);
+    it("unpause", async () => {
+      const ix = await wormholeGateway.unpauseIx({
+        unpauser: authority.publicKey,
+      });
+      await expectIxSuccess([ix], [authority]);
 
-      // Check balance change.
-      expect(senderTbtcAfter.amount).to.equal(
-        senderTbtcBefore.amount - sendAmount
-      );
-      expect(gatewayAfter.amount).to.equal(gatewayBefore.amount - sendAmount);
-    });
+      const custodianState = await wormholeGateway.getCustodianData();
+      expect(custodianState.paused).to.be.false;
 
-    it("cannot send wrapped tbtc (insufficient wrapped balance)", async () => {
-      // Use common token account.
-      const sender = commonTokenOwner.publicKey;
-      const senderToken = getAssociatedTokenAddressSync(
-        tbtc.getMintPDA(),
-        sender
-      );
+      const removeGuardianIx = await tbtc.removeGuardianIx({
+        guardianAdmin: authority.publicKey,
+        guardian: guardianKeys.publicKey,
+      });
+      await expectIxSuccess([removeGuardianIx], [authority]);
+    });
 
-      // Get destination gateway.
-      const recipientChain = 2;
-      const recipient = Array.from(Buffer.alloc(32, "deadbeef", "hex"));
-      const nonce = 420;
+    it("cannot unpause (not paused)", async () => {
+      const failingIx = await wormholeGateway.unpauseIx({
+        unpauser: authority.publicKey,
+      });
+      await expectIxFail([failingIx], [authority], "IsNotPaused");
+    });
+  });
 
-      // Check token accounts.
-      const gatewayWrappedBalance = await getAccount(
-        connection,
-        gatewayWrappedTbtcToken
+  describe("canonical token", () => {
+    it("cannot request canonical token update (not authority)", async () => {
+      const failingIx = await wormholeGateway.requestCanonicalTokenUpdateIx(
+        {
+          authority: imposter.publicKey,
+        },
+        wormholeGateway.ETHEREUM_TBTC_TOKEN
       );
+      await expectIxFail([failingIx], [imposter], "IsNotAuthority");
+    });
 
-      // Try an amount that won't work.
-      const sendAmount = gatewayWrappedBalance.amount + BigInt(69);
-      const ix = await wormholeGateway.sendTbtcWrappedIx(
+    it("cannot update canonical token (no pending update)", async () => {
+      const failingIx = await wormholeGateway.updateCanonicalTokenIx(
         {
-          senderToken,
-          sender,
+          authority: authority.publicKey,
+          newWrappedTbtcMint: WRAPPED_TBTC_MINT,
         },
-        {
-          amount: new anchor.BN(sendAmount.toString()),
-          recipientChain,
-          recipient,
-          arbiterFee: new anchor.BN(0),
-          nonce,
-        }
+        wormholeGateway.ETHEREUM_TBTC_TOKEN
+      );
+      await expectIxFail(
+        [failingIx],
+        [authority],
+        "NoPendingCanonicalTokenUpdate"
       );
-      await expectIxFail([ix], [commonTokenOwner], "NotEnoughWrappedTbtc");
     });
 
-    it("cannot send wrapped tbtc(zero amount)", async () => {
-      // Use common token account.
-      const sender = commonTokenOwner.publicKey;
-      const senderToken = getAssociatedTokenAddressSync(
-        tbtc.getMintPDA(),
-        sender
+    it("request canonical token update", async () => {
+      const ix = await wormholeGateway.requestCanonicalTokenUpdateIx(
+        {
+          authority: authority.publicKey,
+        },
+        wormholeGateway.ETHEREUM_TBTC_TOKEN
       );
+      await expectIxSuccess([ix], [authority]);
 
-      // Get destination gateway.
-      const recipientChain = 2;
-      const recipient = Array.from(Buffer.alloc(32, "deadbeef", "hex"));
-      const nonce = 420;
+      const { pendingCanonicalToken } =
+        await wormholeGateway.getCustodianData();
+      expect(pendingCanonicalToken).to.eql(wormholeGateway.ETHEREUM_TBTC_TOKEN);
+    });
 
-      // Try an amount that won't work.
-      const sendAmount = BigInt(0);
-      const ix = await wormholeGateway.sendTbtcWrappedIx(
+    it("cannot update canonical token (custody not empty)", async () => {
+      const failingIx = await wormholeGateway.updateCanonicalTokenIx(
         {
-          senderToken,
-          sender,
+          authority: authority.publicKey,
+          newWrappedTbtcMint: WRAPPED_TBTC_MINT,
         },
-        {
-          amount: new anchor.BN(sendAmount.toString()),
-          recipientChain,
-          recipient,
-          arbiterFee: new anchor.BN(0),
-          nonce,
-        }
+        wormholeGateway.ETHEREUM_TBTC_TOKEN
       );
-      await expectIxFail([ix], [commonTokenOwner], "ZeroAmount");
+      await expectIxFail([failingIx], [authority], "CustodyNotEmpty");
     });
 
-    it("cannot send wrapped tbtc (recipient is zero address)", async () => {
-      // Use common token account.
-      const sender = commonTokenOwner.publicKey;
-      const senderToken = getAssociatedTokenAddressSync(
-        tbtc.getMintPDA(),
-        sender
-      );
+    it("cancel canonical token update", async () => {
+      const ix = await wormholeGateway.cancelCanonicalTokenUpdateIx({
+        authority: authority.publicKey,
+      });
+      await expectIxSuccess([ix], [authority]);
 
-      // Get destination gateway.
-      const recipientChain = 2;
-      const recipient = Array.from(Buffer.alloc(32)); // empty buffer
-      const nonce = 420;
+      const { pendingCanonicalToken } =
+        await wormholeGateway.getCustodianData();
+      expect(pendingCanonicalToken).to.be.null;
 
-      const sendAmount = BigInt(69);
-      const ix = await wormholeGateway.sendTbtcWrappedIx(
-        {
-          senderToken,
-          sender,
-        },
-        {
-          amount: new anchor.BN(sendAmount.toString()),
-          recipientChain,
-          recipient,
-          arbiterFee: new anchor.BN(0),
-          nonce,
-        }
+      const failingIx = await wormholeGateway.cancelCanonicalTokenUpdateIx({
+        authority: authority.publicKey,
+      });
+      await expectIxFail(
+        [failingIx],
+        [authority],
+        "NoPendingCanonicalTokenUpdate"
       );
-      await expectIxFail([ix], [commonTokenOwner], "ZeroRecipient");
     });
   });
 });
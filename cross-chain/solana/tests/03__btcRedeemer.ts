@@ -0,0 +1,290 @@
+import { MockEthereumTokenBridge } from "@certusone/wormhole-sdk/lib/cjs/mock";
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { getAccount, getAssociatedTokenAddressSync } from "@solana/spl-token";
+import { expect } from "chai";
+import { BtcRedeemer } from "../target/types/btc_redeemer";
+import {
+  ETHEREUM_TOKEN_BRIDGE_ADDRESS,
+  WORMHOLE_GATEWAY_PROGRAM_ID,
+  ethereumGatewaySendTbtc,
+  expectIxFail,
+  expectIxSuccess,
+  generatePayer,
+  getOrCreateAta,
+  transferLamports,
+} from "./helpers";
+import * as btcRedeemer from "./helpers/btcRedeemer";
+import * as tbtc from "./helpers/tbtc";
+import * as wormholeGateway from "./helpers/wormholeGateway";
+
+describe("btc-redeemer", () => {
+  // Configure the client to use the local cluster.
+  anchor.setProvider(anchor.AnchorProvider.env());
+
+  const program = anchor.workspace.BtcRedeemer as Program<BtcRedeemer>;
+  const connection = program.provider.connection;
+
+  const config = btcRedeemer.getConfigPDA();
+
+  const authority = (
+    (program.provider as anchor.AnchorProvider).wallet as anchor.Wallet
+  ).payer;
+  const redeemer = anchor.web3.Keypair.generate();
+
+  // Mock foreign emitter. Start past the sequences used by the gateway tests.
+  const ethereumTokenBridge = new MockEthereumTokenBridge(
+    ETHEREUM_TOKEN_BRIDGE_ADDRESS,
+    1000
+  );
+
+  // Length-prefixed P2WPKH output script.
+  const redeemerOutputScript = Buffer.from(
+    "160014deadbeefdeadbeefdeadbeefdeadbeefdeadbeef",
+    "hex"
+  );
+
+  describe("setup", () => {
+    it("initialize", async () => {
+      const minimumRedemptionAmount = BigInt(1000);
+
+      await program.methods
+        .initialize(new anchor.BN(minimumRedemptionAmount.toString()))
+        .accounts({
+          authority: authority.publicKey,
+          config,
+        })
+        .rpc();
+
+      const configData = await btcRedeemer.getConfigData();
+      expect(configData.authority).to.eql(authority.publicKey);
+      expect(configData.minimumRedemptionAmount.toString()).to.equal(
+        minimumRedemptionAmount.toString()
+      );
+      expect(configData.redeemedAmount.toString()).to.equal("0");
+    });
+
+    it("set payload sender", async () => {
+      // The gateway only sends to the L1 redeemer on behalf of this program.
+      const payloadSender = btcRedeemer.getPayloadSenderPDA();
+      const ix = await wormholeGateway.updatePayloadSenderIx(
+        {
+          gatewayManager: authority.publicKey,
+        },
+        payloadSender
+      );
+      await expectIxSuccess([ix], [authority]);
+
+      const custodianState = await wormholeGateway.getCustodianData();
+      expect(custodianState.payloadSender).to.eql(payloadSender);
+    });
+
+    it("receive tbtc to redeem", async () => {
+      const payer = await generatePayer(authority);
+      const redeemerToken = await getOrCreateAta(
+        payer,
+        tbtc.getMintPDA(),
+        redeemer.publicKey
+      );
+
+      // Make room for minting the received tBTC.
+      const sentAmount = BigInt(10000);
+      const mintedAmount = await wormholeGateway.getMintedAmount();
+      const updateLimitIx = await wormholeGateway.updateMintingLimitIx(
+        {
+          limitManager: authority.publicKey,
+        },
+        mintedAmount + sentAmount
+      );
+      await expectIxSuccess([updateLimitIx], [authority]);
+
+      const fromGateway = await wormholeGateway
+        .getGatewayInfo(2)
+        .then((info) => info.address);
+      const signedVaa = await ethereumGatewaySendTbtc(
+        payer,
+        ethereumTokenBridge,
+        sentAmount,
+        fromGateway,
+        WORMHOLE_GATEWAY_PROGRAM_ID,
+        redeemer.publicKey
+      );
+
+      const ix = await wormholeGateway.receiveTbtcIx(
+        {
+          payer: payer.publicKey,
+          recipientToken: redeemerToken,
+          recipient: redeemer.publicKey,
+        },
+        signedVaa
+      );
+      await expectIxSuccess([ix], [payer]);
+
+      const redeemerTbtc = await getAccount(connection, redeemerToken);
+      expect(redeemerTbtc.amount).to.equal(sentAmount);
+
+      // The redeemer pays for the Wormhole message.
+      await transferLamports(authority, redeemer.publicKey, 1000000000);
+    });
+  });
+
+  describe("minimum redemption amount", () => {
+    it("cannot update minimum redemption amount (not authority)", async () => {
+      const imposter = await generatePayer(authority);
+      const failingIx = await btcRedeemer.updateMinimumRedemptionAmountIx(
+        {
+          authority: imposter.publicKey,
+        },
+        BigInt(69)
+      );
+      await expectIxFail([failingIx], [imposter], "IsNotAuthority");
+    });
+
+    it("cannot update minimum redemption amount (zero)", async () => {
+      const failingIx = await btcRedeemer.updateMinimumRedemptionAmountIx(
+        {
+          authority: authority.publicKey,
+        },
+        BigInt(0)
+      );
+      await expectIxFail(
+        [failingIx],
+        [authority],
+        "MinimumRedemptionAmountZero"
+      );
+    });
+
+    it("update minimum redemption amount", async () => {
+      const minimumRedemptionAmount = BigInt(2000);
+      const ix = await btcRedeemer.updateMinimumRedemptionAmountIx(
+        {
+          authority: authority.publicKey,
+        },
+        minimumRedemptionAmount
+      );
+      await expectIxSuccess([ix], [authority]);
+
+      const configData = await btcRedeemer.getConfigData();
+      expect(configData.minimumRedemptionAmount.toString()).to.equal(
+        minimumRedemptionAmount.toString()
+      );
+    });
+  });
+
+  describe("request redemption", () => {
+    it("cannot request redemption (amount too low)", async () => {
+      const senderToken = getAssociatedTokenAddressSync(
+        tbtc.getMintPDA(),
+        redeemer.publicKey
+      );
+
+      const ix = await btcRedeemer.requestRedemptionIx(
+        {
+          senderToken,
+          sender: redeemer.publicKey,
+        },
+        {
+          amount: BigInt(1999),
+          redeemerOutputScript,
+          nonce: 420,
+        }
+      );
+      await expectIxFail([ix], [redeemer], "AmountTooLowToRedeem");
+    });
+
+    it("cannot request redemption (invalid output scripts)", async () => {
+      const senderToken = getAssociatedTokenAddressSync(
+        tbtc.getMintPDA(),
+        redeemer.publicKey
+      );
+
+      const invalidScripts = [
+        // Missing length prefix.
+        "0014deadbeefdeadbeefdeadbeefdeadbeefdeadbeef",
+        // Wrong length prefix.
+        "170014deadbeefdeadbeefdeadbeefdeadbeefdeadbeef",
+        // Witness program with an unsupported hash length.
+        "150013deadbeefdeadbeefdeadbeefdeadbeefdeadbe",
+        // P2PK.
+        "2321" + "02".padEnd(66, "ab") + "ac",
+        // Empty.
+        "",
+      ];
+      for (const script of invalidScripts) {
+        const ix = await btcRedeemer.requestRedemptionIx(
+          {
+            senderToken,
+            sender: redeemer.publicKey,
+          },
+          {
+            amount: BigInt(2000),
+            redeemerOutputScript: Buffer.from(script, "hex"),
+            nonce: 420,
+          }
+        );
+        await expectIxFail([ix], [redeemer], "InvalidRedeemerOutputScript");
+      }
+    });
+
+    it("request redemption", async () => {
+      const senderToken = getAssociatedTokenAddressSync(
+        tbtc.getMintPDA(),
+        redeemer.publicKey
+      );
+      const gatewayWrappedTbtcToken = wormholeGateway.getWrappedTbtcTokenPDA();
+
+      const [senderTbtcBefore, gatewayBefore] = await Promise.all([
+        getAccount(connection, senderToken),
+        getAccount(connection, gatewayWrappedTbtcToken),
+      ]);
+      const mintedAmountBefore = await wormholeGateway.getMintedAmount();
+
+      // Redeem to each standard output script type.
+      const scripts = [
+        // P2PKH.
+        "1976a914deadbeefdeadbeefdeadbeefdeadbeefdeadbeef88ac",
+        // P2WPKH.
+        "160014deadbeefdeadbeefdeadbeefdeadbeefdeadbeef",
+        // P2SH.
+        "17a914deadbeefdeadbeefdeadbeefdeadbeefdeadbeef87",
+        // P2WSH.
+        "220020" + "deadbeef".repeat(8),
+      ];
+      const amount = BigInt(2000);
+      for (const script of scripts) {
+        const ix = await btcRedeemer.requestRedemptionIx(
+          {
+            senderToken,
+            sender: redeemer.publicKey,
+          },
+          {
+            amount,
+            redeemerOutputScript: Buffer.from(script, "hex"),
+            nonce: 420,
+          }
+        );
+        await expectIxSuccess([ix], [redeemer]);
+      }
+
+      const redeemedAmount = amount * BigInt(scripts.length);
+      const [senderTbtcAfter, gatewayAfter] = await Promise.all([
+        getAccount(connection, senderToken),
+        getAccount(connection, gatewayWrappedTbtcToken),
+      ]);
+      expect(senderTbtcAfter.amount).to.equal(
+        senderTbtcBefore.amount - redeemedAmount
+      );
+      expect(gatewayAfter.amount).to.equal(
+        gatewayBefore.amount - redeemedAmount
+      );
+
+      const mintedAmountAfter = await wormholeGateway.getMintedAmount();
+      expect(mintedAmountAfter).to.equal(mintedAmountBefore - redeemedAmount);
+
+      const configData = await btcRedeemer.getConfigData();
+      expect(configData.redeemedAmount.toString()).to.equal(
+        redeemedAmount.toString()
+      );
+    });
+  });
+});
@@ -0,0 +1,498 @@
+import { MockEthereumTokenBridge } from "@certusone/wormhole-sdk/lib/cjs/mock";
+import * as coreBridge from "@certusone/wormhole-sdk/lib/cjs/solana/wormhole";
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { getAccount } from "@solana/spl-token";
+import { expect } from "chai";
+import { BtcDepositor } from "../target/types/btc_depositor";
+import {
+  ETHEREUM_TOKEN_BRIDGE_ADDRESS,
+  WORMHOLE_GATEWAY_PROGRAM_ID,
+  ethereumGatewaySendTbtc,
+  ethereumGatewaySendTbtcWithPayload,
+  expectIxFail,
+  expectIxSuccess,
+  generatePayer,
+  getOrCreateAta,
+} from "./helpers";
+import * as btcDepositor from "./helpers/btcDepositor";
+import * as tbtc from "./helpers/tbtc";
+import * as wormholeGateway from "./helpers/wormholeGateway";
+
+describe("btc-depositor", () => {
+  // Configure the client to use the local cluster.
+  anchor.setProvider(anchor.AnchorProvider.env());
+
+  const program = anchor.workspace.BtcDepositor as Program<BtcDepositor>;
+  const connection = program.provider.connection;
+
+  const config = btcDepositor.getConfigPDA();
+
+  const authority = (
+    (program.provider as anchor.AnchorProvider).wallet as anchor.Wallet
+  ).payer;
+  const recipient = anchor.web3.Keypair.generate();
+
+  // Mock foreign emitter. Start past the sequences used by the other tests.
+  const ethereumTokenBridge = new MockEthereumTokenBridge(
+    ETHEREUM_TOKEN_BRIDGE_ADDRESS,
+    2000
+  );
+
+  const fundingTx: btcDepositor.BitcoinTxInfo = {
+    version: Buffer.from("01000000", "hex"),
+    inputVector: Buffer.from(
+      "01" + "deadbeef".repeat(8) + "00000000" + "00" + "ffffffff",
+      "hex"
+    ),
+    outputVector: Buffer.from(
+      "01" + "1027000000000000" + "220020" + "deadbeef".repeat(8),
+      "hex"
+    ),
+    locktime: Buffer.from("00000000", "hex"),
+  };
+  const reveal: btcDepositor.DepositRevealInfo = {
+    fundingOutputIndex: 0,
+    blindingFactor: Buffer.from("f9f0c90d00039523", "hex"),
+    walletPubkeyHash: Buffer.alloc(20, "deadbeef", "hex"),
+    refundPubkeyHash: Buffer.alloc(20, "beefdead", "hex"),
+    refundLocktime: Buffer.from("60bcea61", "hex"),
+    vault: Buffer.alloc(20, "b0ba", "hex"),
+  };
+  const depositKey = btcDepositor.getDepositKey(fundingTx, reveal);
+  const depositRecord = btcDepositor.getDepositRecordPDA(depositKey);
+
+  describe("setup", () => {
+    it("initialize", async () => {
+      const gatewayCustodian = wormholeGateway.getCustodianPDA();
+
+      await program.methods
+        .initialize(gatewayCustodian)
+        .accounts({
+          authority: authority.publicKey,
+          config,
+        })
+        .rpc();
+
+      const configData = await btcDepositor.getConfigData();
+      expect(configData.authority).to.eql(authority.publicKey);
+      expect(configData.gatewayCustodian).to.eql(gatewayCustodian);
+    });
+  });
+
+  describe("initialize deposit", () => {
+    it("cannot initialize deposit (zero recipient)", async () => {
+      const payer = await generatePayer(authority);
+      const ix = await btcDepositor.initializeDepositIx(
+        {
+          payer: payer.publicKey,
+        },
+        {
+          fundingTx,
+          reveal,
+          recipient: anchor.web3.PublicKey.default,
+        }
+      );
+      await expectIxFail([ix], [payer], "ZeroRecipient");
+    });
+
+    it("cannot initialize deposit (invalid deposit key)", async () => {
+      const payer = await generatePayer(authority);
+      const otherDepositKey = btcDepositor.getDepositKey(fundingTx, {
+        ...reveal,
+        fundingOutputIndex: 1,
+      });
+      const ix = await btcDepositor.initializeDepositIx(
+        {
+          payer: payer.publicKey,
+        },
+        {
+          fundingTx,
+          reveal,
+          recipient: recipient.publicKey,
+        },
+        otherDepositKey
+      );
+      await expectIxFail([ix], [payer], "InvalidDepositKey");
+    });
+
+    it("initialize deposit", async () => {
+      const payer = await generatePayer(authority);
+      const ix = await btcDepositor.initializeDepositIx(
+        {
+          payer: payer.publicKey,
+        },
+        {
+          fundingTx,
+          reveal,
+          recipient: recipient.publicKey,
+        }
+      );
+      await expectIxSuccess([ix], [payer]);
+
+      const record = await btcDepositor.getDepositRecord(depositKey);
+      expect(Buffer.from(record.depositKey)).to.eql(depositKey);
+      expect(record.recipient).to.eql(recipient.publicKey);
+      expect(record.depositor).to.eql(payer.publicKey);
+      expect(record.state).to.eql({ initialized: {} });
+      expect(record.amount.toString()).to.equal("0");
+
+      // The message is the ABI-encoded `initializeDeposit` arguments.
+      const {
+        message: { payload },
+      } = await coreBridge.getPostedMessage(
+        connection,
+        btcDepositor.getCoreMessagePDA(depositKey)
+      );
+      const word = (index: number) =>
+        payload.subarray(index * 32, (index + 1) * 32);
+      expect(word(0).readUInt32BE(28)).to.equal(256);
+      expect(word(1).readUInt32BE(28)).to.equal(reveal.fundingOutputIndex);
+      expect(word(2).subarray(0, 8)).to.eql(reveal.blindingFactor);
+      expect(word(6).subarray(12)).to.eql(reveal.vault);
+      expect(word(7)).to.eql(recipient.publicKey.toBuffer());
+      expect(word(8).subarray(0, 4)).to.eql(fundingTx.version);
+    });
+
+    it("cannot initialize deposit (already initialized)", async () => {
+      const payer = await generatePayer(authority);
+      const ix = await btcDepositor.initializeDepositIx(
+        {
+          payer: payer.publicKey,
+        },
+        {
+          fundingTx,
+          reveal,
+          recipient: recipient.publicKey,
+        }
+      );
+      await expectIxFail([ix], [payer], "already in use");
+    });
+  });
+
+  describe("finalize deposit", () => {
+    it("cannot finalize deposit (not gateway custodian)", async () => {
+      const imposter = await generatePayer(authority);
+      const ix = await btcDepositor.finalizeDepositIx(
+        {
+          depositRecord,
+          recipient: recipient.publicKey,
+          gatewayCustodian: imposter.publicKey,
+        },
+        BigInt(10000)
+      );
+      await expectIxFail([ix], [imposter], "IsNotGatewayCustodian");
+    });
+
+    it("cannot receive tbtc (invalid btc depositor config)", async () => {
+      const payer = await generatePayer(authority);
+      const recipientToken = await getOrCreateAta(
+        payer,
+        tbtc.getMintPDA(),
+        recipient.publicKey
+      );
+
+      const fromGateway = await wormholeGateway
+        .getGatewayInfo(2)
+        .then((info) => info.address);
+      const signedVaa = await ethereumGatewaySendTbtc(
+        payer,
+        ethereumTokenBridge,
+        BigInt(10000),
+        fromGateway,
+        WORMHOLE_GATEWAY_PROGRAM_ID,
+        recipient.publicKey
+      );
+
+      // Any account but the BTC Depositor program's config is rejected.
+      const ix = await wormholeGateway.receiveTbtcIx(
+        {
+          payer: payer.publicKey,
+          recipientToken,
+          recipient: recipient.publicKey,
+          depositRecord,
+          btcDepositorConfig: depositRecord,
+        },
+        signedVaa
+      );
+      await expectIxFail([ix], [payer], "InvalidBtcDepositorConfig");
+    });
+
+    it("cannot receive tbtc (unknown l1 btc depositor)", async () => {
+      const payer = await generatePayer(authority);
+      const recipientToken = await getOrCreateAta(
+        payer,
+        tbtc.getMintPDA(),
+        recipient.publicKey
+      );
+
+      const fromGateway = await wormholeGateway
+        .getGatewayInfo(2)
+        .then((info) => info.address);
+      const signedVaa = await ethereumGatewaySendTbtc(
+        payer,
+        ethereumTokenBridge,
+        BigInt(10000),
+        fromGateway,
+        WORMHOLE_GATEWAY_PROGRAM_ID,
+        recipient.publicKey
+      );
+
+      // The L1 BTC depositor is not set yet, so no transfer can finalize the
+      // deposit.
+      const ix = await wormholeGateway.receiveTbtcIx(
+        {
+          payer: payer.publicKey,
+          recipientToken,
+          recipient: recipient.publicKey,
+          depositRecord,
+        },
+        signedVaa
+      );
+      await expectIxFail([ix], [payer], "UnknownL1BtcDepositor");
+    });
+
+    it("cannot update l1 btc depositor (not authority)", async () => {
+      const imposter = await generatePayer(authority);
+      const ix = await btcDepositor.updateL1BtcDepositorIx(
+        {
+          authority: imposter.publicKey,
+        },
+        Array.from(Buffer.alloc(32, "deadbeef", "hex"))
+      );
+      await expectIxFail([ix], [imposter], "IsNotAuthority");
+    });
+
+    it("update l1 btc depositor", async () => {
+      // The mock L1 BTC depositor sends through the registered gateway address.
+      const l1BtcDepositor = await wormholeGateway
+        .getGatewayInfo(2)
+        .then((info) => info.address);
+      const ix = await btcDepositor.updateL1BtcDepositorIx(
+        {
+          authority: authority.publicKey,
+        },
+        l1BtcDepositor
+      );
+      await expectIxSuccess([ix], [authority]);
+
+      const configData = await btcDepositor.getConfigData();
+      expect(configData.l1BtcDepositor).to.eql(l1BtcDepositor);
+    });
+
+    it("cannot receive tbtc (recipient mismatch)", async () => {
+      const payer = await generatePayer(authority);
+      const otherRecipient = anchor.web3.Keypair.generate();
+      const recipientToken = await getOrCreateAta(
+        payer,
+        tbtc.getMintPDA(),
+        otherRecipient.publicKey
+      );
+
+      const fromGateway = await wormholeGateway
+        .getGatewayInfo(2)
+        .then((info) => info.address);
+      const signedVaa = await ethereumGatewaySendTbtc(
+        payer,
+        ethereumTokenBridge,
+        BigInt(10000),
+        fromGateway,
+        WORMHOLE_GATEWAY_PROGRAM_ID,
+        otherRecipient.publicKey
+      );
+
+      const ix = await wormholeGateway.receiveTbtcIx(
+        {
+          payer: payer.publicKey,
+          recipientToken,
+          recipient: otherRecipient.publicKey,
+          depositRecord,
+        },
+        signedVaa
+      );
+      await expectIxFail([ix], [payer], "RecipientMismatch");
+    });
+
+    it("cannot receive tbtc (deposit record mismatch)", async () => {
+      const payer = await generatePayer(authority);
+      const recipientToken = await getOrCreateAta(
+        payer,
+        tbtc.getMintPDA(),
+        recipient.publicKey
+      );
+
+      const fromGateway = await wormholeGateway
+        .getGatewayInfo(2)
+        .then((info) => info.address);
+      const signedVaa = await ethereumGatewaySendTbtcWithPayload(
+        payer,
+        ethereumTokenBridge,
+        BigInt(10000),
+        fromGateway,
+        WORMHOLE_GATEWAY_PROGRAM_ID,
+        wormholeGateway.encodeTransferPayload({
+          recipient: recipient.publicKey,
+          depositKey,
+        })
+      );
+
+      // The transfer references the deposit, so it cannot finalize another one.
+      const otherDepositKey = btcDepositor.getDepositKey(fundingTx, {
+        ...reveal,
+        fundingOutputIndex: 1,
+      });
+      const ix = await wormholeGateway.receiveTbtcIx(
+        {
+          payer: payer.publicKey,
+          recipientToken,
+          recipient: recipient.publicKey,
+          depositRecord: btcDepositor.getDepositRecordPDA(otherDepositKey),
+        },
+        signedVaa
+      );
+      await expectIxFail([ix], [payer], "DepositRecordMismatch");
+    });
+
+    it("cannot receive tbtc (deposit accounts missing)", async () => {
+      const payer = await generatePayer(authority);
+      const recipientToken = await getOrCreateAta(
+        payer,
+        tbtc.getMintPDA(),
+        recipient.publicKey
+      );
+
+      const fromGateway = await wormholeGateway
+        .getGatewayInfo(2)
+        .then((info) => info.address);
+      const signedVaa = await ethereumGatewaySendTbtcWithPayload(
+        payer,
+        ethereumTokenBridge,
+        BigInt(10000),
+        fromGateway,
+        WORMHOLE_GATEWAY_PROGRAM_ID,
+        wormholeGateway.encodeTransferPayload({
+          recipient: recipient.publicKey,
+          depositKey,
+        })
+      );
+
+      // The transfer references the deposit, so it must finalize it.
+      const ix = await wormholeGateway.receiveTbtcIx(
+        {
+          payer: payer.publicKey,
+          recipientToken,
+          recipient: recipient.publicKey,
+        },
+        signedVaa
+      );
+      await expectIxFail([ix], [payer], "IncompleteDepositAccounts");
+    });
+
+    it("receive tbtc and finalize deposit", async () => {
+      const payer = await generatePayer(authority);
+      const recipientToken = await getOrCreateAta(
+        payer,
+        tbtc.getMintPDA(),
+        recipient.publicKey
+      );
+
+      // Make room for minting the received tBTC.
+      const sentAmount = BigInt(10000);
+      const mintedAmount = await wormholeGateway.getMintedAmount();
+      const updateLimitIx = await wormholeGateway.updateMintingLimitIx(
+        {
+          limitManager: authority.publicKey,
+        },
+        mintedAmount + sentAmount
+      );
+      await expectIxSuccess([updateLimitIx], [authority]);
+
+      const fromGateway = await wormholeGateway
+        .getGatewayInfo(2)
+        .then((info) => info.address);
+      const signedVaa = await ethereumGatewaySendTbtc(
+        payer,
+        ethereumTokenBridge,
+        sentAmount,
+        fromGateway,
+        WORMHOLE_GATEWAY_PROGRAM_ID,
+        recipient.publicKey
+      );
+
+      const ix = await wormholeGateway.receiveTbtcIx(
+        {
+          payer: payer.publicKey,
+          recipientToken,
+          recipient: recipient.publicKey,
+          depositRecord,
+        },
+        signedVaa
+      );
+      await expectIxSuccess([ix], [payer]);
+
+      const recipientTbtc = await getAccount(connection, recipientToken);
+      expect(recipientTbtc.amount).to.equal(sentAmount);
+
+      const record = await btcDepositor.getDepositRecord(depositKey);
+      expect(record.state).to.eql({ finalized: {} });
+      expect(record.amount.toString()).to.equal(sentAmount.toString());
+    });
+
+    it("receive tbtc (deposit already finalized)", async () => {
+      const payer = await generatePayer(authority);
+      const recipientToken = await getOrCreateAta(
+        payer,
+        tbtc.getMintPDA(),
+        recipient.publicKey
+      );
+      const recipientBefore = await getAccount(connection, recipientToken);
+
+      // Make room for minting the received tBTC.
+      const sentAmount = BigInt(5000);
+      const mintedAmount = await wormholeGateway.getMintedAmount();
+      const updateLimitIx = await wormholeGateway.updateMintingLimitIx(
+        {
+          limitManager: authority.publicKey,
+        },
+        mintedAmount + sentAmount
+      );
+      await expectIxSuccess([updateLimitIx], [authority]);
+
+      const fromGateway = await wormholeGateway
+        .getGatewayInfo(2)
+        .then((info) => info.address);
+      const signedVaa = await ethereumGatewaySendTbtcWithPayload(
+        payer,
+        ethereumTokenBridge,
+        sentAmount,
+        fromGateway,
+        WORMHOLE_GATEWAY_PROGRAM_ID,
+        wormholeGateway.encodeTransferPayload({
+          recipient: recipient.publicKey,
+          depositKey,
+        })
+      );
+
+      // Finalizing is skipped, so the transfer can still be redeemed.
+      const ix = await wormholeGateway.receiveTbtcIx(
+        {
+          payer: payer.publicKey,
+          recipientToken,
+          recipient: recipient.publicKey,
+          depositRecord,
+        },
+        signedVaa
+      );
+      await expectIxSuccess([ix], [payer]);
+
+      const recipientAfter = await getAccount(connection, recipientToken);
+      expect(recipientAfter.amount).to.equal(
+        recipientBefore.amount + sentAmount
+      );
+
+      const record = await btcDepositor.getDepositRecord(depositKey);
+      expect(record.state).to.eql({ finalized: {} });
+      expect(record.amount.toString()).to.equal("10000");
+    });
+  });
+});
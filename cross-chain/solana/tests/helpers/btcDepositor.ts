@@ -0,0 +1,217 @@
+import { keccak256 } from "@certusone/wormhole-sdk";
+import * as coreBridge from "@certusone/wormhole-sdk/lib/cjs/solana/wormhole";
+import { BN, Program, workspace } from "@coral-xyz/anchor";
+import {
+  PublicKey,
+  SYSVAR_CLOCK_PUBKEY,
+  SYSVAR_RENT_PUBKEY,
+  TransactionInstruction,
+} from "@solana/web3.js";
+import { createHash } from "crypto";
+import { BtcDepositor } from "../../target/types/btc_depositor";
+import {
+  BTC_DEPOSITOR_PROGRAM_ID,
+  CORE_BRIDGE_DATA,
+  CORE_BRIDGE_PROGRAM_ID,
+} from "./consts";
+import { eventCpiAccounts } from "./utils";
+
+export type BitcoinTxInfo = {
+  version: Buffer;
+  inputVector: Buffer;
+  outputVector: Buffer;
+  locktime: Buffer;
+};
+
+export type DepositRevealInfo = {
+  fundingOutputIndex: number;
+  blindingFactor: Buffer;
+  walletPubkeyHash: Buffer;
+  refundPubkeyHash: Buffer;
+  refundLocktime: Buffer;
+  vault: Buffer;
+};
+
+export function getConfigPDA(): PublicKey {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("config")],
+    BTC_DEPOSITOR_PROGRAM_ID
+  )[0];
+}
+
+export function getDepositRecordPDA(depositKey: Buffer): PublicKey {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("deposit-record"), depositKey],
+    BTC_DEPOSITOR_PROGRAM_ID
+  )[0];
+}
+
+export function getCoreEmitterPDA(): PublicKey {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("emitter")],
+    BTC_DEPOSITOR_PROGRAM_ID
+  )[0];
+}
+
+export function getCoreMessagePDA(depositKey: Buffer): PublicKey {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("msg"), depositKey],
+    BTC_DEPOSITOR_PROGRAM_ID
+  )[0];
+}
+
+export async function getConfigData() {
+  const program = workspace.BtcDepositor as Program<BtcDepositor>;
+  return program.account.config.fetch(getConfigPDA());
+}
+
+export async function getDepositRecord(depositKey: Buffer) {
+  const program = workspace.BtcDepositor as Program<BtcDepositor>;
+  return program.account.depositRecord.fetch(getDepositRecordPDA(depositKey));
+}
+
+export function getDepositKey(
+  fundingTx: BitcoinTxInfo,
+  reveal: DepositRevealInfo
+): Buffer {
+  const sha256 = (data: Buffer) => createHash("sha256").update(data).digest();
+  const fundingTxHash = sha256(
+    sha256(
+      Buffer.concat([
+        fundingTx.version,
+        fundingTx.inputVector,
+        fundingTx.outputVector,
+        fundingTx.locktime,
+      ])
+    )
+  );
+
+  const fundingOutputIndex = Buffer.alloc(4);
+  fundingOutputIndex.writeUInt32BE(reveal.fundingOutputIndex);
+
+  return keccak256(Buffer.concat([fundingTxHash, fundingOutputIndex]));
+}
+
+type UpdateL1BtcDepositorContext = {
+  config?: PublicKey;
+  authority: PublicKey;
+};
+
+export async function updateL1BtcDepositorIx(
+  accounts: UpdateL1BtcDepositorContext,
+  l1BtcDepositor: number[]
+): Promise<TransactionInstruction> {
+  const program = workspace.BtcDepositor as Program<BtcDepositor>;
+
+  let { config, authority } = accounts;
+  if (config === undefined) {
+    config = getConfigPDA();
+  }
+
+  return program.methods
+    .updateL1BtcDepositor(l1BtcDepositor)
+    .accounts({
+      config,
+      authority,
+      ...eventCpiAccounts(program.programId),
+    })
+    .instruction();
+}
+
+type InitializeDepositContext = {
+  payer: PublicKey;
+  depositRecord?: PublicKey;
+};
+
+type InitializeDepositArgs = {
+  fundingTx: BitcoinTxInfo;
+  reveal: DepositRevealInfo;
+  recipient: PublicKey;
+};
+
+export async function initializeDepositIx(
+  accounts: InitializeDepositContext,
+  args: InitializeDepositArgs,
+  depositKey?: Buffer
+): Promise<TransactionInstruction> {
+  const program = workspace.BtcDepositor as Program<BtcDepositor>;
+
+  if (depositKey === undefined) {
+    depositKey = getDepositKey(args.fundingTx, args.reveal);
+  }
+
+  let { payer, depositRecord } = accounts;
+  if (depositRecord === undefined) {
+    depositRecord = getDepositRecordPDA(depositKey);
+  }
+
+  const coreEmitter = getCoreEmitterPDA();
+
+  return program.methods
+    .initializeDeposit(Array.from(depositKey), {
+      fundingTx: {
+        version: Array.from(args.fundingTx.version),
+        inputVector: args.fundingTx.inputVector,
+        outputVector: args.fundingTx.outputVector,
+        locktime: Array.from(args.fundingTx.locktime),
+      },
+      reveal: {
+        fundingOutputIndex: args.reveal.fundingOutputIndex,
+        blindingFactor: Array.from(args.reveal.blindingFactor),
+        walletPubkeyHash: Array.from(args.reveal.walletPubkeyHash),
+        refundPubkeyHash: Array.from(args.reveal.refundPubkeyHash),
+        refundLocktime: Array.from(args.reveal.refundLocktime),
+        vault: Array.from(args.reveal.vault),
+      },
+      recipient: args.recipient,
+    })
+    .accounts({
+      payer,
+      depositRecord,
+      coreEmitter,
+      coreBridgeData: CORE_BRIDGE_DATA,
+      coreMessage: getCoreMessagePDA(depositKey),
+      coreEmitterSequence: coreBridge.deriveEmitterSequenceKey(
+        coreEmitter,
+        CORE_BRIDGE_PROGRAM_ID
+      ),
+      coreFeeCollector: coreBridge.deriveFeeCollectorKey(
+        CORE_BRIDGE_PROGRAM_ID
+      ),
+      clock: SYSVAR_CLOCK_PUBKEY,
+      rent: SYSVAR_RENT_PUBKEY,
+      coreBridgeProgram: CORE_BRIDGE_PROGRAM_ID,
+      ...eventCpiAccounts(program.programId),
+    })
+    .instruction();
+}
+
+type FinalizeDepositContext = {
+  config?: PublicKey;
+  depositRecord: PublicKey;
+  recipient: PublicKey;
+  gatewayCustodian: PublicKey;
+};
+
+export async function finalizeDepositIx(
+  accounts: FinalizeDepositContext,
+  amount: bigint
+): Promise<TransactionInstruction> {
+  const program = workspace.BtcDepositor as Program<BtcDepositor>;
+
+  let { config, depositRecord, recipient, gatewayCustodian } = accounts;
+  if (config === undefined) {
+    config = getConfigPDA();
+  }
+
+  return program.methods
+    .finalizeDeposit(new BN(amount.toString()))
+    .accounts({
+      config,
+      depositRecord,
+      recipient,
+      gatewayCustodian,
+      ...eventCpiAccounts(program.programId),
+    })
+    .instruction();
+}
@@ -0,0 +1,153 @@
+import * as tokenBridge from "@certusone/wormhole-sdk/lib/cjs/solana/tokenBridge";
+import * as coreBridge from "@certusone/wormhole-sdk/lib/cjs/solana/wormhole";
+import { BN, Program, workspace } from "@coral-xyz/anchor";
+import { TOKEN_PROGRAM_ID } from "@solana/spl-token";
+import {
+  PublicKey,
+  SYSVAR_CLOCK_PUBKEY,
+  SYSVAR_RENT_PUBKEY,
+  TransactionInstruction,
+} from "@solana/web3.js";
+import { BtcRedeemer } from "../../target/types/btc_redeemer";
+import {
+  BTC_REDEEMER_PROGRAM_ID,
+  CORE_BRIDGE_DATA,
+  CORE_BRIDGE_PROGRAM_ID,
+  TBTC_PROGRAM_ID,
+  TOKEN_BRIDGE_PROGRAM_ID,
+  WORMHOLE_GATEWAY_PROGRAM_ID,
+  WRAPPED_TBTC_ASSET,
+  WRAPPED_TBTC_MINT,
+} from "./consts";
+import * as tbtc from "./tbtc";
+import {
+  eventCpiAccounts,
+  eventCpiRemainingAccounts,
+  getTokenBridgeCoreEmitter,
+  getTokenBridgeSequence,
+} from "./utils";
+import * as wormholeGateway from "./wormholeGateway";
+
+export function getConfigPDA(): PublicKey {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("config")],
+    BTC_REDEEMER_PROGRAM_ID
+  )[0];
+}
+
+export function getPayloadSenderPDA(): PublicKey {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("payload-sender")],
+    BTC_REDEEMER_PROGRAM_ID
+  )[0];
+}
+
+export async function getConfigData() {
+  const program = workspace.BtcRedeemer as Program<BtcRedeemer>;
+  return program.account.config.fetch(getConfigPDA());
+}
+
+type UpdateMinimumRedemptionAmountContext = {
+  config?: PublicKey;
+  authority: PublicKey;
+};
+
+export async function updateMinimumRedemptionAmountIx(
+  accounts: UpdateMinimumRedemptionAmountContext,
+  minimumRedemptionAmount: bigint
+): Promise<TransactionInstruction> {
+  const program = workspace.BtcRedeemer as Program<BtcRedeemer>;
+
+  let { config, authority } = accounts;
+  if (config === undefined) {
+    config = getConfigPDA();
+  }
+
+  return program.methods
+    .updateMinimumRedemptionAmount(new BN(minimumRedemptionAmount.toString()))
+    .accounts({
+      config,
+      authority,
+      ...eventCpiAccounts(program.programId),
+    })
+    .instruction();
+}
+
+type RequestRedemptionContext = {
+  config?: PublicKey;
+  senderToken: PublicKey;
+  sender: PublicKey;
+};
+
+type RequestRedemptionArgs = {
+  amount: bigint;
+  redeemerOutputScript: Buffer;
+  nonce: number;
+};
+
+export async function requestRedemptionIx(
+  accounts: RequestRedemptionContext,
+  args: RequestRedemptionArgs
+): Promise<TransactionInstruction> {
+  const program = workspace.BtcRedeemer as Program<BtcRedeemer>;
+
+  let { config, senderToken, sender } = accounts;
+  if (config === undefined) {
+    config = getConfigPDA();
+  }
+
+  const { canonicalToken } = await wormholeGateway.getCustodianData();
+  const tokenBridgeCoreEmitter = getTokenBridgeCoreEmitter();
+  const sequence = await getTokenBridgeSequence();
+
+  return program.methods
+    .requestRedemption({
+      amount: new BN(args.amount.toString()),
+      redeemerOutputScript: args.redeemerOutputScript,
+      nonce: args.nonce,
+    })
+    .accounts({
+      config,
+      custodian: wormholeGateway.getCustodianPDA(),
+      allowedChain: wormholeGateway.getAllowedChainPDA(canonicalToken.chain),
+      wrappedTbtcToken: wormholeGateway.getWrappedTbtcTokenPDA(),
+      wrappedTbtcMint: WRAPPED_TBTC_MINT,
+      tbtcMint: tbtc.getMintPDA(),
+      senderToken,
+      sender,
+      payloadSender: getPayloadSenderPDA(),
+      tbtcConfig: tbtc.getConfigPDA(),
+      tokenBridgeConfig: tokenBridge.deriveTokenBridgeConfigKey(
+        TOKEN_BRIDGE_PROGRAM_ID
+      ),
+      tokenBridgeWrappedAsset: WRAPPED_TBTC_ASSET,
+      tokenBridgeTransferAuthority: tokenBridge.deriveAuthoritySignerKey(
+        TOKEN_BRIDGE_PROGRAM_ID
+      ),
+      coreBridgeData: CORE_BRIDGE_DATA,
+      coreMessage: wormholeGateway.getCoreMessagePDA(sequence),
+      tokenBridgeCoreEmitter,
+      coreEmitterSequence: coreBridge.deriveEmitterSequenceKey(
+        tokenBridgeCoreEmitter,
+        CORE_BRIDGE_PROGRAM_ID
+      ),
+      coreFeeCollector: coreBridge.deriveFeeCollectorKey(
+        CORE_BRIDGE_PROGRAM_ID
+      ),
+      clock: SYSVAR_CLOCK_PUBKEY,
+      tokenBridgeSender: tokenBridge.deriveSenderAccountKey(
+        WORMHOLE_GATEWAY_PROGRAM_ID
+      ),
+      rent: SYSVAR_RENT_PUBKEY,
+      tbtcProgram: TBTC_PROGRAM_ID,
+      tokenBridgeProgram: TOKEN_BRIDGE_PROGRAM_ID,
+      coreBridgeProgram: CORE_BRIDGE_PROGRAM_ID,
+      tokenProgram: TOKEN_PROGRAM_ID,
+      wormholeGatewayProgram: WORMHOLE_GATEWAY_PROGRAM_ID,
+      ...eventCpiAccounts(program.programId),
+    })
+    .remainingAccounts(
+      eventCpiRemainingAccounts(TBTC_PROGRAM_ID, WORMHOLE_GATEWAY_PROGRAM_ID)
+    )
+    .instruction();
+}
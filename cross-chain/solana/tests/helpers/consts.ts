@@ -6,6 +6,12 @@ export const TBTC_PROGRAM_ID = new PublicKey(
 export const WORMHOLE_GATEWAY_PROGRAM_ID = new PublicKey(
   "8H9F5JGbEMyERycwaGuzLS5MQnV7dn2wm2h6egJ3Leiu"
 );
+export const BTC_REDEEMER_PROGRAM_ID = new PublicKey(
+  "8xPdHeMpTxht5BTy5qk5hPubGZ9Vx2SQiFkaAM6daDN9"
+);
+export const BTC_DEPOSITOR_PROGRAM_ID = new PublicKey(
+  "tuB29m4h5MVyWSY7VBwaSd7C7kvc9NVNgnGUeswxbGB"
+);
 
 export const CORE_BRIDGE_PROGRAM_ID = new PublicKey(
   "worm2ZoG2kUd4vFXhvjh93UUH596ayRfgQ2MgjNMTth"
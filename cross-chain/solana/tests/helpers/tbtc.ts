@@ -4,6 +4,7 @@ import { PublicKey, TransactionInstruction } from "@solana/web3.js";
 import { config, expect } from "chai";
 import { Tbtc } from "../../target/types/tbtc";
 import { TBTC_PROGRAM_ID } from "./consts";
+import { eventCpiAccounts } from "./utils";
 import { PROGRAM_ID as METADATA_PROGRAM_ID } from "@metaplex-foundation/mpl-token-metadata";
 
 export function getConfigPDA(): PublicKey {
@@ -59,6 +60,38 @@ export function getMintersPDA(): PublicKey {
   )[0];
 }
 
+export function getMintRateLimitPDA(): PublicKey {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("mint-rate-limit")],
+    TBTC_PROGRAM_ID
+  )[0];
+}
+
+export type Role =
+  | "minterAdmin"
+  | "guardianAdmin"
+  | "limitManager"
+  | "unpauser";
+
+// Must match the order of the `Role` enum variants.
+const ROLES: Role[] = [
+  "minterAdmin",
+  "guardianAdmin",
+  "limitManager",
+  "unpauser",
+];
+
+export function getRoleMemberPDA(role: Role, member: PublicKey): PublicKey {
+  return PublicKey.findProgramAddressSync(
+    [
+      Buffer.from("role-member"),
+      Buffer.from([ROLES.indexOf(role)]),
+      member.toBuffer(),
+    ],
+    TBTC_PROGRAM_ID
+  )[0];
+}
+
 export async function getConfigData() {
   const program = workspace.Tbtc as Program<Tbtc>;
   const config = getConfigPDA();
@@ -111,9 +144,37 @@ export async function getMinterInfo(minter: PublicKey) {
   return program.account.minterInfo.fetch(minterInfoPDA);
 }
 
-export async function checkMinterInfo(minter: PublicKey) {
+export async function checkMinterInfo(
+  minter: PublicKey,
+  expected?: {
+    allowance: bigint;
+    minted: bigint;
+  }
+) {
   const minterInfo = await getMinterInfo(minter);
   expect(minterInfo.minter).to.eql(minter);
+
+  if (expected !== undefined) {
+    const { allowance, minted } = expected;
+    expect(BigInt(minterInfo.allowance.toString())).to.equal(allowance);
+    expect(BigInt(minterInfo.minted.toString())).to.equal(minted);
+  }
+}
+
+export async function getMintRateLimit() {
+  const program = workspace.Tbtc as Program<Tbtc>;
+  const mintRateLimit = getMintRateLimitPDA();
+  return program.account.mintRateLimit.fetch(mintRateLimit);
+}
+
+export async function checkMintRateLimit(expected: {
+  capacity: bigint;
+  window: number;
+}) {
+  const { capacity, window } = expected;
+  const mintRateLimit = await getMintRateLimit();
+  expect(BigInt(mintRateLimit.capacity.toString())).to.equal(capacity);
+  expect(mintRateLimit.window).to.equal(window);
 }
 
 export async function getGuardianInfo(guardian: PublicKey) {
@@ -129,7 +190,8 @@ export async function checkGuardianInfo(guardian: PublicKey) {
 
 type AddGuardianContext = {
   config?: PublicKey;
-  authority: PublicKey;
+  guardianAdmin: PublicKey;
+  roleMember?: PublicKey;
   guardians?: PublicKey;
   guardianInfo?: PublicKey;
   guardian: PublicKey;
@@ -140,11 +202,16 @@ export async function addGuardianIx(
 ): Promise<TransactionInstruction> {
   const program = workspace.Tbtc as Program<Tbtc>;
 
-  let { config, authority, guardians, guardianInfo, guardian } = accounts;
+  let { config, roleMember, guardianAdmin, guardians, guardianInfo, guardian } =
+    accounts;
   if (config === undefined) {
     config = getConfigPDA();
   }
 
+  if (roleMember === undefined) {
+    roleMember = getRoleMemberPDA("guardianAdmin", guardianAdmin);
+  }
+
   if (guardians === undefined) {
     guardians = getGuardiansPDA();
   }
@@ -157,32 +224,41 @@ export async function addGuardianIx(
     .addGuardian()
     .accounts({
       config,
-      authority,
+      roleMember,
+      guardianAdmin,
       guardians,
       guardianInfo,
       guardian,
+      ...eventCpiAccounts(program.programId),
     })
     .instruction();
 }
 
 type AddMinterContext = {
   config?: PublicKey;
-  authority: PublicKey;
+  minterAdmin: PublicKey;
+  roleMember?: PublicKey;
   minters?: PublicKey;
   minterInfo?: PublicKey;
   minter: PublicKey;
 };
 
 export async function addMinterIx(
-  accounts: AddMinterContext
+  accounts: AddMinterContext,
+  allowance: bigint
 ): Promise<TransactionInstruction> {
   const program = workspace.Tbtc as Program<Tbtc>;
 
-  let { config, authority, minters, minterInfo, minter } = accounts;
+  let { config, roleMember, minterAdmin, minters, minterInfo, minter } =
+    accounts;
   if (config === undefined) {
     config = getConfigPDA();
   }
 
+  if (roleMember === undefined) {
+    roleMember = getRoleMemberPDA("minterAdmin", minterAdmin);
+  }
+
   if (minters === undefined) {
     minters = getMintersPDA();
   }
@@ -192,13 +268,89 @@ export async function addMinterIx(
   }
 
   return program.methods
-    .addMinter()
+    .addMinter(new BN(allowance.toString()))
     .accounts({
       config,
-      authority,
+      roleMember,
+      minterAdmin,
       minters,
       minterInfo,
       minter,
+      ...eventCpiAccounts(program.programId),
+    })
+    .instruction();
+}
+
+type BurnContext = {
+  mint?: PublicKey;
+  config?: PublicKey;
+  owner: PublicKey;
+  ownerToken: PublicKey;
+};
+
+export async function burnIx(
+  accounts: BurnContext,
+  amount: BN
+): Promise<TransactionInstruction> {
+  const program = workspace.Tbtc as Program<Tbtc>;
+
+  let { mint, config, owner, ownerToken } = accounts;
+  if (mint === undefined) {
+    mint = getMintPDA();
+  }
+
+  if (config === undefined) {
+    config = getConfigPDA();
+  }
+
+  return program.methods
+    .burn(amount)
+    .accounts({
+      mint,
+      config,
+      owner,
+      ownerToken,
+      ...eventCpiAccounts(program.programId),
+    })
+    .instruction();
+}
+
+type BurnFromContext = {
+  mint?: PublicKey;
+  config?: PublicKey;
+  minterInfo?: PublicKey;
+  minter: PublicKey;
+  ownerToken: PublicKey;
+};
+
+export async function burnFromIx(
+  accounts: BurnFromContext,
+  amount: BN
+): Promise<TransactionInstruction> {
+  const program = workspace.Tbtc as Program<Tbtc>;
+
+  let { mint, config, minterInfo, minter, ownerToken } = accounts;
+  if (mint === undefined) {
+    mint = getMintPDA();
+  }
+
+  if (config === undefined) {
+    config = getConfigPDA();
+  }
+
+  if (minterInfo === undefined) {
+    minterInfo = getMinterInfoPDA(minter);
+  }
+
+  return program.methods
+    .burnFrom(amount)
+    .accounts({
+      mint,
+      config,
+      minterInfo,
+      minter,
+      ownerToken,
+      ...eventCpiAccounts(program.programId),
     })
     .instruction();
 }
@@ -223,6 +375,7 @@ export async function cancelAuthorityChangeIx(
     .accounts({
       config,
       authority,
+      ...eventCpiAccounts(program.programId),
     })
     .instruction();
 }
@@ -249,6 +402,80 @@ export async function changeAuthorityIx(
       config,
       authority,
       newAuthority,
+      ...eventCpiAccounts(program.programId),
+    })
+    .instruction();
+}
+
+type FreezeAccountContext = {
+  mint?: PublicKey;
+  config?: PublicKey;
+  guardianInfo?: PublicKey;
+  guardian: PublicKey;
+  tokenAccount: PublicKey;
+};
+
+export async function freezeAccountIx(
+  accounts: FreezeAccountContext
+): Promise<TransactionInstruction> {
+  const program = workspace.Tbtc as Program<Tbtc>;
+
+  let { mint, config, guardianInfo, guardian, tokenAccount } = accounts;
+  if (mint === undefined) {
+    mint = getMintPDA();
+  }
+
+  if (config === undefined) {
+    config = getConfigPDA();
+  }
+
+  if (guardianInfo === undefined) {
+    guardianInfo = getGuardianInfoPDA(guardian);
+  }
+
+  return program.methods
+    .freezeAccount()
+    .accounts({
+      mint,
+      config,
+      guardianInfo,
+      guardian,
+      tokenAccount,
+      ...eventCpiAccounts(program.programId),
+    })
+    .instruction();
+}
+
+type GrantRoleContext = {
+  config?: PublicKey;
+  authority: PublicKey;
+  roleMember?: PublicKey;
+  member: PublicKey;
+};
+
+export async function grantRoleIx(
+  accounts: GrantRoleContext,
+  role: Role
+): Promise<TransactionInstruction> {
+  const program = workspace.Tbtc as Program<Tbtc>;
+
+  let { config, authority, roleMember, member } = accounts;
+  if (config === undefined) {
+    config = getConfigPDA();
+  }
+
+  if (roleMember === undefined) {
+    roleMember = getRoleMemberPDA(role, member);
+  }
+
+  return program.methods
+    .grantRole({ [role]: {} })
+    .accounts({
+      config,
+      authority,
+      roleMember,
+      member,
+      ...eventCpiAccounts(program.programId),
     })
     .instruction();
 }
@@ -258,6 +485,7 @@ type InitializeContext = {
   config?: PublicKey;
   guardians?: PublicKey;
   minters?: PublicKey;
+  mintRateLimit?: PublicKey;
   authority: PublicKey;
   tbtcMetadata?: PublicKey;
   mplTokenMetadataProgram?: PublicKey;
@@ -273,6 +501,7 @@ export async function initializeIx(
     config,
     guardians,
     minters,
+    mintRateLimit,
     authority,
     tbtcMetadata,
     mplTokenMetadataProgram,
@@ -294,6 +523,10 @@ export async function initializeIx(
     minters = getMintersPDA();
   }
 
+  if (mintRateLimit === undefined) {
+    mintRateLimit = getMintRateLimitPDA();
+  }
+
   if (tbtcMetadata === undefined) {
     tbtcMetadata = getTbtcMetadataPDA();
   }
@@ -309,6 +542,7 @@ export async function initializeIx(
       config,
       guardians,
       minters,
+      mintRateLimit,
       authority,
       tbtcMetadata,
       mplTokenMetadataProgram,
@@ -316,6 +550,95 @@ export async function initializeIx(
     .instruction();
 }
 
+type MigrateConfigContext = {
+  config?: PublicKey;
+  authority: PublicKey;
+};
+
+export async function migrateConfigIx(
+  accounts: MigrateConfigContext
+): Promise<TransactionInstruction> {
+  const program = workspace.Tbtc as Program<Tbtc>;
+
+  let { config, authority } = accounts;
+  if (config === undefined) {
+    config = getConfigPDA();
+  }
+
+  return program.methods
+    .migrateConfig()
+    .accounts({
+      config,
+      authority,
+    })
+    .instruction();
+}
+
+type MigrateMintRateLimitContext = {
+  roleMember?: PublicKey;
+  limitManager: PublicKey;
+  mintRateLimit?: PublicKey;
+};
+
+export async function migrateMintRateLimitIx(
+  accounts: MigrateMintRateLimitContext
+): Promise<TransactionInstruction> {
+  const program = workspace.Tbtc as Program<Tbtc>;
+
+  let { roleMember, limitManager, mintRateLimit } = accounts;
+  if (roleMember === undefined) {
+    roleMember = getRoleMemberPDA("limitManager", limitManager);
+  }
+
+  if (mintRateLimit === undefined) {
+    mintRateLimit = getMintRateLimitPDA();
+  }
+
+  return program.methods
+    .migrateMintRateLimit()
+    .accounts({
+      roleMember,
+      limitManager,
+      mintRateLimit,
+      ...eventCpiAccounts(program.programId),
+    })
+    .instruction();
+}
+
+type MigrateMinterInfoContext = {
+  roleMember?: PublicKey;
+  minterAdmin: PublicKey;
+  minterInfo?: PublicKey;
+  minter: PublicKey;
+};
+
+export async function migrateMinterInfoIx(
+  accounts: MigrateMinterInfoContext,
+  allowance: bigint
+): Promise<TransactionInstruction> {
+  const program = workspace.Tbtc as Program<Tbtc>;
+
+  let { roleMember, minterAdmin, minterInfo, minter } = accounts;
+  if (roleMember === undefined) {
+    roleMember = getRoleMemberPDA("minterAdmin", minterAdmin);
+  }
+
+  if (minterInfo === undefined) {
+    minterInfo = getMinterInfoPDA(minter);
+  }
+
+  return program.methods
+    .migrateMinterInfo(new BN(allowance.toString()))
+    .accounts({
+      roleMember,
+      minterAdmin,
+      minterInfo,
+      minter,
+      ...eventCpiAccounts(program.programId),
+    })
+    .instruction();
+}
+
 type PauseContext = {
   config?: PublicKey;
   guardianInfo?: PublicKey;
@@ -342,13 +665,45 @@ export async function pauseIx(
       config,
       guardianInfo,
       guardian,
+      ...eventCpiAccounts(program.programId),
     })
     .instruction();
 }
 
-type RemoveGuardianContext = {
+type RecoverTokensContext = {
   config?: PublicKey;
   authority: PublicKey;
+  sourceToken: PublicKey;
+  recipientToken: PublicKey;
+};
+
+export async function recoverTokensIx(
+  accounts: RecoverTokensContext,
+  amount: bigint
+): Promise<TransactionInstruction> {
+  const program = workspace.Tbtc as Program<Tbtc>;
+
+  let { config, authority, sourceToken, recipientToken } = accounts;
+  if (config === undefined) {
+    config = getConfigPDA();
+  }
+
+  return program.methods
+    .recoverTokens(new BN(amount.toString()))
+    .accounts({
+      config,
+      authority,
+      sourceToken,
+      recipientToken,
+      ...eventCpiAccounts(program.programId),
+    })
+    .instruction();
+}
+
+type RemoveGuardianContext = {
+  config?: PublicKey;
+  guardianAdmin: PublicKey;
+  roleMember?: PublicKey;
   guardians?: PublicKey;
   guardianInfo?: PublicKey;
   guardian: PublicKey;
@@ -359,11 +714,16 @@ export async function removeGuardianIx(
 ): Promise<TransactionInstruction> {
   const program = workspace.Tbtc as Program<Tbtc>;
 
-  let { config, authority, guardians, guardianInfo, guardian } = accounts;
+  let { config, roleMember, guardianAdmin, guardians, guardianInfo, guardian } =
+    accounts;
   if (config === undefined) {
     config = getConfigPDA();
   }
 
+  if (roleMember === undefined) {
+    roleMember = getRoleMemberPDA("guardianAdmin", guardianAdmin);
+  }
+
   if (guardians === undefined) {
     guardians = getGuardiansPDA();
   }
@@ -376,17 +736,20 @@ export async function removeGuardianIx(
     .removeGuardian()
     .accounts({
       config,
-      authority,
+      roleMember,
+      guardianAdmin,
       guardians,
       guardianInfo,
       guardian,
+      ...eventCpiAccounts(program.programId),
     })
     .instruction();
 }
 
 type RemoveMinterContext = {
   config?: PublicKey;
-  authority: PublicKey;
+  minterAdmin: PublicKey;
+  roleMember?: PublicKey;
   minters?: PublicKey;
   minterInfo?: PublicKey;
   minter: PublicKey;
@@ -397,11 +760,16 @@ export async function removeMinterIx(
 ): Promise<TransactionInstruction> {
   const program = workspace.Tbtc as Program<Tbtc>;
 
-  let { config, authority, minters, minterInfo, minter } = accounts;
+  let { config, roleMember, minterAdmin, minters, minterInfo, minter } =
+    accounts;
   if (config === undefined) {
     config = getConfigPDA();
   }
 
+  if (roleMember === undefined) {
+    roleMember = getRoleMemberPDA("minterAdmin", minterAdmin);
+  }
+
   if (minters === undefined) {
     minters = getMintersPDA();
   }
@@ -414,10 +782,146 @@ export async function removeMinterIx(
     .removeMinter()
     .accounts({
       config,
-      authority,
+      roleMember,
+      minterAdmin,
       minters,
       minterInfo,
       minter,
+      ...eventCpiAccounts(program.programId),
+    })
+    .instruction();
+}
+
+type SetMintRateLimitContext = {
+  limitManager: PublicKey;
+  roleMember?: PublicKey;
+  mintRateLimit?: PublicKey;
+};
+
+type SetMintRateLimitArgs = {
+  capacity: bigint;
+  window: number;
+};
+
+export async function setMintRateLimitIx(
+  accounts: SetMintRateLimitContext,
+  args: SetMintRateLimitArgs
+): Promise<TransactionInstruction> {
+  const program = workspace.Tbtc as Program<Tbtc>;
+
+  let { roleMember, limitManager, mintRateLimit } = accounts;
+  if (roleMember === undefined) {
+    roleMember = getRoleMemberPDA("limitManager", limitManager);
+  }
+
+  if (mintRateLimit === undefined) {
+    mintRateLimit = getMintRateLimitPDA();
+  }
+
+  return program.methods
+    .setMintRateLimit({
+      capacity: new BN(args.capacity.toString()),
+      window: args.window,
+    })
+    .accounts({
+      roleMember,
+      limitManager,
+      mintRateLimit,
+      ...eventCpiAccounts(program.programId),
+    })
+    .instruction();
+}
+
+type SetMinterAllowanceContext = {
+  minterAdmin: PublicKey;
+  roleMember?: PublicKey;
+  minterInfo?: PublicKey;
+  minter: PublicKey;
+};
+
+export async function setMinterAllowanceIx(
+  accounts: SetMinterAllowanceContext,
+  allowance: bigint
+): Promise<TransactionInstruction> {
+  const program = workspace.Tbtc as Program<Tbtc>;
+
+  let { roleMember, minterAdmin, minterInfo, minter } = accounts;
+  if (roleMember === undefined) {
+    roleMember = getRoleMemberPDA("minterAdmin", minterAdmin);
+  }
+
+  if (minterInfo === undefined) {
+    minterInfo = getMinterInfoPDA(minter);
+  }
+
+  return program.methods
+    .setMinterAllowance(new BN(allowance.toString()))
+    .accounts({
+      roleMember,
+      minterAdmin,
+      minterInfo,
+      minter,
+      ...eventCpiAccounts(program.programId),
+    })
+    .instruction();
+}
+
+type RevokeRoleContext = {
+  config?: PublicKey;
+  authority: PublicKey;
+  roleMember?: PublicKey;
+  member: PublicKey;
+};
+
+export async function revokeRoleIx(
+  accounts: RevokeRoleContext,
+  role: Role
+): Promise<TransactionInstruction> {
+  const program = workspace.Tbtc as Program<Tbtc>;
+
+  let { config, authority, roleMember, member } = accounts;
+  if (config === undefined) {
+    config = getConfigPDA();
+  }
+
+  if (roleMember === undefined) {
+    roleMember = getRoleMemberPDA(role, member);
+  }
+
+  return program.methods
+    .revokeRole({ [role]: {} })
+    .accounts({
+      config,
+      authority,
+      roleMember,
+      member,
+      ...eventCpiAccounts(program.programId),
+    })
+    .instruction();
+}
+
+type SetAuthorityChangeDelayContext = {
+  config?: PublicKey;
+  authority: PublicKey;
+};
+
+export async function setAuthorityChangeDelayIx(
+  accounts: SetAuthorityChangeDelayContext,
+  delay: number
+): Promise<TransactionInstruction> {
+  const program = workspace.Tbtc as Program<Tbtc>;
+
+  let { config, authority } = accounts;
+  if (config === undefined) {
+    config = getConfigPDA();
+  }
+
+  return program.methods
+    .setAuthorityChangeDelay(delay)
+    .accounts({
+      config,
+      authority,
+      ...eventCpiAccounts(program.programId),
     })
     .instruction();
 }
@@ -442,13 +946,48 @@ export async function takeAuthorityIx(
     .accounts({
       config,
       pendingAuthority,
+      ...eventCpiAccounts(program.programId),
     })
     .instruction();
 }
 
-type UnpauseContext = {
+type ThawAccountContext = {
+  mint?: PublicKey;
   config?: PublicKey;
   authority: PublicKey;
+  tokenAccount: PublicKey;
+};
+
+export async function thawAccountIx(
+  accounts: ThawAccountContext
+): Promise<TransactionInstruction> {
+  const program = workspace.Tbtc as Program<Tbtc>;
+
+  let { mint, config, authority, tokenAccount } = accounts;
+  if (mint === undefined) {
+    mint = getMintPDA();
+  }
+
+  if (config === undefined) {
+    config = getConfigPDA();
+  }
+
+  return program.methods
+    .thawAccount()
+    .accounts({
+      mint,
+      config,
+      authority,
+      tokenAccount,
+      ...eventCpiAccounts(program.programId),
+    })
+    .instruction();
+}
+
+type UnpauseContext = {
+  config?: PublicKey;
+  unpauser: PublicKey;
+  roleMember?: PublicKey;
 };
 
 export async function unpauseIx(
@@ -456,16 +995,66 @@ export async function unpauseIx(
 ): Promise<TransactionInstruction> {
   const program = workspace.Tbtc as Program<Tbtc>;
 
-  let { config, authority } = accounts;
+  let { config, roleMember, unpauser } = accounts;
   if (config === undefined) {
     config = getConfigPDA();
   }
 
+  if (roleMember === undefined) {
+    roleMember = getRoleMemberPDA("unpauser", unpauser);
+  }
+
   return program.methods
     .unpause()
+    .accounts({
+      config,
+      roleMember,
+      unpauser,
+      ...eventCpiAccounts(program.programId),
+    })
+    .instruction();
+}
+
+type UpdateMetadataContext = {
+  config?: PublicKey;
+  authority: PublicKey;
+  tbtcMetadata?: PublicKey;
+  mplTokenMetadataProgram?: PublicKey;
+};
+
+type UpdateMetadataArgs = {
+  name: string;
+  symbol: string;
+  uri: string;
+};
+
+export async function updateMetadataIx(
+  accounts: UpdateMetadataContext,
+  args: UpdateMetadataArgs
+): Promise<TransactionInstruction> {
+  const program = workspace.Tbtc as Program<Tbtc>;
+
+  let { config, authority, tbtcMetadata, mplTokenMetadataProgram } = accounts;
+  if (config === undefined) {
+    config = getConfigPDA();
+  }
+
+  if (tbtcMetadata === undefined) {
+    tbtcMetadata = getTbtcMetadataPDA();
+  }
+
+  if (mplTokenMetadataProgram === undefined) {
+    mplTokenMetadataProgram = METADATA_PROGRAM_ID;
+  }
+
+  return program.methods
+    .updateMetadata(args)
     .accounts({
       config,
       authority,
+      tbtcMetadata,
+      mplTokenMetadataProgram,
+      ...eventCpiAccounts(program.programId),
     })
     .instruction();
 }
@@ -475,6 +1064,7 @@ type MintContext = {
   config?: PublicKey;
   minterInfo?: PublicKey;
   minter: PublicKey;
+  mintRateLimit?: PublicKey;
   recipientToken: PublicKey;
 };
 
@@ -484,7 +1074,8 @@ export async function mintIx(
 ): Promise<TransactionInstruction> {
   const program = workspace.Tbtc as Program<Tbtc>;
 
-  let { mint, config, minterInfo, minter, recipientToken } = accounts;
+  let { mint, config, minterInfo, minter, mintRateLimit, recipientToken } =
+    accounts;
   if (mint === undefined) {
     mint = getMintPDA();
   }
@@ -497,6 +1088,10 @@ export async function mintIx(
     minterInfo = getMinterInfoPDA(minter);
   }
 
+  if (mintRateLimit === undefined) {
+    mintRateLimit = getMintRateLimitPDA();
+  }
+
   return program.methods
     .mint(amount)
     .accounts({
@@ -504,6 +1099,7 @@ export async function mintIx(
       config,
       minterInfo,
       minter,
+      mintRateLimit,
       recipientToken,
     })
     .instruction();
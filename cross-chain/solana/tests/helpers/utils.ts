@@ -9,7 +9,14 @@ import {
 } from "@certusone/wormhole-sdk/lib/cjs/mock";
 import { NodeWallet } from "@certusone/wormhole-sdk/lib/cjs/solana";
 import * as coreBridge from "@certusone/wormhole-sdk/lib/cjs/solana/wormhole";
-import { Program, web3, workspace } from "@coral-xyz/anchor";
+import {
+  Event,
+  EventParser,
+  Program,
+  utils,
+  web3,
+  workspace,
+} from "@coral-xyz/anchor";
 import {
   Account,
   TokenAccountNotFoundError,
@@ -18,6 +25,7 @@ import {
   getAssociatedTokenAddressSync,
 } from "@solana/spl-token";
 import {
+  AccountMeta,
   Keypair,
   PublicKey,
   SystemProgram,
@@ -70,12 +78,13 @@ export async function generatePayer(funder: Keypair, lamports?: number) {
 export async function getOrCreateAta(
   payer: Keypair,
   mint: PublicKey,
-  owner: PublicKey
+  owner: PublicKey,
+  allowOwnerOffCurve = false
 ) {
   const program = workspace.WormholeGateway as Program<WormholeGateway>;
   const connection = program.provider.connection;
 
-  const token = getAssociatedTokenAddressSync(mint, owner);
+  const token = getAssociatedTokenAddressSync(mint, owner, allowOwnerOffCurve);
   const tokenData: Account = await getAccount(connection, token).catch(
     (err) => {
       if (err instanceof TokenAccountNotFoundError) {
@@ -186,6 +195,28 @@ export async function ethereumGatewaySendTbtc(
   recipient: PublicKey,
   tokenAddress?: string,
   tokenChain?: number
+) {
+  return ethereumGatewaySendTbtcWithPayload(
+    payer,
+    ethereumTokenBridge,
+    amount,
+    fromGateway,
+    toGateway,
+    recipient.toBuffer(),
+    tokenAddress,
+    tokenChain
+  );
+}
+
+export async function ethereumGatewaySendTbtcWithPayload(
+  payer: web3.Keypair,
+  ethereumTokenBridge: MockEthereumTokenBridge,
+  amount: bigint,
+  fromGateway: number[],
+  toGateway: PublicKey,
+  payload: Buffer,
+  tokenAddress?: string,
+  tokenChain?: number
 ) {
   const program = workspace.WormholeGateway as Program<WormholeGateway>;
 
@@ -196,7 +227,7 @@ export async function ethereumGatewaySendTbtc(
     1,
     toGateway.toBuffer().toString("hex"),
     Buffer.from(fromGateway),
-    recipient.toBuffer(),
+    payload,
     0,
     0
   );
@@ -224,9 +255,9 @@ export async function ethereumGatewaySendTbtc(
 export async function expectIxSuccess(
   ixes: TransactionInstruction[],
   signers: Keypair[]
-) {
+): Promise<string> {
   const program = workspace.WormholeGateway as Program<WormholeGateway>;
-  await sendAndConfirmTransaction(
+  return sendAndConfirmTransaction(
     program.provider.connection,
     new Transaction().add(...ixes),
     signers
@@ -260,6 +291,77 @@ export async function expectIxFail(
   }
 }
 
+// Discriminator of the self-CPI instruction carrying an event when programs
+// are built with the `event-cpi` feature.
+const EVENT_IX_TAG = Buffer.from("e445a52e51cb9a1d", "hex");
+
+export function getEventAuthorityPDA(programId: PublicKey): PublicKey {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("__event_authority")],
+    programId
+  )[0];
+}
+
+// Accounts required by instructions that emit events when programs are built
+// with the `event-cpi` feature. They are ignored otherwise.
+export function eventCpiAccounts(programId: PublicKey) {
+  return {
+    eventAuthority: getEventAuthorityPDA(programId),
+    program: programId,
+  };
+}
+
+// Event authorities of other programs, which are passed as the first remaining
+// accounts to instructions calling them when programs are built with the
+// `event-cpi` feature. They are ignored otherwise.
+export function eventCpiRemainingAccounts(
+  ...programIds: PublicKey[]
+): AccountMeta[] {
+  return programIds.map((programId) => ({
+    pubkey: getEventAuthorityPDA(programId),
+    isSigner: false,
+    isWritable: false,
+  }));
+}
+
+export async function getTxEvents(
+  program: Program<any>,
+  txSig: string
+): Promise<Event[]> {
+  const tx = await program.provider.connection.getTransaction(txSig, {
+    commitment: "confirmed",
+    maxSupportedTransactionVersion: 0,
+  });
+  const parser = new EventParser(program.programId, program.coder);
+  const events = Array.from(parser.parseLogs(tx.meta.logMessages));
+
+  // Events emitted through a self-CPI are in the inner instructions instead.
+  const accountKeys = tx.transaction.message.getAccountKeys({
+    accountKeysFromLookups: tx.meta.loadedAddresses,
+  });
+  for (const { instructions } of tx.meta.innerInstructions ?? []) {
+    for (const ix of instructions) {
+      if (!accountKeys.get(ix.programIdIndex).equals(program.programId)) {
+        continue;
+      }
+
+      const data = Buffer.from(utils.bytes.bs58.decode(ix.data));
+      if (!data.subarray(0, 8).equals(EVENT_IX_TAG)) {
+        continue;
+      }
+
+      const event = program.coder.events.decode(
+        data.subarray(8).toString("base64")
+      );
+      if (event !== null) {
+        events.push(event);
+      }
+    }
+  }
+
+  return events;
+}
+
 export function getTokenBridgeCoreEmitter() {
   const [tokenBridgeCoreEmitter] = PublicKey.findProgramAddressSync(
     [Buffer.from("emitter")],
@@ -4,6 +4,7 @@ import * as coreBridge from "@certusone/wormhole-sdk/lib/cjs/solana/wormhole";
 import { BN, Program, workspace } from "@coral-xyz/anchor";
 import { getAssociatedTokenAddressSync } from "@solana/spl-token";
 import {
+  AccountMeta,
   PublicKey,
   SYSVAR_CLOCK_PUBKEY,
   SYSVAR_RENT_PUBKEY,
@@ -11,10 +12,13 @@ import {
 } from "@solana/web3.js";
 import { expect } from "chai";
 import { WormholeGateway } from "../../target/types/wormhole_gateway";
+import * as btcDepositor from "./btcDepositor";
 import {
+  BTC_DEPOSITOR_PROGRAM_ID,
   CORE_BRIDGE_DATA,
   CORE_BRIDGE_PROGRAM_ID,
   ETHEREUM_ENDPOINT,
+  ETHEREUM_TBTC_ADDRESS,
   TBTC_PROGRAM_ID,
   TOKEN_BRIDGE_PROGRAM_ID,
   WORMHOLE_GATEWAY_PROGRAM_ID,
@@ -22,7 +26,12 @@ import {
   WRAPPED_TBTC_MINT,
 } from "./consts";
 import * as tbtc from "./tbtc";
-import { getTokenBridgeCoreEmitter, getTokenBridgeSequence } from "./utils";
+import {
+  eventCpiAccounts,
+  eventCpiRemainingAccounts,
+  getTokenBridgeCoreEmitter,
+  getTokenBridgeSequence,
+} from "./utils";
 
 export function getCustodianPDA(): PublicKey {
   return PublicKey.findProgramAddressSync(
@@ -49,9 +58,15 @@ export function getGatewayInfoPDA(targetChain: number): PublicKey {
   )[0];
 }
 
-export function getWrappedTbtcTokenPDA(): PublicKey {
+export function getWrappedTbtcTokenPDA(
+  wrappedTbtcMint?: PublicKey
+): PublicKey {
+  const seeds = [Buffer.from("wrapped-token")];
+  if (wrappedTbtcMint !== undefined) {
+    seeds.push(wrappedTbtcMint.toBuffer());
+  }
   return PublicKey.findProgramAddressSync(
-    [Buffer.from("wrapped-token")],
+    seeds,
     WORMHOLE_GATEWAY_PROGRAM_ID
   )[0];
 }
@@ -70,6 +85,52 @@ export function getTokenBridgeRedeemerPDA(): PublicKey {
   )[0];
 }
 
+export function getPendingMintPDA(recipient: PublicKey): PublicKey {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("pending-mint"), recipient.toBuffer()],
+    WORMHOLE_GATEWAY_PROGRAM_ID
+  )[0];
+}
+
+export function getAllowedChainPDA(chain: number): PublicKey {
+  const encodedChain = Buffer.alloc(2);
+  encodedChain.writeUInt16LE(chain);
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("allowed-chain"), encodedChain],
+    WORMHOLE_GATEWAY_PROGRAM_ID
+  )[0];
+}
+
+export function getCallTargetPDA(program: PublicKey): PublicKey {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("call-target"), program.toBuffer()],
+    WORMHOLE_GATEWAY_PROGRAM_ID
+  )[0];
+}
+
+export function getCallAuthorityPDA(targetProgram: PublicKey): PublicKey {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("call-authority"), targetProgram.toBuffer()],
+    WORMHOLE_GATEWAY_PROGRAM_ID
+  )[0];
+}
+
+export type Role = "limitManager" | "gatewayManager" | "unpauser";
+
+// Must match the order of the `Role` enum variants.
+const ROLES: Role[] = ["limitManager", "gatewayManager", "unpauser"];
+
+export function getRoleMemberPDA(role: Role, member: PublicKey): PublicKey {
+  return PublicKey.findProgramAddressSync(
+    [
+      Buffer.from("role-member"),
+      Buffer.from([ROLES.indexOf(role)]),
+      member.toBuffer(),
+    ],
+    WORMHOLE_GATEWAY_PROGRAM_ID
+  )[0];
+}
+
 export async function getCustodianData() {
   const program = workspace.WormholeGateway as Program<WormholeGateway>;
   const custodian = getCustodianPDA();
@@ -95,17 +156,42 @@ export async function getMintedAmount(): Promise<bigint> {
   return BigInt(custodianState.mintedAmount.toString());
 }
 
+export async function getPendingMint(recipient: PublicKey) {
+  const program = workspace.WormholeGateway as Program<WormholeGateway>;
+  return program.account.pendingMint.fetch(getPendingMintPDA(recipient));
+}
+
 export async function getGatewayInfo(chain: number) {
   const program = workspace.WormholeGateway as Program<WormholeGateway>;
   const gatewayInfo = getGatewayInfoPDA(chain);
   return program.account.gatewayInfo.fetch(gatewayInfo);
 }
 
+export async function getGatewayMintedAmount(chain: number): Promise<bigint> {
+  const gatewayInfo = await getGatewayInfo(chain);
+  return BigInt(gatewayInfo.mintedAmount.toString());
+}
+
 export async function checkGateway(chain: number, expectedAddress: number[]) {
   const gatewayInfoState = await getGatewayInfo(chain);
   expect(gatewayInfoState.address).to.eql(expectedAddress);
 }
 
+export type CanonicalToken = {
+  chain: number;
+  address: number[];
+};
+
+export const ETHEREUM_TBTC_TOKEN: CanonicalToken = {
+  chain: 2,
+  address: Array.from(
+    Buffer.concat([
+      Buffer.alloc(12),
+      Buffer.from(ETHEREUM_TBTC_ADDRESS.slice(2), "hex"),
+    ])
+  ),
+};
+
 type CancelAuthorityChange = {
   custodian?: PublicKey;
   authority: PublicKey;
@@ -126,6 +212,32 @@ export async function cancelAuthorityChangeIx(
     .accounts({
       custodian,
       authority,
+      ...eventCpiAccounts(program.programId),
+    })
+    .instruction();
+}
+
+type CancelCanonicalTokenUpdateContext = {
+  custodian?: PublicKey;
+  authority: PublicKey;
+};
+
+export async function cancelCanonicalTokenUpdateIx(
+  accounts: CancelCanonicalTokenUpdateContext
+): Promise<TransactionInstruction> {
+  const program = workspace.WormholeGateway as Program<WormholeGateway>;
+
+  let { custodian, authority } = accounts;
+  if (custodian === undefined) {
+    custodian = getCustodianPDA();
+  }
+
+  return program.methods
+    .cancelCanonicalTokenUpdate()
+    .accounts({
+      custodian,
+      authority,
+      ...eventCpiAccounts(program.programId),
     })
     .instruction();
 }
@@ -152,6 +264,33 @@ export async function changeAuthorityIx(
       custodian,
       authority,
       newAuthority,
+      ...eventCpiAccounts(program.programId),
+    })
+    .instruction();
+}
+
+type SetAuthorityChangeDelayContext = {
+  custodian?: PublicKey;
+  authority: PublicKey;
+};
+
+export async function setAuthorityChangeDelayIx(
+  accounts: SetAuthorityChangeDelayContext,
+  delay: number
+): Promise<TransactionInstruction> {
+  const program = workspace.WormholeGateway as Program<WormholeGateway>;
+
+  let { custodian, authority } = accounts;
+  if (custodian === undefined) {
+    custodian = getCustodianPDA();
+  }
+
+  return program.methods
+    .setAuthorityChangeDelay(delay)
+    .accounts({
+      custodian,
+      authority,
+      ...eventCpiAccounts(program.programId),
     })
     .instruction();
 }
@@ -176,18 +315,189 @@ export async function takeAuthorityIx(
     .accounts({
       custodian,
       pendingAuthority,
+      ...eventCpiAccounts(program.programId),
     })
     .instruction();
 }
 
-type UpdateMintingLimitContext = {
+type GrantRoleContext = {
   custodian?: PublicKey;
   authority: PublicKey;
+  roleMember?: PublicKey;
+  member: PublicKey;
 };
 
-export async function updateMintingLimitIx(
-  accounts: UpdateMintingLimitContext,
-  amount: bigint
+export async function grantRoleIx(
+  accounts: GrantRoleContext,
+  role: Role
+): Promise<TransactionInstruction> {
+  const program = workspace.WormholeGateway as Program<WormholeGateway>;
+
+  let { custodian, authority, roleMember, member } = accounts;
+  if (custodian === undefined) {
+    custodian = getCustodianPDA();
+  }
+
+  if (roleMember === undefined) {
+    roleMember = getRoleMemberPDA(role, member);
+  }
+
+  return program.methods
+    .grantRole({ [role]: {} })
+    .accounts({
+      custodian,
+      authority,
+      roleMember,
+      member,
+      ...eventCpiAccounts(program.programId),
+    })
+    .instruction();
+}
+
+type RevokeRoleContext = {
+  custodian?: PublicKey;
+  authority: PublicKey;
+  roleMember?: PublicKey;
+  member: PublicKey;
+};
+
+export async function revokeRoleIx(
+  accounts: RevokeRoleContext,
+  role: Role
+): Promise<TransactionInstruction> {
+  const program = workspace.WormholeGateway as Program<WormholeGateway>;
+
+  let { custodian, authority, roleMember, member } = accounts;
+  if (custodian === undefined) {
+    custodian = getCustodianPDA();
+  }
+
+  if (roleMember === undefined) {
+    roleMember = getRoleMemberPDA(role, member);
+  }
+
+  return program.methods
+    .revokeRole({ [role]: {} })
+    .accounts({
+      custodian,
+      authority,
+      roleMember,
+      member,
+      ...eventCpiAccounts(program.programId),
+    })
+    .instruction();
+}
+
+type MigrateCustodianContext = {
+  custodian?: PublicKey;
+  authority: PublicKey;
+  wrappedTbtcMint?: PublicKey;
+};
+
+export async function migrateCustodianIx(
+  accounts: MigrateCustodianContext,
+  canonicalToken: CanonicalToken
+): Promise<TransactionInstruction> {
+  const program = workspace.WormholeGateway as Program<WormholeGateway>;
+
+  let { custodian, authority, wrappedTbtcMint } = accounts;
+  if (custodian === undefined) {
+    custodian = getCustodianPDA();
+  }
+
+  if (wrappedTbtcMint === undefined) {
+    wrappedTbtcMint = WRAPPED_TBTC_MINT;
+  }
+
+  return program.methods
+    .migrateCustodian(canonicalToken)
+    .accounts({
+      custodian,
+      authority,
+      wrappedTbtcMint,
+    })
+    .instruction();
+}
+
+type MigrateGatewayInfoContext = {
+  custodian?: PublicKey;
+  authority: PublicKey;
+  gatewayInfo?: PublicKey;
+};
+
+type MigrateGatewayInfoArgs = {
+  chain: number;
+  mintingLimit: bigint;
+};
+
+export async function migrateGatewayInfoIx(
+  accounts: MigrateGatewayInfoContext,
+  args: MigrateGatewayInfoArgs
+): Promise<TransactionInstruction> {
+  const program = workspace.WormholeGateway as Program<WormholeGateway>;
+
+  let { custodian, authority, gatewayInfo } = accounts;
+  if (custodian === undefined) {
+    custodian = getCustodianPDA();
+  }
+
+  if (gatewayInfo === undefined) {
+    gatewayInfo = getGatewayInfoPDA(args.chain);
+  }
+
+  return program.methods
+    .migrateGatewayInfo({
+      chain: args.chain,
+      mintingLimit: new BN(args.mintingLimit.toString()),
+    })
+    .accounts({
+      custodian,
+      authority,
+      gatewayInfo,
+      ...eventCpiAccounts(program.programId),
+    })
+    .instruction();
+}
+
+type PauseContext = {
+  custodian?: PublicKey;
+  guardianInfo?: PublicKey;
+  guardian: PublicKey;
+};
+
+export async function pauseIx(
+  accounts: PauseContext
+): Promise<TransactionInstruction> {
+  const program = workspace.WormholeGateway as Program<WormholeGateway>;
+
+  let { custodian, guardianInfo, guardian } = accounts;
+  if (custodian === undefined) {
+    custodian = getCustodianPDA();
+  }
+
+  if (guardianInfo === undefined) {
+    guardianInfo = tbtc.getGuardianInfoPDA(guardian);
+  }
+
+  return program.methods
+    .pause()
+    .accounts({
+      custodian,
+      guardianInfo,
+      guardian,
+      ...eventCpiAccounts(program.programId),
+    })
+    .instruction();
+}
+
+type RequestCanonicalTokenUpdateContext = {
+  custodian?: PublicKey;
+  authority: PublicKey;
+};
+
+export async function requestCanonicalTokenUpdateIx(
+  accounts: RequestCanonicalTokenUpdateContext,
+  canonicalToken: CanonicalToken
 ): Promise<TransactionInstruction> {
   const program = workspace.WormholeGateway as Program<WormholeGateway>;
 
@@ -197,78 +507,1098 @@ export async function updateMintingLimitIx(
   }
 
   return program.methods
-    .updateMintingLimit(new BN(amount.toString()))
+    .requestCanonicalTokenUpdate(canonicalToken)
+    .accounts({
+      custodian,
+      authority,
+      ...eventCpiAccounts(program.programId),
+    })
+    .instruction();
+}
+
+type RecoverTokensContext = {
+  custodian?: PublicKey;
+  authority: PublicKey;
+  sourceToken: PublicKey;
+  recipientToken: PublicKey;
+};
+
+export async function recoverTokensIx(
+  accounts: RecoverTokensContext,
+  amount: bigint
+): Promise<TransactionInstruction> {
+  const program = workspace.WormholeGateway as Program<WormholeGateway>;
+
+  let { custodian, authority, sourceToken, recipientToken } = accounts;
+  if (custodian === undefined) {
+    custodian = getCustodianPDA();
+  }
+
+  return program.methods
+    .recoverTokens(new BN(amount.toString()))
     .accounts({
       custodian,
       authority,
+      sourceToken,
+      recipientToken,
+      ...eventCpiAccounts(program.programId),
+    })
+    .instruction();
+}
+
+type UnpauseContext = {
+  custodian?: PublicKey;
+  roleMember?: PublicKey;
+  unpauser: PublicKey;
+};
+
+export async function unpauseIx(
+  accounts: UnpauseContext
+): Promise<TransactionInstruction> {
+  const program = workspace.WormholeGateway as Program<WormholeGateway>;
+
+  let { custodian, roleMember, unpauser } = accounts;
+  if (custodian === undefined) {
+    custodian = getCustodianPDA();
+  }
+
+  if (roleMember === undefined) {
+    roleMember = getRoleMemberPDA("unpauser", unpauser);
+  }
+
+  return program.methods
+    .unpause()
+    .accounts({
+      custodian,
+      roleMember,
+      unpauser,
+      ...eventCpiAccounts(program.programId),
+    })
+    .instruction();
+}
+
+type UpdateGatewayMintingLimitContext = {
+  gatewayInfo?: PublicKey;
+  roleMember?: PublicKey;
+  limitManager: PublicKey;
+};
+
+type UpdateGatewayMintingLimitArgs = {
+  chain: number;
+  mintingLimit: bigint;
+};
+
+export async function updateGatewayMintingLimitIx(
+  accounts: UpdateGatewayMintingLimitContext,
+  args: UpdateGatewayMintingLimitArgs
+): Promise<TransactionInstruction> {
+  const program = workspace.WormholeGateway as Program<WormholeGateway>;
+
+  let { gatewayInfo, roleMember, limitManager } = accounts;
+  if (gatewayInfo === undefined) {
+    gatewayInfo = getGatewayInfoPDA(args.chain);
+  }
+
+  if (roleMember === undefined) {
+    roleMember = getRoleMemberPDA("limitManager", limitManager);
+  }
+
+  return program.methods
+    .updateGatewayMintingLimit({
+      chain: args.chain,
+      mintingLimit: new BN(args.mintingLimit.toString()),
+    })
+    .accounts({
+      gatewayInfo,
+      roleMember,
+      limitManager,
+      ...eventCpiAccounts(program.programId),
+    })
+    .instruction();
+}
+
+type UpdateMintingLimitContext = {
+  custodian?: PublicKey;
+  roleMember?: PublicKey;
+  limitManager: PublicKey;
+};
+
+export async function updateMintingLimitIx(
+  accounts: UpdateMintingLimitContext,
+  amount: bigint
+): Promise<TransactionInstruction> {
+  const program = workspace.WormholeGateway as Program<WormholeGateway>;
+
+  let { custodian, roleMember, limitManager } = accounts;
+  if (custodian === undefined) {
+    custodian = getCustodianPDA();
+  }
+
+  if (roleMember === undefined) {
+    roleMember = getRoleMemberPDA("limitManager", limitManager);
+  }
+
+  return program.methods
+    .updateMintingLimit(new BN(amount.toString()))
+    .accounts({
+      custodian,
+      roleMember,
+      limitManager,
+      ...eventCpiAccounts(program.programId),
+    })
+    .instruction();
+}
+
+type UpdateReceiveModeContext = {
+  custodian?: PublicKey;
+  roleMember?: PublicKey;
+  limitManager: PublicKey;
+};
+
+export type ReceiveMode = "allOrNothing" | "split" | "queue";
+
+export async function updateReceiveModeIx(
+  accounts: UpdateReceiveModeContext,
+  receiveMode: ReceiveMode
+): Promise<TransactionInstruction> {
+  const program = workspace.WormholeGateway as Program<WormholeGateway>;
+
+  let { custodian, roleMember, limitManager } = accounts;
+  if (custodian === undefined) {
+    custodian = getCustodianPDA();
+  }
+
+  if (roleMember === undefined) {
+    roleMember = getRoleMemberPDA("limitManager", limitManager);
+  }
+
+  return program.methods
+    .updateReceiveMode({ [receiveMode]: {} })
+    .accounts({
+      custodian,
+      roleMember,
+      limitManager,
+      ...eventCpiAccounts(program.programId),
+    })
+    .instruction();
+}
+
+type UpdateCanonicalTokenContext = {
+  authority: PublicKey;
+  custodian?: PublicKey;
+  wrappedTbtcToken?: PublicKey;
+  newWrappedTbtcMint: PublicKey;
+  newWrappedTbtcToken?: PublicKey;
+};
+
+export async function updateCanonicalTokenIx(
+  accounts: UpdateCanonicalTokenContext,
+  canonicalToken: CanonicalToken
+): Promise<TransactionInstruction> {
+  const program = workspace.WormholeGateway as Program<WormholeGateway>;
+
+  let {
+    authority,
+    custodian,
+    wrappedTbtcToken,
+    newWrappedTbtcMint,
+    newWrappedTbtcToken,
+  } = accounts;
+  if (custodian === undefined) {
+    custodian = getCustodianPDA();
+  }
+
+  if (wrappedTbtcToken === undefined) {
+    wrappedTbtcToken = getWrappedTbtcTokenPDA();
+  }
+
+  if (newWrappedTbtcToken === undefined) {
+    newWrappedTbtcToken = getWrappedTbtcTokenPDA(newWrappedTbtcMint);
+  }
+
+  return program.methods
+    .updateCanonicalToken(canonicalToken)
+    .accounts({
+      authority,
+      custodian,
+      wrappedTbtcToken,
+      newWrappedTbtcMint,
+      newWrappedTbtcToken,
+      ...eventCpiAccounts(program.programId),
+    })
+    .instruction();
+}
+
+export type RecipientFormat = "evm" | "bytes32";
+
+type UpdateAllowedChainContext = {
+  allowedChain?: PublicKey;
+  roleMember?: PublicKey;
+  gatewayManager: PublicKey;
+};
+
+type UpdateAllowedChainArgs = {
+  chain: number;
+  recipientFormat: RecipientFormat;
+};
+
+export async function updateAllowedChainIx(
+  accounts: UpdateAllowedChainContext,
+  args: UpdateAllowedChainArgs
+): Promise<TransactionInstruction> {
+  const program = workspace.WormholeGateway as Program<WormholeGateway>;
+  let { allowedChain, roleMember, gatewayManager } = accounts;
+
+  if (allowedChain === undefined) {
+    allowedChain = getAllowedChainPDA(args.chain);
+  }
+
+  if (roleMember === undefined) {
+    roleMember = getRoleMemberPDA("gatewayManager", gatewayManager);
+  }
+
+  return program.methods
+    .updateAllowedChain({
+      chain: args.chain,
+      recipientFormat: { [args.recipientFormat]: {} },
+    })
+    .accounts({
+      allowedChain,
+      roleMember,
+      gatewayManager,
+      ...eventCpiAccounts(program.programId),
+    })
+    .instruction();
+}
+
+type RemoveAllowedChainContext = {
+  allowedChain?: PublicKey;
+  roleMember?: PublicKey;
+  gatewayManager: PublicKey;
+};
+
+type RemoveAllowedChainArgs = {
+  chain: number;
+};
+
+export async function removeAllowedChainIx(
+  accounts: RemoveAllowedChainContext,
+  args: RemoveAllowedChainArgs
+): Promise<TransactionInstruction> {
+  const program = workspace.WormholeGateway as Program<WormholeGateway>;
+  let { allowedChain, roleMember, gatewayManager } = accounts;
+
+  if (allowedChain === undefined) {
+    allowedChain = getAllowedChainPDA(args.chain);
+  }
+
+  if (roleMember === undefined) {
+    roleMember = getRoleMemberPDA("gatewayManager", gatewayManager);
+  }
+
+  return program.methods
+    .removeAllowedChain(args)
+    .accounts({
+      allowedChain,
+      roleMember,
+      gatewayManager,
+      ...eventCpiAccounts(program.programId),
+    })
+    .instruction();
+}
+
+type AddCallTargetContext = {
+  custodian?: PublicKey;
+  callTarget?: PublicKey;
+  program: PublicKey;
+  authority: PublicKey;
+};
+
+export async function addCallTargetIx(
+  accounts: AddCallTargetContext
+): Promise<TransactionInstruction> {
+  const program = workspace.WormholeGateway as Program<WormholeGateway>;
+  let { custodian, callTarget, program: targetProgram, authority } = accounts;
+
+  if (custodian === undefined) {
+    custodian = getCustodianPDA();
+  }
+
+  if (callTarget === undefined) {
+    callTarget = getCallTargetPDA(targetProgram);
+  }
+
+  return program.methods
+    .addCallTarget()
+    .accounts({
+      custodian,
+      callTarget,
+      program: targetProgram,
+      authority,
+      ...eventCpiAccounts(program.programId),
+    })
+    .instruction();
+}
+
+type RemoveCallTargetContext = {
+  custodian?: PublicKey;
+  callTarget?: PublicKey;
+  program: PublicKey;
+  authority: PublicKey;
+};
+
+export async function removeCallTargetIx(
+  accounts: RemoveCallTargetContext
+): Promise<TransactionInstruction> {
+  const program = workspace.WormholeGateway as Program<WormholeGateway>;
+  let { custodian, callTarget, program: targetProgram, authority } = accounts;
+
+  if (custodian === undefined) {
+    custodian = getCustodianPDA();
+  }
+
+  if (callTarget === undefined) {
+    callTarget = getCallTargetPDA(targetProgram);
+  }
+
+  return program.methods
+    .removeCallTarget()
+    .accounts({
+      custodian,
+      callTarget,
+      authority,
+      ...eventCpiAccounts(program.programId),
+    })
+    .instruction();
+}
+
+export async function getAllowedChain(chain: number) {
+  const program = workspace.WormholeGateway as Program<WormholeGateway>;
+  return program.account.allowedChain.fetch(getAllowedChainPDA(chain));
+}
+
+type UpdateL1RedeemerContext = {
+  custodian?: PublicKey;
+  roleMember?: PublicKey;
+  gatewayManager: PublicKey;
+};
+
+export async function updateL1RedeemerIx(
+  accounts: UpdateL1RedeemerContext,
+  l1Redeemer: number[]
+): Promise<TransactionInstruction> {
+  const program = workspace.WormholeGateway as Program<WormholeGateway>;
+
+  let { custodian, roleMember, gatewayManager } = accounts;
+  if (custodian === undefined) {
+    custodian = getCustodianPDA();
+  }
+
+  if (roleMember === undefined) {
+    roleMember = getRoleMemberPDA("gatewayManager", gatewayManager);
+  }
+
+  return program.methods
+    .updateL1Redeemer(l1Redeemer)
+    .accounts({
+      custodian,
+      roleMember,
+      gatewayManager,
+      ...eventCpiAccounts(program.programId),
+    })
+    .instruction();
+}
+
+type UpdatePayloadSenderContext = {
+  custodian?: PublicKey;
+  roleMember?: PublicKey;
+  gatewayManager: PublicKey;
+};
+
+export async function updatePayloadSenderIx(
+  accounts: UpdatePayloadSenderContext,
+  payloadSender: PublicKey
+): Promise<TransactionInstruction> {
+  const program = workspace.WormholeGateway as Program<WormholeGateway>;
+
+  let { custodian, roleMember, gatewayManager } = accounts;
+  if (custodian === undefined) {
+    custodian = getCustodianPDA();
+  }
+
+  if (roleMember === undefined) {
+    roleMember = getRoleMemberPDA("gatewayManager", gatewayManager);
+  }
+
+  return program.methods
+    .updatePayloadSender(payloadSender)
+    .accounts({
+      custodian,
+      roleMember,
+      gatewayManager,
+      ...eventCpiAccounts(program.programId),
+    })
+    .instruction();
+}
+
+type UpdateMaxRelayerFeeContext = {
+  custodian?: PublicKey;
+  roleMember?: PublicKey;
+  gatewayManager: PublicKey;
+};
+
+export async function updateMaxRelayerFeeIx(
+  accounts: UpdateMaxRelayerFeeContext,
+  maxRelayerFeeBps: number
+): Promise<TransactionInstruction> {
+  const program = workspace.WormholeGateway as Program<WormholeGateway>;
+
+  let { custodian, roleMember, gatewayManager } = accounts;
+  if (custodian === undefined) {
+    custodian = getCustodianPDA();
+  }
+
+  if (roleMember === undefined) {
+    roleMember = getRoleMemberPDA("gatewayManager", gatewayManager);
+  }
+
+  return program.methods
+    .updateMaxRelayerFee(maxRelayerFeeBps)
+    .accounts({
+      custodian,
+      roleMember,
+      gatewayManager,
+      ...eventCpiAccounts(program.programId),
+    })
+    .instruction();
+}
+
+type UpdateGatewayAddressContext = {
+  gatewayInfo?: PublicKey;
+  roleMember?: PublicKey;
+  gatewayManager: PublicKey;
+};
+
+type UpdateGatewayAddressArgs = {
+  chain: number;
+  address: number[];
+};
+
+export async function updateGatewayAddress(
+  accounts: UpdateGatewayAddressContext,
+  args: UpdateGatewayAddressArgs
+) {
+  const program = workspace.WormholeGateway as Program<WormholeGateway>;
+  let { gatewayInfo, roleMember, gatewayManager } = accounts;
+
+  if (gatewayInfo === undefined) {
+    gatewayInfo = getGatewayInfoPDA(args.chain);
+  }
+
+  if (roleMember === undefined) {
+    roleMember = getRoleMemberPDA("gatewayManager", gatewayManager);
+  }
+
+  return program.methods
+    .updateGatewayAddress(args)
+    .accounts({
+      gatewayInfo,
+      roleMember,
+      gatewayManager,
+      ...eventCpiAccounts(program.programId),
+    })
+    .instruction();
+}
+
+type RemoveGatewayAddressContext = {
+  gatewayInfo?: PublicKey;
+  roleMember?: PublicKey;
+  gatewayManager: PublicKey;
+};
+
+type RemoveGatewayAddressArgs = {
+  chain: number;
+};
+
+export async function removeGatewayAddressIx(
+  accounts: RemoveGatewayAddressContext,
+  args: RemoveGatewayAddressArgs
+): Promise<TransactionInstruction> {
+  const program = workspace.WormholeGateway as Program<WormholeGateway>;
+  let { gatewayInfo, roleMember, gatewayManager } = accounts;
+
+  if (gatewayInfo === undefined) {
+    gatewayInfo = getGatewayInfoPDA(args.chain);
+  }
+
+  if (roleMember === undefined) {
+    roleMember = getRoleMemberPDA("gatewayManager", gatewayManager);
+  }
+
+  return program.methods
+    .removeGatewayAddress(args)
+    .accounts({
+      gatewayInfo,
+      roleMember,
+      gatewayManager,
+      ...eventCpiAccounts(program.programId),
+    })
+    .instruction();
+}
+
+type UpdateGatewayDisabledContext = {
+  gatewayInfo?: PublicKey;
+  roleMember?: PublicKey;
+  gatewayManager: PublicKey;
+};
+
+type UpdateGatewayDisabledArgs = {
+  chain: number;
+  disabled: boolean;
+};
+
+export async function updateGatewayDisabledIx(
+  accounts: UpdateGatewayDisabledContext,
+  args: UpdateGatewayDisabledArgs
+): Promise<TransactionInstruction> {
+  const program = workspace.WormholeGateway as Program<WormholeGateway>;
+  let { gatewayInfo, roleMember, gatewayManager } = accounts;
+
+  if (gatewayInfo === undefined) {
+    gatewayInfo = getGatewayInfoPDA(args.chain);
+  }
+
+  if (roleMember === undefined) {
+    roleMember = getRoleMemberPDA("gatewayManager", gatewayManager);
+  }
+
+  return program.methods
+    .updateGatewayDisabled(args)
+    .accounts({
+      gatewayInfo,
+      roleMember,
+      gatewayManager,
+      ...eventCpiAccounts(program.programId),
+    })
+    .instruction();
+}
+
+type DepositWormholeTbtcContext = {
+  custodian?: PublicKey;
+  wrappedTbtcToken?: PublicKey;
+  wrappedTbtcMint?: PublicKey;
+  tbtcMint?: PublicKey;
+  recipientWrappedToken: PublicKey;
+  recipientToken: PublicKey;
+  recipient: PublicKey;
+  tbtcConfig?: PublicKey;
+  tbtcMinterInfo?: PublicKey;
+  tbtcMintRateLimit?: PublicKey;
+  tbtcProgram?: PublicKey;
+};
+
+export async function depositWormholeTbtcIx(
+  accounts: DepositWormholeTbtcContext,
+  amount: bigint
+): Promise<TransactionInstruction> {
+  const program = workspace.WormholeGateway as Program<WormholeGateway>;
+  let {
+    custodian,
+    wrappedTbtcToken,
+    wrappedTbtcMint,
+    tbtcMint,
+    recipientWrappedToken,
+    recipientToken,
+    recipient,
+    tbtcConfig,
+    tbtcMinterInfo,
+    tbtcMintRateLimit,
+    tbtcProgram,
+  } = accounts;
+
+  if (custodian === undefined) {
+    custodian = getCustodianPDA();
+  }
+
+  if (wrappedTbtcToken === undefined) {
+    wrappedTbtcToken = getWrappedTbtcTokenPDA();
+  }
+
+  if (wrappedTbtcMint === undefined) {
+    wrappedTbtcMint = WRAPPED_TBTC_MINT;
+  }
+
+  if (tbtcMint === undefined) {
+    tbtcMint = tbtc.getMintPDA();
+  }
+
+  if (tbtcConfig === undefined) {
+    tbtcConfig = tbtc.getConfigPDA();
+  }
+
+  if (tbtcMinterInfo === undefined) {
+    tbtcMinterInfo = tbtc.getMinterInfoPDA(custodian);
+  }
+
+  if (tbtcMintRateLimit === undefined) {
+    tbtcMintRateLimit = tbtc.getMintRateLimitPDA();
+  }
+
+  if (tbtcProgram === undefined) {
+    tbtcProgram = TBTC_PROGRAM_ID;
+  }
+
+  return program.methods
+    .depositWormholeTbtc(new BN(amount.toString()))
+    .accounts({
+      custodian,
+      wrappedTbtcToken,
+      wrappedTbtcMint,
+      tbtcMint,
+      recipientWrappedToken,
+      recipientToken,
+      recipient,
+      tbtcConfig,
+      tbtcMinterInfo,
+      tbtcMintRateLimit,
+      tbtcProgram,
+    })
+    .instruction();
+}
+
+type ReceiveTbtcContext = {
+  payer: PublicKey;
+  custodian?: PublicKey;
+  postedVaa?: PublicKey;
+  gatewayInfo?: PublicKey;
+  tokenBridgeClaim?: PublicKey;
+  wrappedTbtcToken?: PublicKey;
+  wrappedTbtcMint?: PublicKey;
+  tbtcMint?: PublicKey;
+  recipientToken?: PublicKey;
+  recipient: PublicKey;
+  payerToken?: PublicKey;
+  recipientWrappedToken?: PublicKey;
+  pendingMint?: PublicKey;
+  depositRecord?: PublicKey;
+  btcDepositorConfig?: PublicKey;
+  tbtcConfig?: PublicKey;
+  tbtcMinterInfo?: PublicKey;
+  tbtcMintRateLimit?: PublicKey;
+  tokenBridgeConfig?: PublicKey;
+  tokenBridgeRegisteredEmitter?: PublicKey;
+  //tokenBridgeRedeemer?: PublicKey;
+  tokenBridgeWrappedAsset?: PublicKey;
+  tokenBridgeMintAuthority?: PublicKey;
+  rent?: PublicKey;
+  tbtcProgram?: PublicKey;
+  btcDepositorProgram?: PublicKey;
+  tokenBridgeProgram?: PublicKey;
+  coreBridgeProgram?: PublicKey;
+};
+
+export async function receiveTbtcIx(
+  accounts: ReceiveTbtcContext,
+  signedVaa: Buffer
+): Promise<TransactionInstruction> {
+  const parsed = parseVaa(signedVaa);
+
+  const program = workspace.WormholeGateway as Program<WormholeGateway>;
+  let {
+    payer,
+    custodian,
+    postedVaa,
+    gatewayInfo,
+    tokenBridgeClaim,
+    wrappedTbtcToken,
+    wrappedTbtcMint,
+    tbtcMint,
+    recipientToken,
+    recipient,
+    payerToken,
+    recipientWrappedToken,
+    pendingMint,
+    depositRecord,
+    btcDepositorConfig,
+    tbtcConfig,
+    tbtcMinterInfo,
+    tbtcMintRateLimit,
+    tokenBridgeConfig,
+    tokenBridgeRegisteredEmitter,
+    //tokenBridgeRedeemer,
+    tokenBridgeWrappedAsset,
+    tokenBridgeMintAuthority,
+    rent,
+    tbtcProgram,
+    btcDepositorProgram,
+    tokenBridgeProgram,
+    coreBridgeProgram,
+  } = accounts;
+
+  if (custodian === undefined) {
+    custodian = getCustodianPDA();
+  }
+
+  if (postedVaa === undefined) {
+    postedVaa = coreBridge.derivePostedVaaKey(
+      CORE_BRIDGE_PROGRAM_ID,
+      parsed.hash
+    );
+  }
+
+  if (gatewayInfo === undefined) {
+    gatewayInfo = getGatewayInfoPDA(parsed.emitterChain);
+  }
+
+  if (tokenBridgeClaim === undefined) {
+    tokenBridgeClaim = coreBridge.deriveClaimKey(
+      TOKEN_BRIDGE_PROGRAM_ID,
+      parsed.emitterAddress,
+      parsed.emitterChain,
+      parsed.sequence
+    );
+  }
+
+  if (wrappedTbtcToken === undefined) {
+    wrappedTbtcToken = getWrappedTbtcTokenPDA();
+  }
+
+  if (wrappedTbtcMint === undefined) {
+    wrappedTbtcMint = WRAPPED_TBTC_MINT;
+  }
+
+  if (tbtcMint === undefined) {
+    tbtcMint = tbtc.getMintPDA();
+  }
+
+  if (recipientToken === undefined) {
+    recipientToken = getAssociatedTokenAddressSync(tbtcMint, recipient, true);
+  }
+
+  if (recipientWrappedToken == undefined) {
+    recipientWrappedToken = getAssociatedTokenAddressSync(
+      wrappedTbtcMint,
+      recipient,
+      true
+    );
+  }
+
+  if (pendingMint === undefined) {
+    pendingMint = getPendingMintPDA(recipient);
+  }
+
+  // The BTC depositor accounts are only needed to finalize a deposit.
+  if (depositRecord !== undefined) {
+    if (btcDepositorConfig === undefined) {
+      btcDepositorConfig = btcDepositor.getConfigPDA();
+    }
+
+    if (btcDepositorProgram === undefined) {
+      btcDepositorProgram = BTC_DEPOSITOR_PROGRAM_ID;
+    }
+  }
+
+  if (tbtcConfig === undefined) {
+    tbtcConfig = tbtc.getConfigPDA();
+  }
+
+  if (tbtcMinterInfo === undefined) {
+    tbtcMinterInfo = tbtc.getMinterInfoPDA(custodian);
+  }
+
+  if (tbtcMintRateLimit === undefined) {
+    tbtcMintRateLimit = tbtc.getMintRateLimitPDA();
+  }
+
+  if (tokenBridgeConfig === undefined) {
+    tokenBridgeConfig = tokenBridge.deriveTokenBridgeConfigKey(
+      TOKEN_BRIDGE_PROGRAM_ID
+    );
+  }
+
+  if (tokenBridgeRegisteredEmitter === undefined) {
+    tokenBridgeRegisteredEmitter = ETHEREUM_ENDPOINT;
+  }
+
+  // if (tokenBridgeRedeemer === undefined) {
+  //   tokenBridgeRedeemer = tokenBridge.deriveRedeemerAccountKey(
+  //     WORMHOLE_GATEWAY_PROGRAM_ID
+  //   );
+  // }
+
+  if (tokenBridgeWrappedAsset === undefined) {
+    tokenBridgeWrappedAsset = WRAPPED_TBTC_ASSET;
+  }
+
+  if (tokenBridgeMintAuthority === undefined) {
+    tokenBridgeMintAuthority = tokenBridge.deriveMintAuthorityKey(
+      TOKEN_BRIDGE_PROGRAM_ID
+    );
+  }
+
+  if (rent === undefined) {
+    rent = SYSVAR_RENT_PUBKEY;
+  }
+
+  if (tbtcProgram === undefined) {
+    tbtcProgram = TBTC_PROGRAM_ID;
+  }
+
+  if (tokenBridgeProgram === undefined) {
+    tokenBridgeProgram = TOKEN_BRIDGE_PROGRAM_ID;
+  }
+
+  if (coreBridgeProgram === undefined) {
+    coreBridgeProgram = CORE_BRIDGE_PROGRAM_ID;
+  }
+
+  return program.methods
+    .receiveTbtc(Array.from(parsed.hash))
+    .accounts({
+      payer,
+      custodian,
+      postedVaa,
+      gatewayInfo,
+      tokenBridgeClaim,
+      wrappedTbtcToken,
+      tbtcMint,
+      recipientToken,
+      recipient,
+      payerToken: payerToken ?? null,
+      recipientWrappedToken,
+      pendingMint,
+      depositRecord: depositRecord ?? null,
+      btcDepositorConfig: btcDepositorConfig ?? null,
+      tbtcConfig,
+      tbtcMinterInfo,
+      tbtcMintRateLimit,
+      wrappedTbtcMint,
+      tokenBridgeConfig,
+      tokenBridgeRegisteredEmitter,
+      //tokenBridgeRedeemer,
+      tokenBridgeWrappedAsset,
+      tokenBridgeMintAuthority,
+      rent,
+      tbtcProgram,
+      btcDepositorProgram: btcDepositorProgram ?? null,
+      tokenBridgeProgram,
+      coreBridgeProgram,
+      ...eventCpiAccounts(program.programId),
+    })
+    .remainingAccounts(
+      depositRecord === undefined
+        ? []
+        : eventCpiRemainingAccounts(BTC_DEPOSITOR_PROGRAM_ID)
+    )
+    .instruction();
+}
+
+type TransferPayload = {
+  recipient: PublicKey;
+  relayerFee?: bigint;
+  depositKey?: Buffer;
+  memo?: Buffer;
+};
+
+export function encodeTransferPayload(payload: TransferPayload): Buffer {
+  const relayerFee = Buffer.alloc(8);
+  relayerFee.writeBigUInt64BE(payload.relayerFee ?? BigInt(0));
+  const memo = payload.memo ?? Buffer.alloc(0);
+
+  // Version 1. A zero deposit key means no deposit is referenced.
+  return Buffer.concat([
+    Buffer.from([1]),
+    payload.recipient.toBuffer(),
+    relayerFee,
+    payload.depositKey ?? Buffer.alloc(32),
+    Buffer.from([memo.length]),
+    memo,
+  ]);
+}
+
+type ReceiveAndCallPayload = {
+  targetProgram: PublicKey;
+  recipient: PublicKey;
+  sender: Buffer;
+  message: Buffer;
+};
+
+export function encodeReceiveAndCallPayload(
+  payload: ReceiveAndCallPayload,
+  version?: number
+): Buffer {
+  // The message is zero-padded to its fixed size.
+  const message = Buffer.alloc(128);
+  payload.message.copy(message);
+
+  return Buffer.concat([
+    Buffer.from([version ?? 2]),
+    payload.targetProgram.toBuffer(),
+    payload.recipient.toBuffer(),
+    payload.sender,
+    message,
+  ]);
+}
+
+type ReceiveTbtcAndCallContext = {
+  payer: PublicKey;
+  recipientToken: PublicKey;
+  recipient: PublicKey;
+  targetProgram: PublicKey;
+  callTarget?: PublicKey;
+  remainingAccounts?: AccountMeta[];
+};
+
+export async function receiveTbtcAndCallIx(
+  accounts: ReceiveTbtcAndCallContext,
+  signedVaa: Buffer
+): Promise<TransactionInstruction> {
+  const parsed = parseVaa(signedVaa);
+
+  const program = workspace.WormholeGateway as Program<WormholeGateway>;
+  let {
+    payer,
+    recipientToken,
+    recipient,
+    targetProgram,
+    callTarget,
+    remainingAccounts,
+  } = accounts;
+
+  if (callTarget === undefined) {
+    callTarget = getCallTargetPDA(targetProgram);
+  }
+
+  const custodian = getCustodianPDA();
+
+  return program.methods
+    .receiveTbtcAndCall(Array.from(parsed.hash))
+    .accounts({
+      payer,
+      custodian,
+      postedVaa: coreBridge.derivePostedVaaKey(
+        CORE_BRIDGE_PROGRAM_ID,
+        parsed.hash
+      ),
+      gatewayInfo: getGatewayInfoPDA(parsed.emitterChain),
+      tokenBridgeClaim: coreBridge.deriveClaimKey(
+        TOKEN_BRIDGE_PROGRAM_ID,
+        parsed.emitterAddress,
+        parsed.emitterChain,
+        parsed.sequence
+      ),
+      wrappedTbtcToken: getWrappedTbtcTokenPDA(),
+      wrappedTbtcMint: WRAPPED_TBTC_MINT,
+      tbtcMint: tbtc.getMintPDA(),
+      recipientToken,
+      recipient,
+      callTarget,
+      targetProgram,
+      callAuthority: getCallAuthorityPDA(targetProgram),
+      tbtcConfig: tbtc.getConfigPDA(),
+      tbtcMinterInfo: tbtc.getMinterInfoPDA(custodian),
+      tbtcMintRateLimit: tbtc.getMintRateLimitPDA(),
+      tokenBridgeConfig: tokenBridge.deriveTokenBridgeConfigKey(
+        TOKEN_BRIDGE_PROGRAM_ID
+      ),
+      tokenBridgeRegisteredEmitter: ETHEREUM_ENDPOINT,
+      tokenBridgeWrappedAsset: WRAPPED_TBTC_ASSET,
+      tokenBridgeMintAuthority: tokenBridge.deriveMintAuthorityKey(
+        TOKEN_BRIDGE_PROGRAM_ID
+      ),
+      rent: SYSVAR_RENT_PUBKEY,
+      tbtcProgram: TBTC_PROGRAM_ID,
+      tokenBridgeProgram: TOKEN_BRIDGE_PROGRAM_ID,
+      coreBridgeProgram: CORE_BRIDGE_PROGRAM_ID,
+      ...eventCpiAccounts(program.programId),
     })
+    .remainingAccounts(remainingAccounts ?? [])
     .instruction();
 }
 
-type UpdateGatewayAddressContext = {
-  custodian?: PublicKey;
-  gatewayInfo?: PublicKey;
-  authority: PublicKey;
+type ReceiveTbtcWithoutCallContext = {
+  payer: PublicKey;
+  recipientToken: PublicKey;
+  recipient: PublicKey;
 };
 
-type UpdateGatewayAddressArgs = {
-  chain: number;
-  address: number[];
-};
+export async function receiveTbtcWithoutCallIx(
+  accounts: ReceiveTbtcWithoutCallContext,
+  signedVaa: Buffer
+): Promise<TransactionInstruction> {
+  const parsed = parseVaa(signedVaa);
 
-export async function updateGatewayAddress(
-  accounts: UpdateGatewayAddressContext,
-  args: UpdateGatewayAddressArgs
-) {
   const program = workspace.WormholeGateway as Program<WormholeGateway>;
-  let { custodian, gatewayInfo, authority } = accounts;
-
-  if (custodian === undefined) {
-    custodian = getCustodianPDA();
-  }
+  const { payer, recipientToken, recipient } = accounts;
 
-  if (gatewayInfo === undefined) {
-    gatewayInfo = getGatewayInfoPDA(args.chain);
-  }
+  const custodian = getCustodianPDA();
 
   return program.methods
-    .updateGatewayAddress(args)
+    .receiveTbtcWithoutCall(Array.from(parsed.hash))
     .accounts({
+      payer,
       custodian,
-      gatewayInfo,
-      authority,
+      postedVaa: coreBridge.derivePostedVaaKey(
+        CORE_BRIDGE_PROGRAM_ID,
+        parsed.hash
+      ),
+      gatewayInfo: getGatewayInfoPDA(parsed.emitterChain),
+      tokenBridgeClaim: coreBridge.deriveClaimKey(
+        TOKEN_BRIDGE_PROGRAM_ID,
+        parsed.emitterAddress,
+        parsed.emitterChain,
+        parsed.sequence
+      ),
+      wrappedTbtcToken: getWrappedTbtcTokenPDA(),
+      wrappedTbtcMint: WRAPPED_TBTC_MINT,
+      tbtcMint: tbtc.getMintPDA(),
+      recipientToken,
+      recipient,
+      tbtcConfig: tbtc.getConfigPDA(),
+      tbtcMinterInfo: tbtc.getMinterInfoPDA(custodian),
+      tbtcMintRateLimit: tbtc.getMintRateLimitPDA(),
+      tokenBridgeConfig: tokenBridge.deriveTokenBridgeConfigKey(
+        TOKEN_BRIDGE_PROGRAM_ID
+      ),
+      tokenBridgeRegisteredEmitter: ETHEREUM_ENDPOINT,
+      tokenBridgeWrappedAsset: WRAPPED_TBTC_ASSET,
+      tokenBridgeMintAuthority: tokenBridge.deriveMintAuthorityKey(
+        TOKEN_BRIDGE_PROGRAM_ID
+      ),
+      rent: SYSVAR_RENT_PUBKEY,
+      tbtcProgram: TBTC_PROGRAM_ID,
+      tokenBridgeProgram: TOKEN_BRIDGE_PROGRAM_ID,
+      coreBridgeProgram: CORE_BRIDGE_PROGRAM_ID,
+      ...eventCpiAccounts(program.programId),
     })
     .instruction();
 }
 
-type DepositWormholeTbtcContext = {
+type ClaimPendingMintContext = {
   custodian?: PublicKey;
-  wrappedTbtcToken?: PublicKey;
-  wrappedTbtcMint?: PublicKey;
+  pendingMint?: PublicKey;
   tbtcMint?: PublicKey;
-  recipientWrappedToken: PublicKey;
   recipientToken: PublicKey;
   recipient: PublicKey;
   tbtcConfig?: PublicKey;
   tbtcMinterInfo?: PublicKey;
+  tbtcMintRateLimit?: PublicKey;
   tbtcProgram?: PublicKey;
 };
 
-export async function depositWormholeTbtcIx(
-  accounts: DepositWormholeTbtcContext,
-  amount: bigint
+export async function claimPendingMintIx(
+  accounts: ClaimPendingMintContext
 ): Promise<TransactionInstruction> {
   const program = workspace.WormholeGateway as Program<WormholeGateway>;
   let {
     custodian,
-    wrappedTbtcToken,
-    wrappedTbtcMint,
+    pendingMint,
     tbtcMint,
-    recipientWrappedToken,
     recipientToken,
     recipient,
     tbtcConfig,
     tbtcMinterInfo,
+    tbtcMintRateLimit,
     tbtcProgram,
   } = accounts;
 
@@ -276,12 +1606,8 @@ export async function depositWormholeTbtcIx(
     custodian = getCustodianPDA();
   }
 
-  if (wrappedTbtcToken === undefined) {
-    wrappedTbtcToken = getWrappedTbtcTokenPDA();
-  }
-
-  if (wrappedTbtcMint === undefined) {
-    wrappedTbtcMint = WRAPPED_TBTC_MINT;
+  if (pendingMint === undefined) {
+    pendingMint = getPendingMintPDA(recipient);
   }
 
   if (tbtcMint === undefined) {
@@ -296,78 +1622,87 @@ export async function depositWormholeTbtcIx(
     tbtcMinterInfo = tbtc.getMinterInfoPDA(custodian);
   }
 
+  if (tbtcMintRateLimit === undefined) {
+    tbtcMintRateLimit = tbtc.getMintRateLimitPDA();
+  }
+
   if (tbtcProgram === undefined) {
     tbtcProgram = TBTC_PROGRAM_ID;
   }
 
   return program.methods
-    .depositWormholeTbtc(new BN(amount.toString()))
+    .claimPendingMint()
     .accounts({
       custodian,
-      wrappedTbtcToken,
-      wrappedTbtcMint,
+      pendingMint,
       tbtcMint,
-      recipientWrappedToken,
       recipientToken,
       recipient,
       tbtcConfig,
       tbtcMinterInfo,
+      tbtcMintRateLimit,
       tbtcProgram,
+      ...eventCpiAccounts(program.programId),
     })
     .instruction();
 }
 
-type ReceiveTbtcContext = {
-  payer: PublicKey;
+type SendTbtcGatewayContext = {
   custodian?: PublicKey;
-  postedVaa?: PublicKey;
-  tokenBridgeClaim?: PublicKey;
+  allowedChain?: PublicKey;
+  gatewayInfo?: PublicKey;
   wrappedTbtcToken?: PublicKey;
   wrappedTbtcMint?: PublicKey;
   tbtcMint?: PublicKey;
-  recipientToken: PublicKey;
-  recipient: PublicKey;
-  recipientWrappedToken?: PublicKey;
-  tbtcConfig?: PublicKey;
-  tbtcMinterInfo?: PublicKey;
+  senderToken: PublicKey;
+  sender: PublicKey;
   tokenBridgeConfig?: PublicKey;
-  tokenBridgeRegisteredEmitter?: PublicKey;
-  //tokenBridgeRedeemer?: PublicKey;
   tokenBridgeWrappedAsset?: PublicKey;
-  tokenBridgeMintAuthority?: PublicKey;
+  tokenBridgeTransferAuthority?: PublicKey;
+  coreBridgeData?: PublicKey;
+  coreMessage?: PublicKey;
+  tokenBridgeCoreEmitter?: PublicKey;
+  coreEmitterSequence?: PublicKey;
+  coreFeeCollector?: PublicKey;
+  clock?: PublicKey;
+  tokenBridgeSender?: PublicKey;
   rent?: PublicKey;
-  tbtcProgram?: PublicKey;
   tokenBridgeProgram?: PublicKey;
   coreBridgeProgram?: PublicKey;
 };
 
-export async function receiveTbtcIx(
-  accounts: ReceiveTbtcContext,
-  signedVaa: Buffer
-): Promise<TransactionInstruction> {
-  const parsed = parseVaa(signedVaa);
+type SendTbtcGatewayArgs = {
+  amount: BN;
+  recipientChain: number;
+  recipient: number[];
+  nonce: number;
+};
 
+export async function sendTbtcGatewayIx(
+  accounts: SendTbtcGatewayContext,
+  args: SendTbtcGatewayArgs
+): Promise<TransactionInstruction> {
   const program = workspace.WormholeGateway as Program<WormholeGateway>;
   let {
-    payer,
     custodian,
-    postedVaa,
-    tokenBridgeClaim,
+    allowedChain,
+    gatewayInfo,
     wrappedTbtcToken,
     wrappedTbtcMint,
     tbtcMint,
-    recipientToken,
-    recipient,
-    recipientWrappedToken,
-    tbtcConfig,
-    tbtcMinterInfo,
+    senderToken,
+    sender,
     tokenBridgeConfig,
-    tokenBridgeRegisteredEmitter,
-    //tokenBridgeRedeemer,
     tokenBridgeWrappedAsset,
-    tokenBridgeMintAuthority,
+    tokenBridgeTransferAuthority,
+    coreBridgeData,
+    coreMessage,
+    tokenBridgeCoreEmitter,
+    coreEmitterSequence,
+    coreFeeCollector,
+    clock,
+    tokenBridgeSender,
     rent,
-    tbtcProgram,
     tokenBridgeProgram,
     coreBridgeProgram,
   } = accounts;
@@ -376,20 +1711,12 @@ export async function receiveTbtcIx(
     custodian = getCustodianPDA();
   }
 
-  if (postedVaa === undefined) {
-    postedVaa = coreBridge.derivePostedVaaKey(
-      CORE_BRIDGE_PROGRAM_ID,
-      parsed.hash
-    );
+  if (allowedChain === undefined) {
+    allowedChain = getAllowedChainPDA(args.recipientChain);
   }
 
-  if (tokenBridgeClaim === undefined) {
-    tokenBridgeClaim = coreBridge.deriveClaimKey(
-      TOKEN_BRIDGE_PROGRAM_ID,
-      parsed.emitterAddress,
-      parsed.emitterChain,
-      parsed.sequence
-    );
+  if (gatewayInfo === undefined) {
+    gatewayInfo = getGatewayInfoPDA(args.recipientChain);
   }
 
   if (wrappedTbtcToken === undefined) {
@@ -404,53 +1731,58 @@ export async function receiveTbtcIx(
     tbtcMint = tbtc.getMintPDA();
   }
 
-  if (recipientWrappedToken == undefined) {
-    recipientWrappedToken = getAssociatedTokenAddressSync(
-      wrappedTbtcMint,
-      recipient
+  if (tokenBridgeConfig === undefined) {
+    tokenBridgeConfig = tokenBridge.deriveTokenBridgeConfigKey(
+      TOKEN_BRIDGE_PROGRAM_ID
     );
   }
 
-  if (tbtcConfig === undefined) {
-    tbtcConfig = tbtc.getConfigPDA();
-  }
-
-  if (tbtcMinterInfo === undefined) {
-    tbtcMinterInfo = tbtc.getMinterInfoPDA(custodian);
+  if (tokenBridgeWrappedAsset === undefined) {
+    tokenBridgeWrappedAsset = WRAPPED_TBTC_ASSET;
   }
 
-  if (tokenBridgeConfig === undefined) {
-    tokenBridgeConfig = tokenBridge.deriveTokenBridgeConfigKey(
+  if (tokenBridgeTransferAuthority === undefined) {
+    tokenBridgeTransferAuthority = tokenBridge.deriveAuthoritySignerKey(
       TOKEN_BRIDGE_PROGRAM_ID
     );
   }
 
-  if (tokenBridgeRegisteredEmitter === undefined) {
-    tokenBridgeRegisteredEmitter = ETHEREUM_ENDPOINT;
+  if (coreBridgeData === undefined) {
+    coreBridgeData = CORE_BRIDGE_DATA;
   }
 
-  // if (tokenBridgeRedeemer === undefined) {
-  //   tokenBridgeRedeemer = tokenBridge.deriveRedeemerAccountKey(
-  //     WORMHOLE_GATEWAY_PROGRAM_ID
-  //   );
-  // }
+  if (coreMessage === undefined) {
+    const sequence = await getTokenBridgeSequence();
+    coreMessage = getCoreMessagePDA(sequence);
+  }
 
-  if (tokenBridgeWrappedAsset === undefined) {
-    tokenBridgeWrappedAsset = WRAPPED_TBTC_ASSET;
+  if (tokenBridgeCoreEmitter === undefined) {
+    tokenBridgeCoreEmitter = getTokenBridgeCoreEmitter();
   }
 
-  if (tokenBridgeMintAuthority === undefined) {
-    tokenBridgeMintAuthority = tokenBridge.deriveMintAuthorityKey(
-      TOKEN_BRIDGE_PROGRAM_ID
+  if (coreEmitterSequence === undefined) {
+    coreEmitterSequence = coreBridge.deriveEmitterSequenceKey(
+      tokenBridgeCoreEmitter,
+      CORE_BRIDGE_PROGRAM_ID
     );
   }
 
-  if (rent === undefined) {
-    rent = SYSVAR_RENT_PUBKEY;
+  if (coreFeeCollector === undefined) {
+    coreFeeCollector = coreBridge.deriveFeeCollectorKey(CORE_BRIDGE_PROGRAM_ID);
   }
 
-  if (tbtcProgram === undefined) {
-    tbtcProgram = TBTC_PROGRAM_ID;
+  if (clock === undefined) {
+    clock = SYSVAR_CLOCK_PUBKEY;
+  }
+
+  if (tokenBridgeSender === undefined) {
+    tokenBridgeSender = tokenBridge.deriveSenderAccountKey(
+      WORMHOLE_GATEWAY_PROGRAM_ID
+    );
+  }
+
+  if (rent === undefined) {
+    rent = SYSVAR_RENT_PUBKEY;
   }
 
   if (tokenBridgeProgram === undefined) {
@@ -462,36 +1794,40 @@ export async function receiveTbtcIx(
   }
 
   return program.methods
-    .receiveTbtc(Array.from(parsed.hash))
+    .sendTbtcGateway(args)
     .accounts({
-      payer,
       custodian,
-      postedVaa,
-      tokenBridgeClaim,
+      allowedChain,
+      gatewayInfo,
       wrappedTbtcToken,
-      tbtcMint,
-      recipientToken,
-      recipient,
-      recipientWrappedToken,
-      tbtcConfig,
-      tbtcMinterInfo,
       wrappedTbtcMint,
+      tbtcMint,
+      senderToken,
+      sender,
+      tbtcConfig: tbtc.getConfigPDA(),
       tokenBridgeConfig,
-      tokenBridgeRegisteredEmitter,
-      //tokenBridgeRedeemer,
       tokenBridgeWrappedAsset,
-      tokenBridgeMintAuthority,
+      tokenBridgeTransferAuthority,
+      coreBridgeData,
+      coreMessage,
+      tokenBridgeCoreEmitter,
+      coreEmitterSequence,
+      coreFeeCollector,
+      clock,
+      tokenBridgeSender,
       rent,
-      tbtcProgram,
+      tbtcProgram: TBTC_PROGRAM_ID,
       tokenBridgeProgram,
       coreBridgeProgram,
+      ...eventCpiAccounts(program.programId),
     })
+    .remainingAccounts(eventCpiRemainingAccounts(TBTC_PROGRAM_ID))
     .instruction();
 }
 
-type SendTbtcGatewayContext = {
+type SendTbtcWrappedContext = {
   custodian?: PublicKey;
-  gatewayInfo?: PublicKey;
+  allowedChain?: PublicKey;
   wrappedTbtcToken?: PublicKey;
   wrappedTbtcMint?: PublicKey;
   tbtcMint?: PublicKey;
@@ -506,27 +1842,27 @@ type SendTbtcGatewayContext = {
   coreEmitterSequence?: PublicKey;
   coreFeeCollector?: PublicKey;
   clock?: PublicKey;
-  tokenBridgeSender?: PublicKey;
   rent?: PublicKey;
   tokenBridgeProgram?: PublicKey;
   coreBridgeProgram?: PublicKey;
 };
 
-type SendTbtcGatewayArgs = {
+type SendTbtcWrappedArgs = {
   amount: BN;
   recipientChain: number;
   recipient: number[];
+  arbiterFee: BN;
   nonce: number;
 };
 
-export async function sendTbtcGatewayIx(
-  accounts: SendTbtcGatewayContext,
-  args: SendTbtcGatewayArgs
+export async function sendTbtcWrappedIx(
+  accounts: SendTbtcWrappedContext,
+  args: SendTbtcWrappedArgs
 ): Promise<TransactionInstruction> {
   const program = workspace.WormholeGateway as Program<WormholeGateway>;
   let {
     custodian,
-    gatewayInfo,
+    allowedChain,
     wrappedTbtcToken,
     wrappedTbtcMint,
     tbtcMint,
@@ -541,7 +1877,6 @@ export async function sendTbtcGatewayIx(
     coreEmitterSequence,
     coreFeeCollector,
     clock,
-    tokenBridgeSender,
     rent,
     tokenBridgeProgram,
     coreBridgeProgram,
@@ -551,8 +1886,8 @@ export async function sendTbtcGatewayIx(
     custodian = getCustodianPDA();
   }
 
-  if (gatewayInfo === undefined) {
-    gatewayInfo = getGatewayInfoPDA(args.recipientChain);
+  if (allowedChain === undefined) {
+    allowedChain = getAllowedChainPDA(args.recipientChain);
   }
 
   if (wrappedTbtcToken === undefined) {
@@ -611,12 +1946,6 @@ export async function sendTbtcGatewayIx(
     clock = SYSVAR_CLOCK_PUBKEY;
   }
 
-  if (tokenBridgeSender === undefined) {
-    tokenBridgeSender = tokenBridge.deriveSenderAccountKey(
-      WORMHOLE_GATEWAY_PROGRAM_ID
-    );
-  }
-
   if (rent === undefined) {
     rent = SYSVAR_RENT_PUBKEY;
   }
@@ -630,15 +1959,16 @@ export async function sendTbtcGatewayIx(
   }
 
   return program.methods
-    .sendTbtcGateway(args)
+    .sendTbtcWrapped(args)
     .accounts({
       custodian,
-      gatewayInfo,
+      allowedChain,
       wrappedTbtcToken,
       wrappedTbtcMint,
       tbtcMint,
       senderToken,
       sender,
+      tbtcConfig: tbtc.getConfigPDA(),
       tokenBridgeConfig,
       tokenBridgeWrappedAsset,
       tokenBridgeTransferAuthority,
@@ -648,21 +1978,25 @@ export async function sendTbtcGatewayIx(
       coreEmitterSequence,
       coreFeeCollector,
       clock,
-      tokenBridgeSender,
       rent,
+      tbtcProgram: TBTC_PROGRAM_ID,
       tokenBridgeProgram,
       coreBridgeProgram,
+      ...eventCpiAccounts(program.programId),
     })
+    .remainingAccounts(eventCpiRemainingAccounts(TBTC_PROGRAM_ID))
     .instruction();
 }
 
-type SendTbtcWrappedContext = {
+type SendTbtcWithPayloadContext = {
   custodian?: PublicKey;
+  allowedChain?: PublicKey;
   wrappedTbtcToken?: PublicKey;
   wrappedTbtcMint?: PublicKey;
   tbtcMint?: PublicKey;
   senderToken: PublicKey;
   sender: PublicKey;
+  payloadSender: PublicKey;
   tokenBridgeConfig?: PublicKey;
   tokenBridgeWrappedAsset?: PublicKey;
   tokenBridgeTransferAuthority?: PublicKey;
@@ -677,26 +2011,26 @@ type SendTbtcWrappedContext = {
   coreBridgeProgram?: PublicKey;
 };
 
-type SendTbtcWrappedArgs = {
+type SendTbtcWithPayloadArgs = {
   amount: BN;
-  recipientChain: number;
-  recipient: number[];
-  arbiterFee: BN;
   nonce: number;
+  payload: Buffer;
 };
 
-export async function sendTbtcWrappedIx(
-  accounts: SendTbtcWrappedContext,
-  args: SendTbtcWrappedArgs
+export async function sendTbtcWithPayloadIx(
+  accounts: SendTbtcWithPayloadContext,
+  args: SendTbtcWithPayloadArgs
 ): Promise<TransactionInstruction> {
   const program = workspace.WormholeGateway as Program<WormholeGateway>;
   let {
     custodian,
+    allowedChain,
     wrappedTbtcToken,
     wrappedTbtcMint,
     tbtcMint,
     senderToken,
     sender,
+    payloadSender,
     tokenBridgeConfig,
     tokenBridgeWrappedAsset,
     tokenBridgeTransferAuthority,
@@ -715,6 +2049,11 @@ export async function sendTbtcWrappedIx(
     custodian = getCustodianPDA();
   }
 
+  if (allowedChain === undefined) {
+    const { canonicalToken } = await getCustodianData();
+    allowedChain = getAllowedChainPDA(canonicalToken.chain);
+  }
+
   if (wrappedTbtcToken === undefined) {
     wrappedTbtcToken = getWrappedTbtcTokenPDA();
   }
@@ -784,14 +2123,17 @@ export async function sendTbtcWrappedIx(
   }
 
   return program.methods
-    .sendTbtcWrapped(args)
+    .sendTbtcWithPayload(args)
     .accounts({
       custodian,
+      allowedChain,
       wrappedTbtcToken,
       wrappedTbtcMint,
       tbtcMint,
       senderToken,
       sender,
+      payloadSender,
+      tbtcConfig: tbtc.getConfigPDA(),
       tokenBridgeConfig,
       tokenBridgeWrappedAsset,
       tokenBridgeTransferAuthority,
@@ -802,8 +2144,11 @@ export async function sendTbtcWrappedIx(
       coreFeeCollector,
       clock,
       rent,
+      tbtcProgram: TBTC_PROGRAM_ID,
       tokenBridgeProgram,
       coreBridgeProgram,
+      ...eventCpiAccounts(program.programId),
     })
+    .remainingAccounts(eventCpiRemainingAccounts(TBTC_PROGRAM_ID))
     .instruction();
 }